@@ -0,0 +1,28 @@
+//! Decodes arbitrary bytes as each account type. Anything that decodes must
+//! encode back to the same bytes, so no layout silently drops or rewrites a
+//! field.
+
+#![no_main]
+
+use fan_engagement_log::state::{
+    AccountState, OrgStats, Organization, Proposal, ProposalResults, ProposalSummary, Season,
+};
+use libfuzzer_sys::fuzz_target;
+
+fn round_trip<T: AccountState>(data: &[u8]) {
+    let Ok(state) = T::unpack(data) else {
+        return;
+    };
+    let mut packed = vec![0u8; T::LEN];
+    state.pack(&mut packed).expect("decoded state must encode");
+    assert_eq!(packed, data);
+}
+
+fuzz_target!(|data: &[u8]| {
+    round_trip::<Organization>(data);
+    round_trip::<Proposal>(data);
+    round_trip::<ProposalResults>(data);
+    round_trip::<Season>(data);
+    round_trip::<ProposalSummary>(data);
+    round_trip::<OrgStats>(data);
+});
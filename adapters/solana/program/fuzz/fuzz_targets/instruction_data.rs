@@ -0,0 +1,17 @@
+//! Decodes arbitrary bytes as a governance instruction. Decoding must reject
+//! malformed payloads with an error, never panic or read out of bounds.
+
+#![no_main]
+
+use fan_engagement_log::instruction::{is_governance_instruction, GovernanceInstruction};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let decoded = GovernanceInstruction::unpack(data);
+    if !is_governance_instruction(data) {
+        assert!(
+            decoded.is_err(),
+            "decoded data without the governance prefix"
+        );
+    }
+});
@@ -0,0 +1,173 @@
+//! Runs arbitrary instruction data against arbitrary permutations of a fixed
+//! account pool in Mollusk.
+//!
+//! The pool holds a live organization with a season, an adapter signer, a
+//! draft proposal and a committed one, a second organization, an uncreated
+//! proposal PDA, and a copy of the organization owned by another program
+//! whose stored authority is the stranger key. Two properties must hold:
+//!
+//! - the program never aborts (a panic or unchecked overflow surfaces as
+//!   `ProgramFailedToComplete`);
+//! - an instruction signed only by the stranger never changes a
+//!   program-owned account, which catches missed signer and owner checks.
+//!
+//! Mollusk executes the SBF build, so run `cargo build-sbf` in the program
+//! directory first and point `SBF_OUT_DIR` at `../target/deploy`.
+
+#![no_main]
+
+#[path = "../../tests/common/mod.rs"]
+mod common;
+
+use arbitrary::Arbitrary;
+use common::{ix, Harness, NOW, PROGRAM_ID};
+use fan_engagement_log::state::Organization;
+use libfuzzer_sys::fuzz_target;
+use solana_account::Account;
+use solana_instruction::{error::InstructionError, AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+const MAX_ACCOUNTS: usize = 8;
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    data: Vec<u8>,
+    accounts: Vec<Meta>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Meta {
+    account: u8,
+    is_signer: bool,
+    is_writable: bool,
+}
+
+struct Fixture {
+    harness: Harness,
+    pool: Vec<Pubkey>,
+    stranger: Pubkey,
+}
+
+fn fixture() -> Fixture {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let foreign = harness.create_org(2);
+    let stranger = harness.funded_key();
+
+    let adapter = harness.funded_key();
+    harness.run(&ix::add_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+    harness.run(&ix::create_season(
+        &org.authority,
+        &org.address,
+        [5u8; 16],
+        NOW,
+        NOW + 30 * 86_400,
+    ));
+    let draft = harness.create_proposal(&org, 2);
+    let committed = harness.create_proposal(&org, 3);
+    harness.open_proposal(&org, &committed);
+    harness.close_proposal(&org, &committed);
+    harness.run(&ix::commit_vote_results(
+        &org.authority,
+        &org.address,
+        &committed,
+        2,
+        10,
+    ));
+    // Back inside the draft's voting window, so opening it can succeed.
+    harness.warp_to(ix::ProposalParams::new(2, NOW).start_at);
+
+    let forged = Pubkey::new_unique();
+    let mut state: Organization = harness.state(&org.address);
+    state.authority = common::address(&stranger);
+    harness.set_state(forged, &state);
+    let mut account = harness.account(&forged).unwrap().clone();
+    account.owner = Pubkey::new_unique();
+    harness.set_account(forged, account);
+
+    let pool = vec![
+        stranger,
+        org.authority,
+        org.recovery_authority,
+        adapter,
+        org.address,
+        foreign.address,
+        forged,
+        draft,
+        committed,
+        ix::results_pda(&committed).0,
+        ix::season_pda(&org.address, [5u8; 16]).0,
+        ix::proposal_pda(&org.address, [4u8; 16]).0,
+        Pubkey::default(),
+    ];
+    Fixture {
+        harness,
+        pool,
+        stranger,
+    }
+}
+
+thread_local! {
+    static FIXTURE: Fixture = fixture();
+}
+
+fn run(fixture: &Fixture, input: Input) {
+    let metas: Vec<AccountMeta> = input
+        .accounts
+        .iter()
+        .take(MAX_ACCOUNTS)
+        .map(|meta| AccountMeta {
+            pubkey: fixture.pool[meta.account as usize % fixture.pool.len()],
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        })
+        .collect();
+    let accounts: Vec<(Pubkey, Account)> = metas
+        .iter()
+        .map(|meta| {
+            let account = fixture
+                .harness
+                .account(&meta.pubkey)
+                .cloned()
+                .unwrap_or_default();
+            (meta.pubkey, account)
+        })
+        .collect();
+    let instruction = Instruction::new_with_bytes(PROGRAM_ID, &input.data, metas.clone());
+
+    let result = fixture
+        .harness
+        .mollusk
+        .process_instruction(&instruction, &accounts);
+    assert_ne!(
+        result.raw_result,
+        Err(InstructionError::ProgramFailedToComplete),
+        "program aborted"
+    );
+    if result.raw_result.is_err() {
+        return;
+    }
+
+    let untrusted = metas
+        .iter()
+        .filter(|meta| meta.is_signer)
+        .all(|meta| meta.pubkey == fixture.stranger);
+    if !untrusted {
+        return;
+    }
+    for (key, after) in &result.resulting_accounts {
+        let before = fixture.harness.account(key).cloned().unwrap_or_default();
+        assert!(
+            after.owner != PROGRAM_ID || *after == before,
+            "stranger changed program account {key}"
+        );
+    }
+}
+
+fuzz_target!(|input: Input| {
+    FIXTURE.with(|fixture| run(fixture, input));
+});
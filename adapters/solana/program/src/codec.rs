@@ -0,0 +1,108 @@
+//! Little-endian cursor helpers shared by instruction and account parsing.
+
+use crate::error::GovernanceError;
+
+pub struct Reader<'a> {
+    data: &'a [u8],
+    error: GovernanceError,
+}
+
+impl<'a> Reader<'a> {
+    /// Creates a reader that reports `error` when the input is too short or
+    /// has trailing bytes.
+    pub fn new(data: &'a [u8], error: GovernanceError) -> Self {
+        Self { data, error }
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], GovernanceError> {
+        if self.data.len() < len {
+            return Err(self.error);
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], GovernanceError> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.read_bytes(N)?);
+        Ok(out)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, GovernanceError> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, GovernanceError> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(self.error),
+        }
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, GovernanceError> {
+        Ok(u64::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64, GovernanceError> {
+        Ok(i64::from_le_bytes(self.read_array()?))
+    }
+
+    /// Reads a `u8` length prefix followed by that many bytes.
+    pub fn read_short_bytes(&mut self) -> Result<&'a [u8], GovernanceError> {
+        let len = self.read_u8()? as usize;
+        self.read_bytes(len)
+    }
+
+    /// The input not yet read.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Fails if any input is left unread.
+    pub fn finish(self) -> Result<(), GovernanceError> {
+        if self.data.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error)
+        }
+    }
+}
+
+pub struct Writer<'a> {
+    data: &'a mut [u8],
+    offset: usize,
+}
+
+impl<'a> Writer<'a> {
+    pub fn new(data: &'a mut [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    /// Number of bytes written so far.
+    pub fn position(&self) -> usize {
+        self.offset
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.data[self.offset..self.offset + bytes.len()].copy_from_slice(bytes);
+        self.offset += bytes.len();
+    }
+
+    pub fn write_u8(&mut self, value: u8) {
+        self.write_bytes(&[value]);
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_u8(value as u8);
+    }
+
+    pub fn write_u64(&mut self, value: u64) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub fn write_i64(&mut self, value: i64) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+}
@@ -0,0 +1,256 @@
+use pinocchio::error::ProgramError;
+
+/// Errors returned by the governance instructions.
+///
+/// Surfaced to clients as `ProgramError::Custom(code)`. Codes start at 6000,
+/// the offset Anchor uses for custom errors, but the variants are this
+/// program's own: a code does not mean the same thing it did in the archived
+/// Anchor program. Off-chain callers can turn a failed transaction back into a
+/// variant with `from_code` or `from_logs`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum GovernanceError {
+    InvalidInstruction = 6000,
+    InvalidPda,
+    InvalidAccountOwner,
+    InvalidAccountData,
+    AccountAlreadyInitialized,
+    Unauthorized,
+    InvalidName,
+    InvalidTitle,
+    InvalidVotingWindow,
+    QuorumExceedsEligiblePower,
+    InvalidStatusTransition,
+    ProposalNotClosed,
+    ResultsNotCommitted,
+    OrganizationMismatch,
+    ProposalMismatch,
+    ArithmeticOverflow,
+    ProposalOutsideSeason,
+    SeasonMismatch,
+    StaleOperationNonce,
+    AdapterSignerLimitReached,
+    AdapterSignerAlreadyRegistered,
+    AdapterSignerNotFound,
+    RecoveryNotConfigured,
+    RecoveryAlreadyInitiated,
+    RecoveryNotInitiated,
+    RecoveryTimelockActive,
+    InvalidAuthority,
+    InvalidSnapshotSlot,
+    VotingPowerLocked,
+    ZeroEligibleVotingPower,
+    VotesExceedEligiblePower,
+    MissingStartDate,
+    EndBeforeNow,
+    OrganizationFrozen,
+    OrganizationNotFrozen,
+    OrganizationNotEmpty,
+    CloseDelayActive,
+    ProposalNotFinalized,
+    VotingNotStarted,
+    VotingEnded,
+    VotingNotEnded,
+    VotingWindowTooShort,
+    VotingWindowTooLong,
+    OrgStatsRequired,
+    ProposalNotDraft,
+    ProposalNotOpen,
+    VotingPeriodAlreadyExtended,
+    InvalidVotingExtension,
+    VotingExtensionTooLong,
+    InvalidDisputeWindow,
+    DisputeWindowOpen,
+    DisputeWindowClosed,
+    ResultsDisputed,
+    ChallengerMismatch,
+    InvalidResultsPreimage,
+    ResultsHashMismatch,
+    UnsupportedHashScheme,
+    OrgConfigRequired,
+    InvalidOrgConfig,
+    AuditLogRequired,
+    OrganizationClosed,
+    ProposalFinalized,
+    ProposalArchived,
+}
+
+impl GovernanceError {
+    /// Every variant, in code order.
+    pub const ALL: [Self; 63] = [
+        Self::InvalidInstruction,
+        Self::InvalidPda,
+        Self::InvalidAccountOwner,
+        Self::InvalidAccountData,
+        Self::AccountAlreadyInitialized,
+        Self::Unauthorized,
+        Self::InvalidName,
+        Self::InvalidTitle,
+        Self::InvalidVotingWindow,
+        Self::QuorumExceedsEligiblePower,
+        Self::InvalidStatusTransition,
+        Self::ProposalNotClosed,
+        Self::ResultsNotCommitted,
+        Self::OrganizationMismatch,
+        Self::ProposalMismatch,
+        Self::ArithmeticOverflow,
+        Self::ProposalOutsideSeason,
+        Self::SeasonMismatch,
+        Self::StaleOperationNonce,
+        Self::AdapterSignerLimitReached,
+        Self::AdapterSignerAlreadyRegistered,
+        Self::AdapterSignerNotFound,
+        Self::RecoveryNotConfigured,
+        Self::RecoveryAlreadyInitiated,
+        Self::RecoveryNotInitiated,
+        Self::RecoveryTimelockActive,
+        Self::InvalidAuthority,
+        Self::InvalidSnapshotSlot,
+        Self::VotingPowerLocked,
+        Self::ZeroEligibleVotingPower,
+        Self::VotesExceedEligiblePower,
+        Self::MissingStartDate,
+        Self::EndBeforeNow,
+        Self::OrganizationFrozen,
+        Self::OrganizationNotFrozen,
+        Self::OrganizationNotEmpty,
+        Self::CloseDelayActive,
+        Self::ProposalNotFinalized,
+        Self::VotingNotStarted,
+        Self::VotingEnded,
+        Self::VotingNotEnded,
+        Self::VotingWindowTooShort,
+        Self::VotingWindowTooLong,
+        Self::OrgStatsRequired,
+        Self::ProposalNotDraft,
+        Self::ProposalNotOpen,
+        Self::VotingPeriodAlreadyExtended,
+        Self::InvalidVotingExtension,
+        Self::VotingExtensionTooLong,
+        Self::InvalidDisputeWindow,
+        Self::DisputeWindowOpen,
+        Self::DisputeWindowClosed,
+        Self::ResultsDisputed,
+        Self::ChallengerMismatch,
+        Self::InvalidResultsPreimage,
+        Self::ResultsHashMismatch,
+        Self::UnsupportedHashScheme,
+        Self::OrgConfigRequired,
+        Self::InvalidOrgConfig,
+        Self::AuditLogRequired,
+        Self::OrganizationClosed,
+        Self::ProposalFinalized,
+        Self::ProposalArchived,
+    ];
+
+    pub fn code(self) -> u32 {
+        self as u32
+    }
+
+    pub fn from_code(code: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|error| error.code() == code)
+    }
+
+    pub fn from_program_error(error: &ProgramError) -> Option<Self> {
+        match error {
+            ProgramError::Custom(code) => Self::from_code(*code),
+            _ => None,
+        }
+    }
+
+    /// Finds the first `custom program error: 0x...` line in transaction or
+    /// simulation logs that carries a governance error code.
+    ///
+    /// Logs are not filtered by program id, so callers that CPI into other
+    /// programs should check which program failed.
+    pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> Option<Self> {
+        const MARKER: &str = "custom program error: 0x";
+
+        logs.iter().find_map(|line| {
+            let line = line.as_ref();
+            let hex = &line[line.find(MARKER)? + MARKER.len()..];
+            let end = hex.find(|c: char| !c.is_ascii_hexdigit()).unwrap_or(hex.len());
+            u32::from_str_radix(&hex[..end], 16).ok().and_then(Self::from_code)
+        })
+    }
+
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::InvalidInstruction => "instruction data could not be decoded",
+            Self::InvalidPda => "account does not match the derived PDA",
+            Self::InvalidAccountOwner => "account is not owned by the program",
+            Self::InvalidAccountData => "account data has the wrong size or discriminator",
+            Self::AccountAlreadyInitialized => "account already exists",
+            Self::Unauthorized => "signer is not allowed to perform this operation",
+            Self::InvalidName => "organization name is empty, too long, or not UTF-8",
+            Self::InvalidTitle => "proposal title is empty, too long, or not UTF-8",
+            Self::InvalidVotingWindow => "voting window must end after it starts",
+            Self::QuorumExceedsEligiblePower => "quorum requirement exceeds eligible voting power",
+            Self::InvalidStatusTransition => "proposal cannot move to the requested status",
+            Self::ProposalNotClosed => "proposal must be closed",
+            Self::ResultsNotCommitted => "results must be committed before finalizing",
+            Self::OrganizationMismatch => "proposal belongs to a different organization",
+            Self::ProposalMismatch => "results belong to a different proposal",
+            Self::ArithmeticOverflow => "arithmetic overflow",
+            Self::ProposalOutsideSeason => "voting window lies outside the season",
+            Self::SeasonMismatch => "season does not belong to the organization or proposal",
+            Self::StaleOperationNonce => "operation nonce is not the next expected value",
+            Self::AdapterSignerLimitReached => "adapter signer limit reached",
+            Self::AdapterSignerAlreadyRegistered => "adapter signer is already registered",
+            Self::AdapterSignerNotFound => "adapter signer is not registered",
+            Self::RecoveryNotConfigured => "organization has no recovery authority",
+            Self::RecoveryAlreadyInitiated => "a recovery is already pending",
+            Self::RecoveryNotInitiated => "no recovery is pending",
+            Self::RecoveryTimelockActive => "recovery timelock has not elapsed",
+            Self::InvalidAuthority => "authority cannot be the default address",
+            Self::InvalidSnapshotSlot => "snapshot slot is in the future",
+            Self::VotingPowerLocked => "eligible voting power can only change while the proposal is a draft",
+            Self::ZeroEligibleVotingPower => "quorum requirement set with zero eligible voting power",
+            Self::VotesExceedEligiblePower => "total votes cast exceed eligible voting power",
+            Self::MissingStartDate => "proposal start time is not set",
+            Self::EndBeforeNow => "voting window has already ended",
+            Self::OrganizationFrozen => "organization is frozen",
+            Self::OrganizationNotFrozen => "organization must be frozen first",
+            Self::OrganizationNotEmpty => "organization still has unarchived proposals, seasons or stats",
+            Self::CloseDelayActive => "organization close delay has not elapsed",
+            Self::ProposalNotFinalized => "proposal is not finalized",
+            Self::VotingNotStarted => "voting window has not started",
+            Self::VotingEnded => "voting window has ended",
+            Self::VotingNotEnded => "voting window has not ended",
+            Self::VotingWindowTooShort => "voting window is shorter than one hour",
+            Self::VotingWindowTooLong => "voting window is longer than 90 days",
+            Self::OrgStatsRequired => "organization stats account is required once it exists",
+            Self::ProposalNotDraft => "proposal can only be amended while in Draft",
+            Self::ProposalNotOpen => "proposal is not open",
+            Self::VotingPeriodAlreadyExtended => "voting period has already been extended",
+            Self::InvalidVotingExtension => "voting period can only be extended to a later end",
+            Self::VotingExtensionTooLong => "voting period extension is too long",
+            Self::InvalidDisputeWindow => "dispute window is out of range",
+            Self::DisputeWindowOpen => "dispute window has not ended",
+            Self::DisputeWindowClosed => "dispute window has ended",
+            Self::ResultsDisputed => "results are disputed",
+            Self::ChallengerMismatch => "challenger account does not match the dispute",
+            Self::InvalidResultsPreimage => "results preimage is not a canonical encoding of these results",
+            Self::ResultsHashMismatch => "results preimage does not hash to the committed results hash",
+            Self::UnsupportedHashScheme => "hash scheme is not supported by this program version",
+            Self::OrgConfigRequired => "organization config account is required",
+            Self::InvalidOrgConfig => "config voting durations must satisfy min <= default <= max within the program limits",
+            Self::AuditLogRequired => "audit log account is required",
+            Self::OrganizationClosed => "organization id belongs to a closed organization",
+            Self::ProposalFinalized => "proposal is finalized; archive it instead",
+            Self::ProposalArchived => "proposal id belongs to an archived proposal",
+        }
+    }
+}
+
+impl core::fmt::Display for GovernanceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} ({:?}): {}", self.code(), self, self.message())
+    }
+}
+
+impl From<GovernanceError> for ProgramError {
+    fn from(e: GovernanceError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
@@ -0,0 +1,681 @@
+//! Governance instruction decoding.
+//!
+//! Governance instruction data is `GOVERNANCE_PREFIX`, a one-byte tag, and a
+//! fixed little-endian payload. Strings are a `u8` length prefix followed by
+//! UTF-8 bytes. Any data that does not start with the prefix is handled by the
+//! log instruction.
+//!
+//! Adapter-facing operations (status updates and results commitments) carry
+//! the organization's next `operation_nonce` so retries cannot double-apply.
+//! They may be signed by the organization authority or by one of its
+//! registered adapter signers. `UpdateProposalDraft` also accepts the
+//! proposal's author, `DisputeResults` accepts any challenger who posts the
+//! bond, and `FinalizeProposal` and `VerifyResultsHash` need no signer;
+//! everything else requires the authority.
+//!
+//! Once an organization has an `AuditLog`, every instruction signed by its
+//! authority, an adapter signer or its recovery authority takes the audit log
+//! PDA (`[WRITE]`) as its last account, after any optional accounts, and
+//! appends an entry to it. `DisputeResults`, `FinalizeProposal`,
+//! `VerifyResultsHash` and `CloseAuditLog` itself do not, and
+//! `CloseOrganization` requires the log to be closed first.
+
+use core::fmt;
+
+use pinocchio::Address;
+
+use crate::{
+    codec::Reader,
+    error::GovernanceError,
+    state::{HashScheme, ProposalStatus},
+};
+
+/// First byte of every governance instruction. `0xFE` never appears in UTF-8,
+/// so no log payload can be mistaken for a governance instruction, whatever
+/// character it starts with.
+pub const GOVERNANCE_PREFIX: u8 = 0xFE;
+
+pub const CREATE_ORGANIZATION: u8 = 0;
+pub const CREATE_PROPOSAL: u8 = 1;
+pub const UPDATE_PROPOSAL_STATUS: u8 = 2;
+pub const COMMIT_VOTE_RESULTS: u8 = 3;
+pub const CREATE_SEASON: u8 = 4;
+pub const ADD_ADAPTER_SIGNER: u8 = 5;
+pub const REMOVE_ADAPTER_SIGNER: u8 = 6;
+pub const INITIATE_RECOVERY: u8 = 7;
+pub const CANCEL_RECOVERY: u8 = 8;
+pub const COMPLETE_RECOVERY: u8 = 9;
+pub const CREATE_PROPOSALS_BATCH: u8 = 10;
+pub const OPEN_PROPOSAL_WITH_SNAPSHOT: u8 = 11;
+pub const CLOSE_AND_COMMIT_RESULTS: u8 = 12;
+pub const SET_ELIGIBLE_VOTING_POWER: u8 = 13;
+pub const LOG_VERSION: u8 = 14;
+pub const FREEZE_ORGANIZATION: u8 = 15;
+pub const CLOSE_ORGANIZATION: u8 = 16;
+pub const ARCHIVE_PROPOSAL: u8 = 17;
+pub const CREATE_ORG_STATS: u8 = 18;
+pub const CLOSE_SEASON: u8 = 19;
+pub const CLOSE_ORG_STATS: u8 = 20;
+pub const UPDATE_PROPOSAL_DRAFT: u8 = 21;
+pub const EXTEND_VOTING_PERIOD: u8 = 22;
+pub const SET_DISPUTE_POLICY: u8 = 23;
+pub const DISPUTE_RESULTS: u8 = 24;
+pub const FINALIZE_PROPOSAL: u8 = 25;
+pub const VERIFY_RESULTS_HASH: u8 = 26;
+pub const SET_ORG_CONFIG: u8 = 27;
+pub const CLOSE_ORG_CONFIG: u8 = 28;
+pub const CREATE_AUDIT_LOG: u8 = 29;
+pub const CLOSE_AUDIT_LOG: u8 = 30;
+pub const DISCARD_PROPOSAL: u8 = 31;
+
+/// Most proposals a single `CreateProposalsBatch` may create. Larger batches
+/// would not fit in a transaction anyway.
+pub const MAX_BATCH_PROPOSALS: usize = 8;
+
+/// `end_at` and `quorum_requirement` values in a `CreateProposal` payload that
+/// take the organization's `OrgConfig` default instead. Neither is a valid
+/// value of its own, so without a config they fail validation as before.
+pub const USE_DEFAULT_END_AT: i64 = 0;
+pub const USE_DEFAULT_QUORUM: u64 = u64::MAX;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GovernanceInstruction<'a> {
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Authority and payer
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[]` System program
+    CreateOrganization {
+        organization_id: [u8; 16],
+        bump: u8,
+        /// Default address for no recovery authority.
+        recovery_authority: Address,
+        name: &'a [u8],
+    },
+
+    /// Creates a draft proposal. Once the organization has an `OrgConfig`,
+    /// `USE_DEFAULT_END_AT` and `USE_DEFAULT_QUORUM` take its defaults and the
+    /// voting window must fit its duration limits. Fails with
+    /// `ProposalArchived` if the id belongs to an archived proposal.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority and payer
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Proposal PDA
+    ///   3. `[]` System program
+    ///   4. `[]` Organization config PDA (once the organization has one)
+    ///   5. `[WRITE]` Season PDA (optional, assigns the proposal to the season;
+    ///      directly after the system program without a config)
+    CreateProposal(CreateProposalArgs<'a>),
+
+    /// Finalizing waits for the results' dispute window to end, and is the
+    /// only way to finalize results flagged with `DisputeResults`.
+    ///
+    /// Accounts:
+    ///   0. `[SIGNER]` Organization authority or adapter signer
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Proposal PDA
+    ///   3. `[WRITE]` Proposal results PDA (only when finalizing)
+    ///   4. `[WRITE]` Season PDA (only when finalizing a proposal in a season)
+    ///   5. `[WRITE]` Organization stats PDA (when finalizing once the
+    ///      organization has one; directly after the results PDA for a
+    ///      proposal without a season)
+    ///   6. `[WRITE]` Challenger (when finalizing results disputed with a
+    ///      bond, which is returned to them; after the accounts above)
+    UpdateProposalStatus {
+        operation_nonce: u64,
+        status: ProposalStatus,
+    },
+
+    /// Commits the results of a closed proposal. `results_hash` is
+    /// `results::compute_results_hash` over the off-chain tally, so
+    /// `results_hash_scheme` must be `HashScheme::Sha256`. The results stay
+    /// open to disputes for the organization's `dispute_window_seconds`.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority or adapter signer, and payer
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Proposal PDA
+    ///   3. `[WRITE]` Proposal results PDA
+    ///   4. `[]` System program
+    CommitVoteResults(CommitVoteResultsArgs),
+
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority and payer
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Season PDA
+    ///   3. `[]` System program
+    CreateSeason {
+        season_id: [u8; 16],
+        bump: u8,
+        start_at: i64,
+        end_at: i64,
+    },
+
+    /// Accounts:
+    ///   0. `[SIGNER]` Organization authority
+    ///   1. `[WRITE]` Organization PDA
+    AddAdapterSigner { signer: Address },
+
+    /// Accounts:
+    ///   0. `[SIGNER]` Organization authority
+    ///   1. `[WRITE]` Organization PDA
+    RemoveAdapterSigner { signer: Address },
+
+    /// Starts the recovery timelock, publicly announcing `new_authority`.
+    ///
+    /// Accounts:
+    ///   0. `[SIGNER]` Organization recovery authority
+    ///   1. `[WRITE]` Organization PDA
+    InitiateRecovery { new_authority: Address },
+
+    /// Accounts:
+    ///   0. `[SIGNER]` Organization authority
+    ///   1. `[WRITE]` Organization PDA
+    CancelRecovery,
+
+    /// Accounts:
+    ///   0. `[SIGNER]` Organization recovery authority
+    ///   1. `[WRITE]` Organization PDA
+    CompleteRecovery,
+
+    /// Creates several proposals at once. Payload is a `u8` count followed by
+    /// that many `CreateProposal` payloads.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority and payer
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[]` System program
+    ///   3. `[WRITE]` Proposal PDAs, one per payload in payload order (indices
+    ///      `3..3+N`)
+    ///   4. `[]` Organization config PDA at index `3+N` (once the organization
+    ///      has one)
+    ///   5. `[WRITE]` Season PDA after the accounts above (optional, assigns
+    ///      every proposal to the season)
+    CreateProposalsBatch(ProposalBatch<'a>),
+
+    /// Opens a draft proposal and records its eligibility snapshot, replacing
+    /// the `eligible_voting_power` given at creation.
+    ///
+    /// Accounts:
+    ///   0. `[SIGNER]` Organization authority or adapter signer
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Proposal PDA
+    OpenProposalWithSnapshot {
+        operation_nonce: u64,
+        snapshot_slot: u64,
+        voter_merkle_root: [u8; 32],
+        eligible_voting_power: u64,
+    },
+
+    /// Closes an open proposal and commits its results in one step. Same
+    /// payload as `CommitVoteResults`.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority or adapter signer, and payer
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Proposal PDA
+    ///   3. `[WRITE]` Proposal results PDA
+    ///   4. `[]` System program
+    CloseAndCommitResults(CommitVoteResultsArgs),
+
+    /// Overwrites the eligible voting power of a draft proposal.
+    ///
+    /// Accounts:
+    ///   0. `[SIGNER]` Organization authority or adapter signer
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Proposal PDA
+    SetEligibleVotingPower {
+        operation_nonce: u64,
+        eligible_voting_power: u64,
+    },
+
+    /// Logs `FE_GOV: version <PROGRAM_VERSION>`. Takes no accounts and reads
+    /// no state, so it can be simulated against any cluster.
+    LogVersion,
+
+    /// Permanently blocks every further change to the organization and its
+    /// proposals and seasons other than winding it down with
+    /// `ArchiveProposal`, `DiscardProposal`, `CloseSeason`, `CloseOrgStats`,
+    /// `CloseOrgConfig`, `CloseAuditLog` and `CloseOrganization`, for clubs
+    /// that no longer govern. Their records stay readable.
+    ///
+    /// Accounts:
+    ///   0. `[SIGNER]` Organization authority
+    ///   1. `[WRITE]` Organization PDA
+    FreezeOrganization,
+
+    /// Closes a frozen organization whose proposals are all archived or
+    /// discarded and whose seasons, stats, config and audit log accounts are
+    /// closed. Finalized proposals are archived with `ArchiveProposal`; those
+    /// that never finalized can no longer change status once the organization
+    /// is frozen and are closed with `DiscardProposal`. Freezing first, at
+    /// least `ORGANIZATION_CLOSE_DELAY_SECONDS` earlier, is the confirmation
+    /// step.
+    ///
+    /// The account is shrunk to a `ClosedOrganization` marker and the rest of
+    /// its rent is refunded to the authority. The marker keeps the id taken, so
+    /// `CreateOrganization` fails with `OrganizationClosed` for it.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority, receives the rent
+    ///   1. `[WRITE]` Organization PDA
+    CloseOrganization,
+
+    /// Shrinks a finalized proposal to a `ProposalSummary` of its ids and
+    /// hashes in place and closes its results, refunding the freed rent to the
+    /// authority. Also allowed once the organization is frozen. No payload.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority, rent recipient
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Proposal PDA
+    ///   3. `[WRITE]` Proposal results PDA
+    ArchiveProposal,
+
+    /// Creates the organization's `OrgStats` PDA. Every finalization from then
+    /// on must pass it and is counted in it. Payload is the PDA bump.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority and payer
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Organization stats PDA
+    ///   3. `[]` System program
+    CreateOrgStats { bump: u8 },
+
+    /// Closes one of a frozen organization's seasons, refunding its rent to
+    /// the authority. Every season must be closed before the organization.
+    /// No payload.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority, rent recipient
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Season PDA
+    CloseSeason,
+
+    /// Closes a frozen organization's `OrgStats` account, refunding its rent
+    /// to the authority. It must be closed before the organization. No
+    /// payload.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority, rent recipient
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Organization stats PDA
+    CloseOrgStats,
+
+    /// Amends a draft proposal's title, content hash, voting window and
+    /// quorum, with the same checks as `CreateProposal`, and counts the
+    /// change in `amendment_count`. Options have no on-chain record; amend
+    /// them off-chain and commit them through `content_hash`.
+    ///
+    /// Accounts:
+    ///   0. `[SIGNER]` Organization authority or the proposal's author
+    ///   1. `[]` Organization PDA
+    ///   2. `[WRITE]` Proposal PDA
+    ///   3. `[]` Organization config PDA (once the organization has one)
+    ///   4. `[]` Season PDA, required if the proposal belongs to one (directly
+    ///      after the proposal without a config)
+    UpdateProposalDraft(UpdateProposalDraftArgs<'a>),
+
+    /// Moves an open proposal's `end_at` to a later `end_at`, once, by at
+    /// most `MAX_VOTING_EXTENSION_SECONDS` and within the voting duration,
+    /// organization config and season limits. The previous end is kept in
+    /// `original_end_at`.
+    ///
+    /// Accounts:
+    ///   0. `[SIGNER]` Organization authority
+    ///   1. `[]` Organization PDA
+    ///   2. `[WRITE]` Proposal PDA
+    ///   3. `[]` Organization config PDA (once the organization has one)
+    ///   4. `[]` Season PDA, required if the proposal belongs to one (directly
+    ///      after the proposal without a config)
+    ExtendVotingPeriod { end_at: i64 },
+
+    /// Sets how long committed results stay open to disputes, up to
+    /// `MAX_DISPUTE_WINDOW_SECONDS`, and the bond a challenger other than the
+    /// authority posts to raise one (0 leaves disputes to the authority).
+    ///
+    /// Accounts:
+    ///   0. `[SIGNER]` Organization authority
+    ///   1. `[WRITE]` Organization PDA
+    SetDisputePolicy {
+        window_seconds: i64,
+        bond_lamports: u64,
+    },
+
+    /// Flags committed results before their dispute window ends. Disputed
+    /// results can no longer be finalized with `FinalizeProposal`, only with
+    /// `UpdateProposalStatus`. Challengers other than the authority post the
+    /// organization's bond into the results account.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority, or challenger and payer
+    ///      of the bond
+    ///   1. `[]` Organization PDA
+    ///   2. `[]` Proposal PDA
+    ///   3. `[WRITE]` Proposal results PDA
+    ///   4. `[]` System program
+    DisputeResults,
+
+    /// Finalizes a closed proposal once its results' dispute window has ended
+    /// without a dispute. Needs no signer.
+    ///
+    /// Accounts:
+    ///   0. `[]` Organization PDA
+    ///   1. `[WRITE]` Proposal PDA
+    ///   2. `[WRITE]` Proposal results PDA
+    ///   3. `[WRITE]` Season PDA (if the proposal is in a season)
+    ///   4. `[WRITE]` Organization stats PDA (if the organization has one)
+    FinalizeProposal,
+
+    /// Hashes a published results preimage and sets `hash_verified` on the
+    /// results if it matches `results_hash`. The payload is the start of the
+    /// canonical encoding (`results::canonical_results_bytes`); the data of
+    /// any accounts after the results PDA continues it, in order. Needs no
+    /// signer.
+    ///
+    /// Accounts:
+    ///   0. `[]` Proposal PDA
+    ///   1. `[WRITE]` Proposal results PDA
+    ///   2. `[]` Accounts holding the rest of the preimage (optional, any
+    ///      number)
+    VerifyResultsHash { preimage: &'a [u8] },
+
+    /// Creates or replaces the organization's `OrgConfig`: the defaults
+    /// `CreateProposal` falls back to and the voting duration limits every
+    /// proposal must then fit, within the program's own limits. Existing
+    /// proposals keep their windows. `bump` is only checked on creation.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority and payer
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Organization config PDA
+    ///   3. `[]` System program
+    SetOrgConfig(OrgConfigArgs),
+
+    /// Closes a frozen organization's `OrgConfig` account, refunding its rent
+    /// to the authority. It must be closed before the organization. No
+    /// payload.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority, rent recipient
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Organization config PDA
+    CloseOrgConfig,
+
+    /// Creates the organization's `AuditLog` PDA, recording its own creation
+    /// as the first entry. Every privileged instruction from then on must pass
+    /// it last and is recorded in it. Payload is the PDA bump.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority and payer
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Audit log PDA
+    ///   3. `[]` System program
+    CreateAuditLog { bump: u8 },
+
+    /// Closes a frozen organization's `AuditLog` account, refunding its rent
+    /// to the authority. It must be closed before the organization. No
+    /// payload.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority, rent recipient
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Audit log PDA
+    CloseAuditLog,
+
+    /// Closes a frozen organization's proposal that never finalized, and its
+    /// results if they were committed, refunding the rent to the authority and
+    /// counting it in `archived_proposal_count`. Fails with
+    /// `ProposalFinalized` for a finalized proposal, which `ArchiveProposal`
+    /// handles. No payload.
+    ///
+    /// Accounts:
+    ///   0. `[WRITE, SIGNER]` Organization authority, rent recipient
+    ///   1. `[WRITE]` Organization PDA
+    ///   2. `[WRITE]` Proposal PDA
+    ///   3. `[WRITE]` Proposal results PDA, if the proposal has results
+    ///   4. `[WRITE]` Challenger, if the results were disputed with a bond
+    DiscardProposal,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateProposalArgs<'a> {
+    pub proposal_id: [u8; 16],
+    pub bump: u8,
+    pub content_hash: [u8; 32],
+    pub content_hash_scheme: HashScheme,
+    pub start_at: i64,
+    pub end_at: i64,
+    pub quorum_requirement: u64,
+    pub eligible_voting_power: u64,
+    pub title: &'a [u8],
+}
+
+impl<'a> CreateProposalArgs<'a> {
+    fn read(reader: &mut Reader<'a>) -> Result<Self, GovernanceError> {
+        Ok(Self {
+            proposal_id: reader.read_array()?,
+            bump: reader.read_u8()?,
+            content_hash: reader.read_array()?,
+            content_hash_scheme: read_hash_scheme(reader)?,
+            start_at: reader.read_i64()?,
+            end_at: reader.read_i64()?,
+            quorum_requirement: reader.read_u64()?,
+            eligible_voting_power: reader.read_u64()?,
+            title: reader.read_short_bytes()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateProposalDraftArgs<'a> {
+    pub content_hash: [u8; 32],
+    pub content_hash_scheme: HashScheme,
+    pub start_at: i64,
+    pub end_at: i64,
+    pub quorum_requirement: u64,
+    pub title: &'a [u8],
+}
+
+impl<'a> UpdateProposalDraftArgs<'a> {
+    fn read(reader: &mut Reader<'a>) -> Result<Self, GovernanceError> {
+        Ok(Self {
+            content_hash: reader.read_array()?,
+            content_hash_scheme: read_hash_scheme(reader)?,
+            start_at: reader.read_i64()?,
+            end_at: reader.read_i64()?,
+            quorum_requirement: reader.read_u64()?,
+            title: reader.read_short_bytes()?,
+        })
+    }
+}
+
+/// Validated `CreateProposal` payloads of a `CreateProposalsBatch`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProposalBatch<'a> {
+    len: usize,
+    data: &'a [u8],
+}
+
+impl<'a> ProposalBatch<'a> {
+    fn read(reader: &mut Reader<'a>) -> Result<Self, GovernanceError> {
+        let len = reader.read_u8()? as usize;
+        if len == 0 || len > MAX_BATCH_PROPOSALS {
+            return Err(GovernanceError::InvalidInstruction);
+        }
+        let data = reader.remaining();
+        for _ in 0..len {
+            CreateProposalArgs::read(reader)?;
+        }
+        Ok(Self { len, data })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = CreateProposalArgs<'a>> {
+        let mut reader = Reader::new(self.data, GovernanceError::InvalidInstruction);
+        // Every payload was decoded once in `read`, so this cannot fail early.
+        core::iter::from_fn(move || CreateProposalArgs::read(&mut reader).ok())
+    }
+}
+
+/// Lists the decoded payloads rather than the raw bytes.
+impl fmt::Debug for ProposalBatch<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitVoteResultsArgs {
+    pub operation_nonce: u64,
+    pub bump: u8,
+    pub results_hash: [u8; 32],
+    pub results_hash_scheme: HashScheme,
+    pub winning_option_id: [u8; 16],
+    pub total_votes_cast: u64,
+    pub quorum_met: bool,
+}
+
+impl CommitVoteResultsArgs {
+    fn read(reader: &mut Reader) -> Result<Self, GovernanceError> {
+        Ok(Self {
+            operation_nonce: reader.read_u64()?,
+            bump: reader.read_u8()?,
+            results_hash: reader.read_array()?,
+            results_hash_scheme: read_hash_scheme(reader)?,
+            winning_option_id: reader.read_array()?,
+            total_votes_cast: reader.read_u64()?,
+            quorum_met: reader.read_bool()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrgConfigArgs {
+    pub bump: u8,
+    pub default_quorum_requirement: u64,
+    pub default_voting_duration_seconds: i64,
+    pub min_voting_duration_seconds: i64,
+    pub max_voting_duration_seconds: i64,
+}
+
+impl OrgConfigArgs {
+    fn read(reader: &mut Reader) -> Result<Self, GovernanceError> {
+        Ok(Self {
+            bump: reader.read_u8()?,
+            default_quorum_requirement: reader.read_u64()?,
+            default_voting_duration_seconds: reader.read_i64()?,
+            min_voting_duration_seconds: reader.read_i64()?,
+            max_voting_duration_seconds: reader.read_i64()?,
+        })
+    }
+}
+
+fn read_hash_scheme(reader: &mut Reader) -> Result<HashScheme, GovernanceError> {
+    HashScheme::try_from(reader.read_u8()?).map_err(|_| GovernanceError::InvalidInstruction)
+}
+
+/// Whether `data` is a governance instruction rather than a log payload.
+pub fn is_governance_instruction(data: &[u8]) -> bool {
+    data.first() == Some(&GOVERNANCE_PREFIX)
+}
+
+impl<'a> GovernanceInstruction<'a> {
+    pub fn unpack(data: &'a [u8]) -> Result<Self, GovernanceError> {
+        let [GOVERNANCE_PREFIX, tag, payload @ ..] = data else {
+            return Err(GovernanceError::InvalidInstruction);
+        };
+        let mut reader = Reader::new(payload, GovernanceError::InvalidInstruction);
+
+        let instruction = match *tag {
+            CREATE_ORGANIZATION => Self::CreateOrganization {
+                organization_id: reader.read_array()?,
+                bump: reader.read_u8()?,
+                recovery_authority: Address::new_from_array(reader.read_array()?),
+                name: reader.read_short_bytes()?,
+            },
+            CREATE_PROPOSAL => Self::CreateProposal(CreateProposalArgs::read(&mut reader)?),
+            UPDATE_PROPOSAL_STATUS => Self::UpdateProposalStatus {
+                operation_nonce: reader.read_u64()?,
+                status: ProposalStatus::try_from(reader.read_u8()?)
+                    .map_err(|_| GovernanceError::InvalidInstruction)?,
+            },
+            COMMIT_VOTE_RESULTS => {
+                Self::CommitVoteResults(CommitVoteResultsArgs::read(&mut reader)?)
+            }
+            CREATE_SEASON => Self::CreateSeason {
+                season_id: reader.read_array()?,
+                bump: reader.read_u8()?,
+                start_at: reader.read_i64()?,
+                end_at: reader.read_i64()?,
+            },
+            ADD_ADAPTER_SIGNER => Self::AddAdapterSigner {
+                signer: Address::new_from_array(reader.read_array()?),
+            },
+            REMOVE_ADAPTER_SIGNER => Self::RemoveAdapterSigner {
+                signer: Address::new_from_array(reader.read_array()?),
+            },
+            INITIATE_RECOVERY => Self::InitiateRecovery {
+                new_authority: Address::new_from_array(reader.read_array()?),
+            },
+            CANCEL_RECOVERY => Self::CancelRecovery,
+            COMPLETE_RECOVERY => Self::CompleteRecovery,
+            CREATE_PROPOSALS_BATCH => Self::CreateProposalsBatch(ProposalBatch::read(&mut reader)?),
+            OPEN_PROPOSAL_WITH_SNAPSHOT => Self::OpenProposalWithSnapshot {
+                operation_nonce: reader.read_u64()?,
+                snapshot_slot: reader.read_u64()?,
+                voter_merkle_root: reader.read_array()?,
+                eligible_voting_power: reader.read_u64()?,
+            },
+            CLOSE_AND_COMMIT_RESULTS => {
+                Self::CloseAndCommitResults(CommitVoteResultsArgs::read(&mut reader)?)
+            }
+            SET_ELIGIBLE_VOTING_POWER => Self::SetEligibleVotingPower {
+                operation_nonce: reader.read_u64()?,
+                eligible_voting_power: reader.read_u64()?,
+            },
+            LOG_VERSION => Self::LogVersion,
+            FREEZE_ORGANIZATION => Self::FreezeOrganization,
+            CLOSE_ORGANIZATION => Self::CloseOrganization,
+            ARCHIVE_PROPOSAL => Self::ArchiveProposal,
+            CREATE_ORG_STATS => Self::CreateOrgStats {
+                bump: reader.read_u8()?,
+            },
+            CLOSE_SEASON => Self::CloseSeason,
+            CLOSE_ORG_STATS => Self::CloseOrgStats,
+            UPDATE_PROPOSAL_DRAFT => {
+                Self::UpdateProposalDraft(UpdateProposalDraftArgs::read(&mut reader)?)
+            }
+            EXTEND_VOTING_PERIOD => Self::ExtendVotingPeriod {
+                end_at: reader.read_i64()?,
+            },
+            SET_DISPUTE_POLICY => Self::SetDisputePolicy {
+                window_seconds: reader.read_i64()?,
+                bond_lamports: reader.read_u64()?,
+            },
+            DISPUTE_RESULTS => Self::DisputeResults,
+            FINALIZE_PROPOSAL => Self::FinalizeProposal,
+            VERIFY_RESULTS_HASH => Self::VerifyResultsHash {
+                preimage: reader.read_bytes(reader.remaining().len())?,
+            },
+            SET_ORG_CONFIG => Self::SetOrgConfig(OrgConfigArgs::read(&mut reader)?),
+            CLOSE_ORG_CONFIG => Self::CloseOrgConfig,
+            CREATE_AUDIT_LOG => Self::CreateAuditLog {
+                bump: reader.read_u8()?,
+            },
+            CLOSE_AUDIT_LOG => Self::CloseAuditLog,
+            DISCARD_PROPOSAL => Self::DiscardProposal,
+            _ => return Err(GovernanceError::InvalidInstruction),
+        };
+
+        reader.finish()?;
+        Ok(instruction)
+    }
+}
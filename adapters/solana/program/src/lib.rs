@@ -1,19 +1,50 @@
 use pinocchio::{
     account::AccountView,
     Address,
-    entrypoint,
     ProgramResult,
     error::ProgramError,
 };
 use pinocchio_log::log;
 
-entrypoint!(process_instruction);
+pub mod codec;
+pub mod error;
+pub mod instruction;
+pub mod math;
+pub mod pda;
+pub mod processor;
+pub mod results;
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+pub mod state;
+pub mod system;
+
+use instruction::{is_governance_instruction, GovernanceInstruction};
+
+/// Semantic version of the program interface. Logged by the `log_version`
+/// instruction and appended to every `FE_GOV:` event line, so backends can
+/// check a cluster's deployment before submitting instructions to it.
+pub const PROGRAM_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg(not(feature = "no-entrypoint"))]
+pinocchio::entrypoint!(process_instruction);
 
 pub fn process_instruction(
-    _program_id: &Address,
+    program_id: &Address,
     accounts: &[AccountView],
     instruction_data: &[u8],
 ) -> ProgramResult {
+    // Governance instructions start with a byte that never occurs in UTF-8.
+    // Everything else (the adapter's JSON payloads) goes through the
+    // transparency log.
+    if is_governance_instruction(instruction_data) {
+        let instruction = GovernanceInstruction::unpack(instruction_data)?;
+        processor::process(program_id, accounts, instruction)
+    } else {
+        process_log(accounts, instruction_data)
+    }
+}
+
+fn process_log(accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
     // 1. Authenticate: Ensure the signer is authorized
     if accounts.is_empty() {
         return Err(ProgramError::NotEnoughAccountKeys);
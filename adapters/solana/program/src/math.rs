@@ -0,0 +1,85 @@
+//! Overflow-safe arithmetic for counters and voting power.
+//!
+//! Checked variants report `GovernanceError::ArithmeticOverflow` so handlers
+//! can use `?`. Saturating variants are for display-only values such as
+//! statistics, where clamping is preferable to failing the instruction.
+
+use crate::error::GovernanceError;
+
+/// Basis points in 100%.
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+pub fn checked_add(a: u64, b: u64) -> Result<u64, GovernanceError> {
+    a.checked_add(b).ok_or(GovernanceError::ArithmeticOverflow)
+}
+
+pub fn checked_mul(a: u64, b: u64) -> Result<u64, GovernanceError> {
+    a.checked_mul(b).ok_or(GovernanceError::ArithmeticOverflow)
+}
+
+pub fn checked_add_i64(a: i64, b: i64) -> Result<i64, GovernanceError> {
+    a.checked_add(b).ok_or(GovernanceError::ArithmeticOverflow)
+}
+
+/// Adds one to a counter in place.
+pub fn increment(counter: &mut u64) -> Result<(), GovernanceError> {
+    *counter = checked_add(*counter, 1)?;
+    Ok(())
+}
+
+/// Subtracts one from a counter in place.
+pub fn decrement(counter: &mut u64) -> Result<(), GovernanceError> {
+    *counter = counter
+        .checked_sub(1)
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+pub fn saturating_mul(a: u64, b: u64) -> u64 {
+    a.saturating_mul(b)
+}
+
+/// `value * bps / 10_000`, rounded down. Basis points above 100% are allowed
+/// as long as the result fits in a `u64`.
+pub fn apply_bps(value: u64, bps: u16) -> Result<u64, GovernanceError> {
+    let scaled = value as u128 * bps as u128 / BPS_DENOMINATOR as u128;
+    u64::try_from(scaled).map_err(|_| GovernanceError::ArithmeticOverflow)
+}
+
+/// `value * bps / 10_000`, rounded up. Use for thresholds, so that e.g. a 50%
+/// quorum of 3 votes needs 2 rather than 1.
+pub fn apply_bps_ceil(value: u64, bps: u16) -> Result<u64, GovernanceError> {
+    let scaled = (value as u128 * bps as u128).div_ceil(BPS_DENOMINATOR as u128);
+    u64::try_from(scaled).map_err(|_| GovernanceError::ArithmeticOverflow)
+}
+
+/// `part` as basis points of `whole`, rounded down, or `0` when `whole` is
+/// zero. Exceeds 10_000 when `part` does, saturating at `u64::MAX`.
+pub fn ratio_bps(part: u64, whole: u64) -> u64 {
+    if whole == 0 {
+        return 0;
+    }
+    let scaled = part as u128 * BPS_DENOMINATOR as u128 / whole as u128;
+    u64::try_from(scaled).unwrap_or(u64::MAX)
+}
+
+/// Largest `r` with `r * r <= value`.
+pub fn isqrt(value: u64) -> u64 {
+    // Digit-by-digit method: no division, and exact for every input.
+    let mut remainder = value;
+    let mut root = 0u64;
+    let mut bit = 1u64 << 62;
+    while bit > value {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if remainder >= root + bit {
+            remainder -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
@@ -0,0 +1,106 @@
+//! PDA seeds and verification.
+//!
+//! The organization, proposal, and results seed strings are the ones the
+//! archived Anchor program used. Addresses also depend on the program id, so
+//! accounts of the old deployment are not reachable through this program.
+//!
+//! - Organization: `[b"organization", organization_id]`
+//! - Proposal: `[b"proposal", organization, proposal_id]`
+//! - Proposal results: `[b"proposal_results", proposal]`
+//! - Season: `[b"season", organization, season_id]`
+//! - Organization stats: `[b"org_stats", organization]`
+//! - Organization config: `[b"org_config", organization]`
+//! - Audit log: `[b"audit_log", organization]`
+//!
+//! Callers pass the bump in instruction data, and creation only accepts the
+//! canonical one that `find_program_address` returns. Any other off-curve bump
+//! would be a second valid address for the same seeds, so the same
+//! organization, proposal, or results could exist twice. `check_pda` derives
+//! the address from the supplied bump rather than searching for it, and proves
+//! the bump canonical by checking that every higher bump is on the curve.
+//! Tests, tools, and backends should find addresses and bumps with the
+//! `find_*_pda` helpers below rather than repeating the seeds.
+
+use pinocchio::{account::AccountView, address::MAX_SEEDS, error::ProgramError, Address};
+
+use crate::error::GovernanceError;
+
+pub const ORGANIZATION_SEED: &[u8] = b"organization";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const RESULTS_SEED: &[u8] = b"proposal_results";
+pub const SEASON_SEED: &[u8] = b"season";
+pub const ORG_STATS_SEED: &[u8] = b"org_stats";
+pub const ORG_CONFIG_SEED: &[u8] = b"org_config";
+pub const AUDIT_LOG_SEED: &[u8] = b"audit_log";
+
+/// Verifies that `account` lives at the PDA derived from `seeds` (without the
+/// bump) and that `bump` is its canonical bump.
+pub fn check_pda(
+    account: &AccountView,
+    seeds: &[&[u8]],
+    bump: u8,
+    program_id: &Address,
+) -> Result<(), ProgramError> {
+    let expected = create_with_bump(seeds, bump, program_id).ok_or(GovernanceError::InvalidPda)?;
+    if account.address() != &expected {
+        return Err(GovernanceError::InvalidPda.into());
+    }
+    // `find_program_address` returns the highest off-curve bump, so `bump` is
+    // canonical only if every bump above it is on the curve. Canonical bumps
+    // are 255 or just below it, so this rarely costs more than one derivation.
+    // The seeds already derived once, so a failure here can only mean the
+    // address is on the curve.
+    for higher in (bump..=u8::MAX).skip(1) {
+        if create_with_bump(seeds, higher, program_id).is_some() {
+            return Err(GovernanceError::InvalidPda.into());
+        }
+    }
+    Ok(())
+}
+
+fn create_with_bump(seeds: &[&[u8]], bump: u8, program_id: &Address) -> Option<Address> {
+    if seeds.len() >= MAX_SEEDS {
+        return None;
+    }
+    let bump = [bump];
+    let mut seeds_with_bump: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+    seeds_with_bump[..seeds.len()].copy_from_slice(seeds);
+    seeds_with_bump[seeds.len()] = &bump;
+    Address::create_program_address(&seeds_with_bump[..=seeds.len()], program_id).ok()
+}
+
+pub fn find_organization_pda(organization_id: &[u8; 16], program_id: &Address) -> (Address, u8) {
+    Address::find_program_address(&[ORGANIZATION_SEED, organization_id], program_id)
+}
+
+pub fn find_proposal_pda(
+    organization: &Address,
+    proposal_id: &[u8; 16],
+    program_id: &Address,
+) -> (Address, u8) {
+    Address::find_program_address(&[PROPOSAL_SEED, organization.as_ref(), proposal_id], program_id)
+}
+
+pub fn find_results_pda(proposal: &Address, program_id: &Address) -> (Address, u8) {
+    Address::find_program_address(&[RESULTS_SEED, proposal.as_ref()], program_id)
+}
+
+pub fn find_season_pda(
+    organization: &Address,
+    season_id: &[u8; 16],
+    program_id: &Address,
+) -> (Address, u8) {
+    Address::find_program_address(&[SEASON_SEED, organization.as_ref(), season_id], program_id)
+}
+
+pub fn find_org_stats_pda(organization: &Address, program_id: &Address) -> (Address, u8) {
+    Address::find_program_address(&[ORG_STATS_SEED, organization.as_ref()], program_id)
+}
+
+pub fn find_org_config_pda(organization: &Address, program_id: &Address) -> (Address, u8) {
+    Address::find_program_address(&[ORG_CONFIG_SEED, organization.as_ref()], program_id)
+}
+
+pub fn find_audit_log_pda(organization: &Address, program_id: &Address) -> (Address, u8) {
+    Address::find_program_address(&[AUDIT_LOG_SEED, organization.as_ref()], program_id)
+}
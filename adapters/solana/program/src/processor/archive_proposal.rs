@@ -0,0 +1,57 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{
+    close_account, load_audit_trail, load_owned_organization, log_event, now, shrink_account,
+};
+use crate::{
+    error::GovernanceError,
+    instruction::ARCHIVE_PROPOSAL,
+    math,
+    state::{AccountState, Proposal, ProposalResults, ProposalStatus, ProposalSummary},
+};
+
+/// Archives a finalized proposal in place: closes its results account and
+/// shrinks the proposal account to a `ProposalSummary`, refunding the freed
+/// rent to the authority.
+///
+/// The summary keeps the proposal address taken, and `create_proposal` fails
+/// with `ProposalArchived` for its id. Archiving is allowed on a frozen
+/// organization, so its proposals can be archived before it closes.
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [authority, organization, proposal_account, results_account, remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_owned_organization(organization, authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+
+    let proposal = Proposal::load(proposal_account, program_id)?;
+    if &proposal.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+    if proposal.status != ProposalStatus::Finalized {
+        return Err(GovernanceError::ProposalNotFinalized.into());
+    }
+    if results_account.address() != &proposal.results {
+        return Err(GovernanceError::ProposalMismatch.into());
+    }
+    let results = ProposalResults::load(results_account, program_id)?;
+    if &results.proposal != proposal_account.address() {
+        return Err(GovernanceError::ProposalMismatch.into());
+    }
+
+    let now = now()?;
+    let summary = ProposalSummary::new(&proposal, &results);
+    close_account(results_account, authority)?;
+    shrink_account(proposal_account, authority, ProposalSummary::LEN)?;
+    summary.store(proposal_account)?;
+
+    math::increment(&mut org.archived_proposal_count)?;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(authority, ARCHIVE_PROPOSAL, proposal_account.address(), now)?;
+
+    log_event("archive_proposal");
+    Ok(())
+}
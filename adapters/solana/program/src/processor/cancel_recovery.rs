@@ -0,0 +1,22 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{load_audit_trail, load_authorized_organization, log_event, now};
+use crate::{instruction::CANCEL_RECOVERY, state::AccountState};
+
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [authority, organization, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_authorized_organization(organization, authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    let pending_authority = org.pending_authority;
+    org.cancel_recovery()?;
+    let now = now()?;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(authority, CANCEL_RECOVERY, &pending_authority, now)?;
+
+    log_event("cancel_recovery");
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{
+    commit_vote_results::write_results, load_audit_trail, load_operator_organization, log_event,
+    log_transition, now,
+};
+use crate::{
+    error::GovernanceError,
+    instruction::{CommitVoteResultsArgs, CLOSE_AND_COMMIT_RESULTS},
+    state::{AccountState, Proposal, ProposalStatus},
+};
+
+/// Closes an open proposal and records its results commitment, consuming a
+/// single operation nonce for both.
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    args: CommitVoteResultsArgs,
+) -> ProgramResult {
+    let [authority, organization, proposal_account, results_account, _system_program, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_operator_organization(organization, authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    org.advance_operation_nonce(args.operation_nonce)?;
+
+    let mut proposal = Proposal::load(proposal_account, program_id)?;
+    if &proposal.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+    let now = now()?;
+    proposal.transition(ProposalStatus::Closed, now)?;
+    proposal.validate_votes_cast(args.total_votes_cast)?;
+
+    write_results(
+        program_id,
+        authority,
+        proposal_account,
+        results_account,
+        &args,
+        now,
+        org.dispute_window_seconds,
+    )?;
+
+    proposal.results = *results_account.address();
+    proposal.store(proposal_account)?;
+    org.store(organization)?;
+    audit.record(
+        authority,
+        CLOSE_AND_COMMIT_RESULTS,
+        proposal_account.address(),
+        now,
+    )?;
+
+    log_transition(&proposal);
+    log_event("close_and_commit_results");
+    Ok(())
+}
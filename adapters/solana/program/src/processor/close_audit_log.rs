@@ -0,0 +1,31 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{close_account, load_owned_organization, log_event, now};
+use crate::{
+    error::GovernanceError,
+    state::{AccountState, AuditLog},
+};
+
+/// Closes the audit log of a frozen organization. Its entries remain in the
+/// transaction history of the instructions that wrote them.
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [authority, organization, log_account, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_owned_organization(organization, authority, program_id)?;
+    org.require_frozen()?;
+    let log = AuditLog::load(log_account, program_id)?;
+    if &log.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+
+    close_account(log_account, authority)?;
+
+    org.has_audit_log = false;
+    org.updated_at = now()?;
+    org.store(organization)?;
+
+    log_event("close_audit_log");
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{close_account, load_audit_trail, load_owned_organization, log_event, now};
+use crate::{
+    error::GovernanceError,
+    instruction::CLOSE_ORG_CONFIG,
+    state::{AccountState, OrgConfig},
+};
+
+/// Closes the config account of a frozen organization, which no longer
+/// creates or changes proposals.
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [authority, organization, config_account, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_owned_organization(organization, authority, program_id)?;
+    org.require_frozen()?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    let config = OrgConfig::load(config_account, program_id)?;
+    if &config.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+
+    close_account(config_account, authority)?;
+
+    org.has_config = false;
+    let now = now()?;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(authority, CLOSE_ORG_CONFIG, config_account.address(), now)?;
+
+    log_event("close_org_config");
+    Ok(())
+}
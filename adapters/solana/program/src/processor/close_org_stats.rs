@@ -0,0 +1,35 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{close_account, load_audit_trail, load_owned_organization, log_event, now};
+use crate::{
+    error::GovernanceError,
+    instruction::CLOSE_ORG_STATS,
+    state::{AccountState, OrgStats},
+};
+
+/// Closes the stats account of a frozen organization, which no longer
+/// finalizes proposals into it.
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [authority, organization, stats_account, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_owned_organization(organization, authority, program_id)?;
+    org.require_frozen()?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    let stats = OrgStats::load(stats_account, program_id)?;
+    if &stats.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+
+    close_account(stats_account, authority)?;
+
+    org.has_stats = false;
+    let now = now()?;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(authority, CLOSE_ORG_STATS, stats_account.address(), now)?;
+
+    log_event("close_org_stats");
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{load_owned_organization, log_event, now, shrink_account};
+use crate::state::{AccountState, ClosedOrganization};
+
+/// Closes a frozen organization whose proposals are all archived or
+/// discarded. Freezing blocks every other change, so it loads the
+/// organization through `load_owned_organization`.
+///
+/// The account is shrunk to a `ClosedOrganization` marker rather than
+/// removed, so `create_organization` cannot hand the same id, and with it the
+/// addresses of the old proposals and seasons, to a new authority.
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [authority, organization, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let org = load_owned_organization(organization, authority, program_id)?;
+    let now = now()?;
+    org.check_closable(now)?;
+
+    shrink_account(organization, authority, ClosedOrganization::LEN)?;
+    ClosedOrganization::new(&org, now).store(organization)?;
+
+    log_event("close_organization");
+    Ok(())
+}
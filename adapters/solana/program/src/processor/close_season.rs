@@ -0,0 +1,31 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{
+    close_account, load_audit_trail, load_organization_season, load_owned_organization, log_event,
+    now,
+};
+use crate::{instruction::CLOSE_SEASON, math, state::AccountState};
+
+/// Closes a season of a frozen organization. Freezing first guarantees no
+/// proposal in the season can still be finalized into it.
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [authority, organization, season, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_owned_organization(organization, authority, program_id)?;
+    org.require_frozen()?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    load_organization_season(season, organization, program_id)?;
+
+    close_account(season, authority)?;
+
+    math::decrement(&mut org.season_count)?;
+    let now = now()?;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(authority, CLOSE_SEASON, season.address(), now)?;
+
+    log_event("close_season");
+    Ok(())
+}
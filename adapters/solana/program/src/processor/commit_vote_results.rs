@@ -0,0 +1,128 @@
+use pinocchio::{
+    account::AccountView,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    Address, ProgramResult,
+};
+
+use super::{load_audit_trail, load_operator_organization, log_event, now};
+use crate::{
+    error::GovernanceError,
+    instruction::{CommitVoteResultsArgs, COMMIT_VOTE_RESULTS},
+    math,
+    pda::{check_pda, RESULTS_SEED},
+    state::{AccountState, Proposal, ProposalResults, ProposalStatus},
+    system::create_pda_account,
+};
+
+/// Records the results commitment for a closed proposal.
+///
+/// The program stores `results_hash` as given; clients produce it with
+/// `results::compute_results_hash` so anyone can recompute it from the tally.
+/// `results_hash_scheme` must be one `HashScheme::check_supported` accepts.
+///
+/// Each proposal has exactly one results PDA (canonical bump only), and it can
+/// only be created once, so a commitment is immutable.
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    args: CommitVoteResultsArgs,
+) -> ProgramResult {
+    let [authority, organization, proposal_account, results_account, _system_program, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_operator_organization(organization, authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    org.advance_operation_nonce(args.operation_nonce)?;
+
+    let mut proposal = Proposal::load(proposal_account, program_id)?;
+    if &proposal.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+    if proposal.status != ProposalStatus::Closed {
+        return Err(GovernanceError::ProposalNotClosed.into());
+    }
+    proposal.validate_votes_cast(args.total_votes_cast)?;
+
+    let now = now()?;
+    write_results(
+        program_id,
+        authority,
+        proposal_account,
+        results_account,
+        &args,
+        now,
+        org.dispute_window_seconds,
+    )?;
+
+    proposal.results = *results_account.address();
+    proposal.updated_at = now;
+    proposal.store(proposal_account)?;
+    org.store(organization)?;
+    audit.record(
+        authority,
+        COMMIT_VOTE_RESULTS,
+        proposal_account.address(),
+        now,
+    )?;
+
+    log_event("commit_vote_results");
+    Ok(())
+}
+
+/// Creates the results PDA for `proposal_account` and writes the commitment,
+/// open to disputes for `dispute_window_seconds`. Callers check the
+/// proposal's organization and status, and record the results address on the
+/// proposal.
+pub(super) fn write_results(
+    program_id: &Address,
+    payer: &AccountView,
+    proposal_account: &AccountView,
+    results_account: &AccountView,
+    args: &CommitVoteResultsArgs,
+    now: i64,
+    dispute_window_seconds: i64,
+) -> ProgramResult {
+    args.results_hash_scheme.check_supported()?;
+    check_pda(
+        results_account,
+        &[RESULTS_SEED, proposal_account.address().as_ref()],
+        args.bump,
+        program_id,
+    )?;
+
+    let bump_seed = [args.bump];
+    let seeds = [
+        Seed::from(RESULTS_SEED),
+        Seed::from(proposal_account.address().as_ref()),
+        Seed::from(&bump_seed),
+    ];
+    create_pda_account(
+        payer,
+        results_account,
+        ProposalResults::LEN,
+        program_id,
+        Signer::from(&seeds),
+    )?;
+
+    ProposalResults {
+        bump: args.bump,
+        proposal: *proposal_account.address(),
+        results_hash: args.results_hash,
+        results_hash_scheme: args.results_hash_scheme,
+        winning_option_id: args.winning_option_id,
+        total_votes_cast: args.total_votes_cast,
+        quorum_met: args.quorum_met,
+        committed_at: now,
+        finalized_at: 0,
+        dispute_ends_at: math::checked_add_i64(now, dispute_window_seconds)?,
+        disputed_by: Address::default(),
+        disputed_at: 0,
+        dispute_bond: 0,
+        hash_verified: false,
+    }
+    .store(results_account)
+}
@@ -0,0 +1,21 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{load_audit_trail, load_recovery_organization, log_event, now};
+use crate::{instruction::COMPLETE_RECOVERY, state::AccountState};
+
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [recovery_authority, organization, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_recovery_organization(organization, recovery_authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    let now = now()?;
+    org.complete_recovery(now)?;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(recovery_authority, COMPLETE_RECOVERY, &org.authority, now)?;
+
+    log_event("complete_recovery");
+    Ok(())
+}
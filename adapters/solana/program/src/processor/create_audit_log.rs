@@ -0,0 +1,67 @@
+use pinocchio::{
+    account::AccountView,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    Address, ProgramResult,
+};
+
+use super::{load_authorized_organization, log_event, now, AuditTrail};
+use crate::{
+    instruction::CREATE_AUDIT_LOG,
+    pda::{check_pda, AUDIT_LOG_SEED},
+    state::{AccountState, AuditLog},
+    system::create_pda_account,
+};
+
+/// Creates the audit log with its own creation as the first entry. Actions
+/// taken before it exists are not backfilled.
+pub fn process(program_id: &Address, accounts: &[AccountView], bump: u8) -> ProgramResult {
+    let [authority, organization, log_account, _system_program, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_authorized_organization(organization, authority, program_id)?;
+
+    check_pda(
+        log_account,
+        &[AUDIT_LOG_SEED, organization.address().as_ref()],
+        bump,
+        program_id,
+    )?;
+
+    let bump_seed = [bump];
+    let seeds = [
+        Seed::from(AUDIT_LOG_SEED),
+        Seed::from(organization.address().as_ref()),
+        Seed::from(&bump_seed),
+    ];
+    create_pda_account(
+        authority,
+        log_account,
+        AuditLog::LEN,
+        program_id,
+        Signer::from(&seeds),
+    )?;
+
+    let now = now()?;
+    let log = AuditLog {
+        bump,
+        organization: *organization.address(),
+        entry_count: 0,
+        entries: Default::default(),
+        created_at: now,
+    };
+    AuditTrail(Some((log_account, log))).record(
+        authority,
+        CREATE_AUDIT_LOG,
+        log_account.address(),
+        now,
+    )?;
+
+    org.has_audit_log = true;
+    org.updated_at = now;
+    org.store(organization)?;
+
+    log_event("create_audit_log");
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use pinocchio::{
+    account::AccountView,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    Address, ProgramResult,
+};
+
+use super::{load_audit_trail, load_authorized_organization, log_event, now};
+use crate::{
+    instruction::CREATE_ORG_STATS,
+    pda::{check_pda, ORG_STATS_SEED},
+    state::{AccountState, OrgStats},
+    system::create_pda_account,
+};
+
+/// Creates the stats account with zeroed counters. Proposals finalized
+/// before it exists are not backfilled.
+pub fn process(program_id: &Address, accounts: &[AccountView], bump: u8) -> ProgramResult {
+    let [authority, organization, stats, _system_program, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_authorized_organization(organization, authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+
+    check_pda(
+        stats,
+        &[ORG_STATS_SEED, organization.address().as_ref()],
+        bump,
+        program_id,
+    )?;
+
+    let bump_seed = [bump];
+    let seeds = [
+        Seed::from(ORG_STATS_SEED),
+        Seed::from(organization.address().as_ref()),
+        Seed::from(&bump_seed),
+    ];
+    create_pda_account(
+        authority,
+        stats,
+        OrgStats::LEN,
+        program_id,
+        Signer::from(&seeds),
+    )?;
+
+    let now = now()?;
+    OrgStats {
+        bump,
+        organization: *organization.address(),
+        quorum_met_count: 0,
+        quorum_failed_count: 0,
+        total_votes_cast: 0,
+        turnout_bps_sum: 0,
+        created_at: now,
+        updated_at: now,
+    }
+    .store(stats)?;
+
+    org.has_stats = true;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(authority, CREATE_ORG_STATS, stats.address(), now)?;
+
+    log_event("create_org_stats");
+    Ok(())
+}
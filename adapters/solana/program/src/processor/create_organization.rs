@@ -0,0 +1,84 @@
+use pinocchio::{
+    account::AccountView,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    Address, ProgramResult,
+};
+
+use super::{log_event, now, require_signer, validate_text};
+use crate::{
+    error::GovernanceError,
+    pda::{check_pda, ORGANIZATION_SEED},
+    state::{fixed_bytes, AccountState, ClosedOrganization, Organization, MAX_NAME_LEN},
+    system::create_pda_account,
+};
+
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    organization_id: [u8; 16],
+    bump: u8,
+    recovery_authority: Address,
+    name: &[u8],
+) -> ProgramResult {
+    let [authority, organization, _system_program, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_signer(authority)?;
+    validate_text(name, MAX_NAME_LEN, GovernanceError::InvalidName)?;
+
+    check_pda(
+        organization,
+        &[ORGANIZATION_SEED, &organization_id],
+        bump,
+        program_id,
+    )?;
+    if ClosedOrganization::load(organization, program_id).is_ok() {
+        return Err(GovernanceError::OrganizationClosed.into());
+    }
+
+    let bump_seed = [bump];
+    let seeds = [
+        Seed::from(ORGANIZATION_SEED),
+        Seed::from(&organization_id),
+        Seed::from(&bump_seed),
+    ];
+    create_pda_account(
+        authority,
+        organization,
+        Organization::LEN,
+        program_id,
+        Signer::from(&seeds),
+    )?;
+
+    let now = now()?;
+    Organization {
+        bump,
+        organization_id,
+        authority: *authority.address(),
+        proposal_count: 0,
+        archived_proposal_count: 0,
+        season_count: 0,
+        has_stats: false,
+        has_config: false,
+        has_audit_log: false,
+        operation_nonce: 0,
+        adapter_signer_count: 0,
+        adapter_signers: Default::default(),
+        recovery_authority,
+        pending_authority: Address::default(),
+        recovery_initiated_at: 0,
+        created_at: now,
+        updated_at: now,
+        frozen_at: 0,
+        dispute_window_seconds: 0,
+        dispute_bond_lamports: 0,
+        name_len: name.len() as u8,
+        name: fixed_bytes(name),
+    }
+    .store(organization)?;
+
+    log_event("create_organization");
+    Ok(())
+}
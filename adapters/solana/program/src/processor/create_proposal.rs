@@ -0,0 +1,240 @@
+use pinocchio::{
+    account::AccountView,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    Address, ProgramResult,
+};
+
+use super::{
+    load_audit_trail, load_authorized_organization, load_organization_config,
+    load_organization_season, log_event, now, validate_text, AuditTrail,
+};
+use crate::{
+    error::GovernanceError,
+    instruction::{CreateProposalArgs, CREATE_PROPOSAL, USE_DEFAULT_END_AT, USE_DEFAULT_QUORUM},
+    math,
+    pda::{check_pda, PROPOSAL_SEED},
+    state::{
+        fixed_bytes, AccountState, OrgConfig, Organization, Proposal, ProposalStatus,
+        ProposalSummary, Season, MAX_TITLE_LEN,
+    },
+    system::create_pda_account,
+};
+
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    args: CreateProposalArgs,
+) -> ProgramResult {
+    let [authority, organization, proposal, _system_program, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut creator = ProposalCreator::load(
+        program_id,
+        authority,
+        organization,
+        remaining,
+        CREATE_PROPOSAL,
+    )?;
+    creator.create(proposal, &args)?;
+    creator.store()?;
+
+    log_event("create_proposal");
+    Ok(())
+}
+
+/// Creates proposals for one organization and optional season, counting them
+/// on both and recording each in the audit log under `instruction`. Shared by
+/// `create_proposal` and `create_proposals_batch`.
+pub(super) struct ProposalCreator<'a> {
+    program_id: &'a Address,
+    authority: &'a AccountView,
+    organization: &'a AccountView,
+    org: Organization,
+    config: Option<OrgConfig>,
+    season: Option<(&'a AccountView, Season)>,
+    audit: AuditTrail<'a>,
+    instruction: u8,
+    now: i64,
+}
+
+impl<'a> ProposalCreator<'a> {
+    pub(super) fn load(
+        program_id: &'a Address,
+        authority: &'a AccountView,
+        organization: &'a AccountView,
+        remaining: &'a [AccountView],
+        instruction: u8,
+    ) -> Result<Self, ProgramError> {
+        let org = load_authorized_organization(organization, authority, program_id)?;
+        let (audit, remaining) = load_audit_trail(remaining, organization, &org, program_id)?;
+        let (config, remaining) =
+            load_organization_config(remaining, organization, &org, program_id)?;
+        let season = match remaining.first() {
+            Some(account) => Some((
+                account,
+                load_organization_season(account, organization, program_id)?,
+            )),
+            None => None,
+        };
+        Ok(Self {
+            program_id,
+            authority,
+            organization,
+            org,
+            config,
+            season,
+            audit,
+            instruction,
+            now: now()?,
+        })
+    }
+
+    pub(super) fn create(
+        &mut self,
+        proposal: &AccountView,
+        args: &CreateProposalArgs,
+    ) -> ProgramResult {
+        args.content_hash_scheme.check_supported()?;
+        let mut end_at = args.end_at;
+        let mut quorum_requirement = args.quorum_requirement;
+        if let Some(config) = &self.config {
+            if end_at == USE_DEFAULT_END_AT {
+                end_at = config.default_end_at(args.start_at)?;
+            }
+            if quorum_requirement == USE_DEFAULT_QUORUM {
+                quorum_requirement = config.default_quorum_requirement;
+            }
+        }
+        validate_proposal(
+            args.title,
+            args.start_at,
+            end_at,
+            quorum_requirement,
+            args.eligible_voting_power,
+            self.now,
+            self.season.as_ref().map(|(_, season)| season),
+        )?;
+        if let Some(config) = &self.config {
+            config.check_voting_duration(args.start_at, end_at)?;
+        }
+
+        check_pda(
+            proposal,
+            &[
+                PROPOSAL_SEED,
+                self.organization.address().as_ref(),
+                &args.proposal_id,
+            ],
+            args.bump,
+            self.program_id,
+        )?;
+        if ProposalSummary::load(proposal, self.program_id).is_ok() {
+            return Err(GovernanceError::ProposalArchived.into());
+        }
+
+        let bump_seed = [args.bump];
+        let seeds = [
+            Seed::from(PROPOSAL_SEED),
+            Seed::from(self.organization.address().as_ref()),
+            Seed::from(&args.proposal_id),
+            Seed::from(&bump_seed),
+        ];
+        create_pda_account(
+            self.authority,
+            proposal,
+            Proposal::LEN,
+            self.program_id,
+            Signer::from(&seeds),
+        )?;
+
+        math::increment(&mut self.org.proposal_count)?;
+
+        let season_address = match &mut self.season {
+            Some((account, season)) => {
+                math::increment(&mut season.proposal_count)?;
+                *account.address()
+            }
+            None => Address::default(),
+        };
+
+        Proposal {
+            bump: args.bump,
+            status: ProposalStatus::Draft,
+            organization: *self.organization.address(),
+            proposal_id: args.proposal_id,
+            created_by: *self.authority.address(),
+            season: season_address,
+            results: Address::default(),
+            content_hash: args.content_hash,
+            content_hash_scheme: args.content_hash_scheme,
+            start_at: args.start_at,
+            end_at,
+            original_end_at: 0,
+            quorum_requirement,
+            eligible_voting_power: args.eligible_voting_power,
+            snapshot_slot: 0,
+            voter_merkle_root: [0; 32],
+            created_at: self.now,
+            updated_at: self.now,
+            opened_at: 0,
+            closed_at: 0,
+            amendment_count: 0,
+            history_hash: [0; 32],
+            title_len: args.title.len() as u8,
+            title: fixed_bytes(args.title),
+        }
+        .store(proposal)?;
+        self.audit.record(
+            self.authority,
+            self.instruction,
+            proposal.address(),
+            self.now,
+        )
+    }
+
+    /// Writes back the organization and season counters.
+    pub(super) fn store(mut self) -> ProgramResult {
+        self.org.updated_at = self.now;
+        self.org.store(self.organization)?;
+        if let Some((account, mut season)) = self.season {
+            season.updated_at = self.now;
+            season.store(account)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks the fields a proposal is created or amended with: a valid title, a
+/// voting window that starts, lasts between the duration limits, ends after
+/// `now` and fits in `season` if there is one, and a quorum the eligible
+/// voting power can meet.
+pub(super) fn validate_proposal(
+    title: &[u8],
+    start_at: i64,
+    end_at: i64,
+    quorum_requirement: u64,
+    eligible_voting_power: u64,
+    now: i64,
+    season: Option<&Season>,
+) -> ProgramResult {
+    validate_text(title, MAX_TITLE_LEN, GovernanceError::InvalidTitle)?;
+    if start_at == 0 {
+        return Err(GovernanceError::MissingStartDate.into());
+    }
+    if end_at <= start_at {
+        return Err(GovernanceError::InvalidVotingWindow.into());
+    }
+    Proposal::validate_voting_duration(start_at, end_at)?;
+    if end_at <= now {
+        return Err(GovernanceError::EndBeforeNow.into());
+    }
+    Proposal::validate_quorum(quorum_requirement, eligible_voting_power)?;
+    if let Some(season) = season {
+        if !season.contains(start_at, end_at) {
+            return Err(GovernanceError::ProposalOutsideSeason.into());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{create_proposal::ProposalCreator, log_event};
+use crate::instruction::{ProposalBatch, CREATE_PROPOSALS_BATCH};
+
+/// Creates every proposal in the batch, or none if any of them fails.
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    batch: ProposalBatch,
+) -> ProgramResult {
+    let [authority, organization, _system_program, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if remaining.len() < batch.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (proposals, remaining) = remaining.split_at(batch.len());
+
+    let mut creator = ProposalCreator::load(
+        program_id,
+        authority,
+        organization,
+        remaining,
+        CREATE_PROPOSALS_BATCH,
+    )?;
+    for (proposal, args) in proposals.iter().zip(batch.iter()) {
+        creator.create(proposal, &args)?;
+    }
+    creator.store()?;
+
+    log_event("create_proposals_batch");
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use pinocchio::{
+    account::AccountView,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    Address, ProgramResult,
+};
+
+use super::{load_audit_trail, load_authorized_organization, log_event, now};
+use crate::{
+    error::GovernanceError,
+    instruction::CREATE_SEASON,
+    math,
+    pda::{check_pda, SEASON_SEED},
+    state::{AccountState, Season},
+    system::create_pda_account,
+};
+
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    season_id: [u8; 16],
+    bump: u8,
+    start_at: i64,
+    end_at: i64,
+) -> ProgramResult {
+    let [authority, organization, season, _system_program, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_authorized_organization(organization, authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+
+    if end_at <= start_at {
+        return Err(GovernanceError::InvalidVotingWindow.into());
+    }
+
+    check_pda(
+        season,
+        &[SEASON_SEED, organization.address().as_ref(), &season_id],
+        bump,
+        program_id,
+    )?;
+
+    let bump_seed = [bump];
+    let seeds = [
+        Seed::from(SEASON_SEED),
+        Seed::from(organization.address().as_ref()),
+        Seed::from(&season_id),
+        Seed::from(&bump_seed),
+    ];
+    create_pda_account(
+        authority,
+        season,
+        Season::LEN,
+        program_id,
+        Signer::from(&seeds),
+    )?;
+
+    let now = now()?;
+    Season {
+        bump,
+        organization: *organization.address(),
+        season_id,
+        start_at,
+        end_at,
+        proposal_count: 0,
+        finalized_proposal_count: 0,
+        total_votes_cast: 0,
+        created_at: now,
+        updated_at: now,
+    }
+    .store(season)?;
+
+    math::increment(&mut org.season_count)?;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(authority, CREATE_SEASON, season.address(), now)?;
+
+    log_event("create_season");
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{close_account, load_audit_trail, load_owned_organization, log_event, now};
+use crate::{
+    error::GovernanceError,
+    instruction::DISCARD_PROPOSAL,
+    math,
+    state::{AccountState, Proposal, ProposalResults, ProposalStatus},
+};
+
+/// Closes a proposal that never finalized on a frozen organization, and its
+/// results account if results were committed, refunding the rent to the
+/// authority. Freezing blocks the status changes that would finalize it, so
+/// without this the organization could never be closed.
+///
+/// The proposal is counted in `archived_proposal_count` like an archived one.
+/// No summary is kept: there is no outcome to record, and the frozen
+/// organization can no longer create a proposal with the same id. A bond a
+/// challenger posted to dispute the results is returned to them.
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [authority, organization, proposal_account, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_owned_organization(organization, authority, program_id)?;
+    org.require_frozen()?;
+    let (mut audit, remaining) = load_audit_trail(remaining, organization, &org, program_id)?;
+
+    let proposal = Proposal::load(proposal_account, program_id)?;
+    if &proposal.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+    if proposal.status == ProposalStatus::Finalized {
+        return Err(GovernanceError::ProposalFinalized.into());
+    }
+
+    if proposal.has_results() {
+        let [results_account, remaining @ ..] = remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        if results_account.address() != &proposal.results {
+            return Err(GovernanceError::ProposalMismatch.into());
+        }
+        let results = ProposalResults::load(results_account, program_id)?;
+        if &results.proposal != proposal_account.address() {
+            return Err(GovernanceError::ProposalMismatch.into());
+        }
+        if results.dispute_bond > 0 {
+            let challenger = remaining
+                .first()
+                .filter(|account| account.address() == &results.disputed_by)
+                .ok_or(GovernanceError::ChallengerMismatch)?;
+            results_account.set_lamports(results_account.lamports() - results.dispute_bond);
+            challenger.set_lamports(math::checked_add(
+                challenger.lamports(),
+                results.dispute_bond,
+            )?);
+        }
+        close_account(results_account, authority)?;
+    }
+    close_account(proposal_account, authority)?;
+
+    let now = now()?;
+    math::increment(&mut org.archived_proposal_count)?;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(authority, DISCARD_PROPOSAL, proposal_account.address(), now)?;
+
+    log_event("discard_proposal");
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{log_event, now, require_signer};
+use crate::{
+    error::GovernanceError,
+    state::{AccountState, Organization, Proposal, ProposalResults},
+    system,
+};
+
+/// Flags committed results during their dispute window, which keeps them from
+/// being finalized by `finalize_proposal`; only the authority or an adapter
+/// signer can then finalize them.
+///
+/// The authority disputes for free. Anyone else posts the organization's
+/// `dispute_bond_lamports` into the results account, and cannot dispute while
+/// the bond is 0.
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [challenger, organization, proposal_account, results_account, _system_program, ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_signer(challenger)?;
+    let org = Organization::load(organization, program_id)?;
+    org.require_not_frozen()?;
+    let proposal = Proposal::load(proposal_account, program_id)?;
+    if &proposal.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+    // Finalizing waits for the dispute window, so results that can still be
+    // disputed always belong to a closed proposal.
+    if !proposal.has_results() {
+        return Err(GovernanceError::ResultsNotCommitted.into());
+    }
+    if results_account.address() != &proposal.results {
+        return Err(GovernanceError::ProposalMismatch.into());
+    }
+    let mut results = ProposalResults::load(results_account, program_id)?;
+
+    let bond = if challenger.address() == &org.authority {
+        0
+    } else if org.dispute_bond_lamports > 0 {
+        org.dispute_bond_lamports
+    } else {
+        return Err(GovernanceError::Unauthorized.into());
+    };
+    results.dispute(*challenger.address(), bond, now()?)?;
+    if bond > 0 {
+        system::transfer(challenger, results_account, bond)?;
+    }
+    results.store(results_account)?;
+
+    log_event("dispute_results");
+    Ok(())
+}
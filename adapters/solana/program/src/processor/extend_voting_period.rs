@@ -0,0 +1,54 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{
+    load_audit_trail, load_authorized_organization, load_organization_config,
+    load_organization_season, log_event, now,
+};
+use crate::{
+    error::GovernanceError,
+    instruction::EXTEND_VOTING_PERIOD,
+    state::{AccountState, Proposal},
+};
+
+/// Extends an open proposal's voting period. Only the organization authority
+/// may, and only once per proposal.
+pub fn process(program_id: &Address, accounts: &[AccountView], end_at: i64) -> ProgramResult {
+    let [authority, organization, proposal_account, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let org = load_authorized_organization(organization, authority, program_id)?;
+    let mut proposal = Proposal::load(proposal_account, program_id)?;
+    if &proposal.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+
+    let (mut audit, remaining) = load_audit_trail(remaining, organization, &org, program_id)?;
+    let (config, remaining) = load_organization_config(remaining, organization, &org, program_id)?;
+    if let Some(config) = &config {
+        config.check_voting_duration(proposal.start_at, end_at)?;
+    }
+    if proposal.has_season() {
+        let season_account = remaining
+            .first()
+            .filter(|account| account.address() == &proposal.season)
+            .ok_or(GovernanceError::SeasonMismatch)?;
+        let season = load_organization_season(season_account, organization, program_id)?;
+        if !season.contains(proposal.start_at, end_at) {
+            return Err(GovernanceError::ProposalOutsideSeason.into());
+        }
+    }
+
+    let now = now()?;
+    proposal.extend_voting_period(end_at, now)?;
+    proposal.store(proposal_account)?;
+    audit.record(
+        authority,
+        EXTEND_VOTING_PERIOD,
+        proposal_account.address(),
+        now,
+    )?;
+
+    log_event("extend_voting_period");
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{log_event, log_transition, now, update_proposal_status::finalize};
+use crate::{
+    error::GovernanceError,
+    state::{AccountState, Organization, Proposal, ProposalStatus},
+};
+
+/// Finalizes a closed proposal whose results were committed and not disputed,
+/// once the dispute window has ended. Anyone may call it, so finalizing does
+/// not depend on the authority or adapter acting promptly.
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [organization, proposal_account, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let org = Organization::load(organization, program_id)?;
+    org.require_not_frozen()?;
+    let mut proposal = Proposal::load(proposal_account, program_id)?;
+    if &proposal.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+    let now = now()?;
+    proposal.transition(ProposalStatus::Finalized, now)?;
+    finalize(
+        program_id,
+        &org,
+        &proposal,
+        proposal_account,
+        remaining,
+        now,
+        false,
+    )?;
+    proposal.store(proposal_account)?;
+
+    log_transition(&proposal);
+    log_event("finalize_proposal");
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{load_audit_trail, load_authorized_organization, log_event, now};
+use crate::{instruction::FREEZE_ORGANIZATION, state::AccountState};
+
+/// Freezes the organization. Every handler that changes an organization, or
+/// its proposals and seasons, loads it through a helper that rejects frozen
+/// organizations, so this is irreversible.
+pub fn process(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    let [authority, organization, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_authorized_organization(organization, authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    let now = now()?;
+    org.freeze(now)?;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(authority, FREEZE_ORGANIZATION, organization.address(), now)?;
+
+    log_event("freeze_organization");
+    Ok(())
+}
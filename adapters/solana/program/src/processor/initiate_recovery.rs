@@ -0,0 +1,28 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{load_audit_trail, load_recovery_organization, log_event, now};
+use crate::{instruction::INITIATE_RECOVERY, state::AccountState};
+
+/// Announces a replacement authority. It only takes effect through
+/// `complete_recovery` after the timelock, and the current authority can
+/// cancel it until then.
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    new_authority: Address,
+) -> ProgramResult {
+    let [recovery_authority, organization, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_recovery_organization(organization, recovery_authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    let now = now()?;
+    org.initiate_recovery(new_authority, now)?;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(recovery_authority, INITIATE_RECOVERY, &new_authority, now)?;
+
+    log_event("initiate_recovery");
+    Ok(())
+}
@@ -0,0 +1,364 @@
+use pinocchio::{
+    account::AccountView,
+    error::ProgramError,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    Address, ProgramResult,
+};
+use pinocchio_log::log;
+
+use crate::{
+    error::GovernanceError,
+    instruction::GovernanceInstruction,
+    math,
+    state::{
+        AccountState, AuditEntry, AuditLog, OrgConfig, Organization, Proposal, ProposalStatus,
+        Season,
+    },
+    PROGRAM_VERSION,
+};
+
+pub mod add_adapter_signer;
+pub mod archive_proposal;
+pub mod cancel_recovery;
+pub mod close_and_commit_results;
+pub mod close_audit_log;
+pub mod close_org_config;
+pub mod close_org_stats;
+pub mod close_organization;
+pub mod close_season;
+pub mod commit_vote_results;
+pub mod complete_recovery;
+pub mod create_audit_log;
+pub mod create_org_stats;
+pub mod create_organization;
+pub mod create_proposal;
+pub mod create_proposals_batch;
+pub mod create_season;
+pub mod discard_proposal;
+pub mod dispute_results;
+pub mod extend_voting_period;
+pub mod finalize_proposal;
+pub mod freeze_organization;
+pub mod initiate_recovery;
+pub mod open_proposal_with_snapshot;
+pub mod remove_adapter_signer;
+pub mod set_dispute_policy;
+pub mod set_eligible_voting_power;
+pub mod set_org_config;
+pub mod update_proposal_draft;
+pub mod update_proposal_status;
+pub mod verify_results_hash;
+
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction: GovernanceInstruction,
+) -> ProgramResult {
+    match instruction {
+        GovernanceInstruction::CreateOrganization {
+            organization_id,
+            bump,
+            recovery_authority,
+            name,
+        } => create_organization::process(
+            program_id,
+            accounts,
+            organization_id,
+            bump,
+            recovery_authority,
+            name,
+        ),
+        GovernanceInstruction::CreateProposal(args) => {
+            create_proposal::process(program_id, accounts, args)
+        }
+        GovernanceInstruction::UpdateProposalStatus {
+            operation_nonce,
+            status,
+        } => update_proposal_status::process(program_id, accounts, operation_nonce, status),
+        GovernanceInstruction::CommitVoteResults(args) => {
+            commit_vote_results::process(program_id, accounts, args)
+        }
+        GovernanceInstruction::CreateSeason {
+            season_id,
+            bump,
+            start_at,
+            end_at,
+        } => create_season::process(program_id, accounts, season_id, bump, start_at, end_at),
+        GovernanceInstruction::AddAdapterSigner { signer } => {
+            add_adapter_signer::process(program_id, accounts, signer)
+        }
+        GovernanceInstruction::RemoveAdapterSigner { signer } => {
+            remove_adapter_signer::process(program_id, accounts, signer)
+        }
+        GovernanceInstruction::InitiateRecovery { new_authority } => {
+            initiate_recovery::process(program_id, accounts, new_authority)
+        }
+        GovernanceInstruction::CancelRecovery => cancel_recovery::process(program_id, accounts),
+        GovernanceInstruction::CompleteRecovery => complete_recovery::process(program_id, accounts),
+        GovernanceInstruction::CreateProposalsBatch(batch) => {
+            create_proposals_batch::process(program_id, accounts, batch)
+        }
+        GovernanceInstruction::OpenProposalWithSnapshot {
+            operation_nonce,
+            snapshot_slot,
+            voter_merkle_root,
+            eligible_voting_power,
+        } => open_proposal_with_snapshot::process(
+            program_id,
+            accounts,
+            operation_nonce,
+            snapshot_slot,
+            voter_merkle_root,
+            eligible_voting_power,
+        ),
+        GovernanceInstruction::CloseAndCommitResults(args) => {
+            close_and_commit_results::process(program_id, accounts, args)
+        }
+        GovernanceInstruction::SetEligibleVotingPower {
+            operation_nonce,
+            eligible_voting_power,
+        } => set_eligible_voting_power::process(
+            program_id,
+            accounts,
+            operation_nonce,
+            eligible_voting_power,
+        ),
+        GovernanceInstruction::LogVersion => {
+            log!("FE_GOV: version {}", PROGRAM_VERSION);
+            Ok(())
+        }
+        GovernanceInstruction::FreezeOrganization => {
+            freeze_organization::process(program_id, accounts)
+        }
+        GovernanceInstruction::CloseOrganization => {
+            close_organization::process(program_id, accounts)
+        }
+        GovernanceInstruction::ArchiveProposal => archive_proposal::process(program_id, accounts),
+        GovernanceInstruction::CreateOrgStats { bump } => {
+            create_org_stats::process(program_id, accounts, bump)
+        }
+        GovernanceInstruction::CloseSeason => close_season::process(program_id, accounts),
+        GovernanceInstruction::CloseOrgStats => close_org_stats::process(program_id, accounts),
+        GovernanceInstruction::UpdateProposalDraft(args) => {
+            update_proposal_draft::process(program_id, accounts, args)
+        }
+        GovernanceInstruction::ExtendVotingPeriod { end_at } => {
+            extend_voting_period::process(program_id, accounts, end_at)
+        }
+        GovernanceInstruction::SetDisputePolicy {
+            window_seconds,
+            bond_lamports,
+        } => set_dispute_policy::process(program_id, accounts, window_seconds, bond_lamports),
+        GovernanceInstruction::DisputeResults => dispute_results::process(program_id, accounts),
+        GovernanceInstruction::FinalizeProposal => finalize_proposal::process(program_id, accounts),
+        GovernanceInstruction::VerifyResultsHash { preimage } => {
+            verify_results_hash::process(program_id, accounts, preimage)
+        }
+        GovernanceInstruction::SetOrgConfig(args) => {
+            set_org_config::process(program_id, accounts, args)
+        }
+        GovernanceInstruction::CloseOrgConfig => close_org_config::process(program_id, accounts),
+        GovernanceInstruction::CreateAuditLog { bump } => {
+            create_audit_log::process(program_id, accounts, bump)
+        }
+        GovernanceInstruction::CloseAuditLog => close_audit_log::process(program_id, accounts),
+        GovernanceInstruction::DiscardProposal => discard_proposal::process(program_id, accounts),
+    }
+}
+
+/// Logs `FE_GOV: <instruction> v<version>` once an instruction succeeds.
+fn log_event(instruction: &str) {
+    log!("FE_GOV: {} v{}", instruction, PROGRAM_VERSION);
+}
+
+/// Logs `FE_GOV: opened_at <ts>` or `FE_GOV: closed_at <ts>` after a
+/// proposal opens or closes, ahead of its `log_event` line.
+fn log_transition(proposal: &Proposal) {
+    match proposal.status {
+        ProposalStatus::Open => log!("FE_GOV: opened_at {}", proposal.opened_at),
+        ProposalStatus::Closed => log!("FE_GOV: closed_at {}", proposal.closed_at),
+        ProposalStatus::Draft | ProposalStatus::Finalized => {}
+    }
+}
+
+fn require_signer(account: &AccountView) -> Result<(), ProgramError> {
+    if !account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+/// Loads the organization and checks that `authority` signed as its authority.
+fn load_authorized_organization(
+    organization: &AccountView,
+    authority: &AccountView,
+    program_id: &Address,
+) -> Result<Organization, ProgramError> {
+    let state = load_owned_organization(organization, authority, program_id)?;
+    state.require_not_frozen()?;
+    Ok(state)
+}
+
+/// Like `load_authorized_organization`, but also accepts a frozen
+/// organization. Only the instructions that wind an organization down, such as
+/// archiving its proposals and closing it, load it this way.
+fn load_owned_organization(
+    organization: &AccountView,
+    authority: &AccountView,
+    program_id: &Address,
+) -> Result<Organization, ProgramError> {
+    require_signer(authority)?;
+    let state = Organization::load(organization, program_id)?;
+    if &state.authority != authority.address() {
+        return Err(GovernanceError::Unauthorized.into());
+    }
+    Ok(state)
+}
+
+/// Loads the organization for an adapter operation, which `signer` may sign
+/// as either the authority or a registered adapter signer.
+fn load_operator_organization(
+    organization: &AccountView,
+    signer: &AccountView,
+    program_id: &Address,
+) -> Result<Organization, ProgramError> {
+    require_signer(signer)?;
+    let state = Organization::load(organization, program_id)?;
+    if &state.authority != signer.address() && !state.is_adapter_signer(signer.address()) {
+        return Err(GovernanceError::Unauthorized.into());
+    }
+    state.require_not_frozen()?;
+    Ok(state)
+}
+
+/// Loads the organization and checks that `signer` signed as its recovery
+/// authority.
+fn load_recovery_organization(
+    organization: &AccountView,
+    signer: &AccountView,
+    program_id: &Address,
+) -> Result<Organization, ProgramError> {
+    require_signer(signer)?;
+    let state = Organization::load(organization, program_id)?;
+    if !state.has_recovery_authority() {
+        return Err(GovernanceError::RecoveryNotConfigured.into());
+    }
+    if &state.recovery_authority != signer.address() {
+        return Err(GovernanceError::Unauthorized.into());
+    }
+    state.require_not_frozen()?;
+    Ok(state)
+}
+
+/// Loads a season and checks that it belongs to `organization`.
+fn load_organization_season(
+    season: &AccountView,
+    organization: &AccountView,
+    program_id: &Address,
+) -> Result<Season, ProgramError> {
+    let state = Season::load(season, program_id)?;
+    if &state.organization != organization.address() {
+        return Err(GovernanceError::SeasonMismatch.into());
+    }
+    Ok(state)
+}
+
+/// Loads the organization's config from the front of `accounts` if it has
+/// one, returning it with the accounts that follow.
+fn load_organization_config<'a>(
+    accounts: &'a [AccountView],
+    organization: &AccountView,
+    org: &Organization,
+    program_id: &Address,
+) -> Result<(Option<OrgConfig>, &'a [AccountView]), ProgramError> {
+    if !org.has_config {
+        return Ok((None, accounts));
+    }
+    let [config, rest @ ..] = accounts else {
+        return Err(GovernanceError::OrgConfigRequired.into());
+    };
+    let state = OrgConfig::load(config, program_id)?;
+    if &state.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+    Ok((Some(state), rest))
+}
+
+/// The organization's audit log, if it has one, loaded by a privileged
+/// handler so it can record what it did once it has succeeded.
+struct AuditTrail<'a>(Option<(&'a AccountView, AuditLog)>);
+
+impl AuditTrail<'_> {
+    /// Appends an entry for `instruction`, signed by `actor`, acting on
+    /// `target`. Does nothing for an organization without an audit log.
+    fn record(
+        &mut self,
+        actor: &AccountView,
+        instruction: u8,
+        target: &Address,
+        now: i64,
+    ) -> ProgramResult {
+        let Some((account, log)) = &mut self.0 else {
+            return Ok(());
+        };
+        log.append(AuditEntry {
+            actor: *actor.address(),
+            instruction,
+            target: *target,
+            timestamp: now,
+        });
+        log.store(account)
+    }
+}
+
+/// Loads the organization's audit log from the end of `accounts` if it has
+/// one, returning it with the accounts before it.
+fn load_audit_trail<'a>(
+    accounts: &'a [AccountView],
+    organization: &AccountView,
+    org: &Organization,
+    program_id: &Address,
+) -> Result<(AuditTrail<'a>, &'a [AccountView]), ProgramError> {
+    if !org.has_audit_log {
+        return Ok((AuditTrail(None), accounts));
+    }
+    let [rest @ .., account] = accounts else {
+        return Err(GovernanceError::AuditLogRequired.into());
+    };
+    let log = AuditLog::load(account, program_id)?;
+    if &log.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+    Ok((AuditTrail(Some((account, log))), rest))
+}
+
+/// Checks a length-bounded, non-empty UTF-8 field.
+fn validate_text(value: &[u8], max_len: usize, error: GovernanceError) -> Result<(), ProgramError> {
+    if value.is_empty() || value.len() > max_len || core::str::from_utf8(value).is_err() {
+        return Err(error.into());
+    }
+    Ok(())
+}
+
+/// Moves all of `account`'s lamports to `recipient` and clears it, so the
+/// runtime removes the account at the end of the transaction.
+fn close_account(account: &AccountView, recipient: &AccountView) -> ProgramResult {
+    recipient.set_lamports(math::checked_add(recipient.lamports(), account.lamports())?);
+    account.close()
+}
+
+/// Shrinks `account` to `len` bytes and moves the rent it no longer needs to
+/// `recipient`.
+fn shrink_account(account: &AccountView, recipient: &AccountView, len: usize) -> ProgramResult {
+    account.resize(len)?;
+    let refund = account
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(len));
+    account.set_lamports(account.lamports() - refund);
+    recipient.set_lamports(math::checked_add(recipient.lamports(), refund)?);
+    Ok(())
+}
+
+fn now() -> Result<i64, ProgramError> {
+    Ok(Clock::get()?.unix_timestamp)
+}
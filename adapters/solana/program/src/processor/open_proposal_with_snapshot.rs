@@ -0,0 +1,60 @@
+use pinocchio::{
+    account::AccountView,
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    Address, ProgramResult,
+};
+
+use super::{load_audit_trail, load_operator_organization, log_event, log_transition};
+use crate::{
+    error::GovernanceError,
+    instruction::OPEN_PROPOSAL_WITH_SNAPSHOT,
+    state::{AccountState, Proposal},
+};
+
+/// Moves a draft proposal to `Open` and records the eligibility snapshot in
+/// the same instruction, so no vote can land between the two.
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    operation_nonce: u64,
+    snapshot_slot: u64,
+    voter_merkle_root: [u8; 32],
+    eligible_voting_power: u64,
+) -> ProgramResult {
+    let [authority, organization, proposal_account, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_operator_organization(organization, authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    org.advance_operation_nonce(operation_nonce)?;
+
+    let mut proposal = Proposal::load(proposal_account, program_id)?;
+    if &proposal.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+
+    let clock = Clock::get()?;
+    if snapshot_slot > clock.slot {
+        return Err(GovernanceError::InvalidSnapshotSlot.into());
+    }
+    proposal.open_with_snapshot(
+        snapshot_slot,
+        voter_merkle_root,
+        eligible_voting_power,
+        clock.unix_timestamp,
+    )?;
+    proposal.store(proposal_account)?;
+    org.store(organization)?;
+    audit.record(
+        authority,
+        OPEN_PROPOSAL_WITH_SNAPSHOT,
+        proposal_account.address(),
+        clock.unix_timestamp,
+    )?;
+
+    log_transition(&proposal);
+    log_event("open_proposal_with_snapshot");
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{load_audit_trail, load_authorized_organization, log_event, now};
+use crate::{instruction::REMOVE_ADAPTER_SIGNER, state::AccountState};
+
+pub fn process(program_id: &Address, accounts: &[AccountView], signer: Address) -> ProgramResult {
+    let [authority, organization, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_authorized_organization(organization, authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    org.remove_adapter_signer(&signer)?;
+    let now = now()?;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(authority, REMOVE_ADAPTER_SIGNER, &signer, now)?;
+
+    log_event("remove_adapter_signer");
+    Ok(())
+}
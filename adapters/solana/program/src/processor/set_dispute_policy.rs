@@ -0,0 +1,28 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{load_audit_trail, load_authorized_organization, log_event, now};
+use crate::{instruction::SET_DISPUTE_POLICY, state::AccountState};
+
+/// Sets the organization's dispute window and challenger bond. Results that
+/// are already committed keep the window they were committed with.
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    window_seconds: i64,
+    bond_lamports: u64,
+) -> ProgramResult {
+    let [authority, organization, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_authorized_organization(organization, authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    org.set_dispute_policy(window_seconds, bond_lamports)?;
+    let now = now()?;
+    org.updated_at = now;
+    org.store(organization)?;
+    audit.record(authority, SET_DISPUTE_POLICY, organization.address(), now)?;
+
+    log_event("set_dispute_policy");
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{load_audit_trail, load_operator_organization, log_event, now};
+use crate::{
+    error::GovernanceError,
+    instruction::SET_ELIGIBLE_VOTING_POWER,
+    state::{AccountState, Proposal},
+};
+
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    operation_nonce: u64,
+    eligible_voting_power: u64,
+) -> ProgramResult {
+    let [authority, organization, proposal_account, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_operator_organization(organization, authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    org.advance_operation_nonce(operation_nonce)?;
+
+    let mut proposal = Proposal::load(proposal_account, program_id)?;
+    if &proposal.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+    proposal.set_eligible_voting_power(eligible_voting_power)?;
+    let now = now()?;
+    proposal.updated_at = now;
+    proposal.store(proposal_account)?;
+    org.store(organization)?;
+    audit.record(
+        authority,
+        SET_ELIGIBLE_VOTING_POWER,
+        proposal_account.address(),
+        now,
+    )?;
+
+    log_event("set_eligible_voting_power");
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use pinocchio::{
+    account::AccountView,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    Address, ProgramResult,
+};
+
+use super::{load_audit_trail, load_authorized_organization, log_event, now};
+use crate::{
+    error::GovernanceError,
+    instruction::{OrgConfigArgs, SET_ORG_CONFIG},
+    pda::{check_pda, ORG_CONFIG_SEED},
+    state::{AccountState, OrgConfig},
+    system::create_pda_account,
+};
+
+/// Creates the organization's config account on first use and overwrites its
+/// defaults and limits afterwards.
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    args: OrgConfigArgs,
+) -> ProgramResult {
+    let [authority, organization, config_account, _system_program, remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_authorized_organization(organization, authority, program_id)?;
+    let (mut audit, _) = load_audit_trail(remaining, organization, &org, program_id)?;
+    let now = now()?;
+
+    let (bump, created_at) = if org.has_config {
+        let config = OrgConfig::load(config_account, program_id)?;
+        if &config.organization != organization.address() {
+            return Err(GovernanceError::OrganizationMismatch.into());
+        }
+        (config.bump, config.created_at)
+    } else {
+        check_pda(
+            config_account,
+            &[ORG_CONFIG_SEED, organization.address().as_ref()],
+            args.bump,
+            program_id,
+        )?;
+        (args.bump, now)
+    };
+
+    let config = OrgConfig {
+        bump,
+        organization: *organization.address(),
+        default_quorum_requirement: args.default_quorum_requirement,
+        default_voting_duration_seconds: args.default_voting_duration_seconds,
+        min_voting_duration_seconds: args.min_voting_duration_seconds,
+        max_voting_duration_seconds: args.max_voting_duration_seconds,
+        created_at,
+        updated_at: now,
+    };
+    config.validate()?;
+
+    if !org.has_config {
+        let bump_seed = [bump];
+        let seeds = [
+            Seed::from(ORG_CONFIG_SEED),
+            Seed::from(organization.address().as_ref()),
+            Seed::from(&bump_seed),
+        ];
+        create_pda_account(
+            authority,
+            config_account,
+            OrgConfig::LEN,
+            program_id,
+            Signer::from(&seeds),
+        )?;
+        org.has_config = true;
+        org.updated_at = now;
+        org.store(organization)?;
+    }
+    config.store(config_account)?;
+    audit.record(authority, SET_ORG_CONFIG, config_account.address(), now)?;
+
+    log_event("set_org_config");
+    Ok(())
+}
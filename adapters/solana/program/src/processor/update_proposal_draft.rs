@@ -0,0 +1,89 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::{
+    create_proposal::validate_proposal, load_audit_trail, load_organization_config,
+    load_organization_season, log_event, now, require_signer,
+};
+use crate::{
+    error::GovernanceError,
+    instruction::{UpdateProposalDraftArgs, UPDATE_PROPOSAL_DRAFT},
+    math,
+    state::{fixed_bytes, AccountState, Organization, Proposal, ProposalStatus},
+};
+
+/// Amends a draft proposal. The organization authority and the proposal's
+/// author may both do so; nothing can change once the proposal opens.
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    args: UpdateProposalDraftArgs,
+) -> ProgramResult {
+    let [signer, organization, proposal_account, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_signer(signer)?;
+    let org = Organization::load(organization, program_id)?;
+    org.require_not_frozen()?;
+    let mut proposal = Proposal::load(proposal_account, program_id)?;
+    if &proposal.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+    if &org.authority != signer.address() && &proposal.created_by != signer.address() {
+        return Err(GovernanceError::Unauthorized.into());
+    }
+    if proposal.status != ProposalStatus::Draft {
+        return Err(GovernanceError::ProposalNotDraft.into());
+    }
+
+    let (mut audit, remaining) = load_audit_trail(remaining, organization, &org, program_id)?;
+    let (config, remaining) = load_organization_config(remaining, organization, &org, program_id)?;
+    let season = if proposal.has_season() {
+        let season_account = remaining
+            .first()
+            .filter(|account| account.address() == &proposal.season)
+            .ok_or(GovernanceError::SeasonMismatch)?;
+        Some(load_organization_season(
+            season_account,
+            organization,
+            program_id,
+        )?)
+    } else {
+        None
+    };
+
+    let now = now()?;
+    args.content_hash_scheme.check_supported()?;
+    validate_proposal(
+        args.title,
+        args.start_at,
+        args.end_at,
+        args.quorum_requirement,
+        proposal.eligible_voting_power,
+        now,
+        season.as_ref(),
+    )?;
+    if let Some(config) = &config {
+        config.check_voting_duration(args.start_at, args.end_at)?;
+    }
+
+    proposal.content_hash = args.content_hash;
+    proposal.content_hash_scheme = args.content_hash_scheme;
+    proposal.start_at = args.start_at;
+    proposal.end_at = args.end_at;
+    proposal.quorum_requirement = args.quorum_requirement;
+    proposal.title_len = args.title.len() as u8;
+    proposal.title = fixed_bytes(args.title);
+    math::increment(&mut proposal.amendment_count)?;
+    proposal.updated_at = now;
+    proposal.store(proposal_account)?;
+    audit.record(
+        signer,
+        UPDATE_PROPOSAL_DRAFT,
+        proposal_account.address(),
+        now,
+    )?;
+
+    log_event("update_proposal_draft");
+    Ok(())
+}
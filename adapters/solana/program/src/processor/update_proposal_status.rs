@@ -0,0 +1,148 @@
+use pinocchio::{
+    account::AccountView,
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    Address, ProgramResult,
+};
+
+use super::{load_audit_trail, load_operator_organization, log_event, log_transition};
+use crate::{
+    error::GovernanceError,
+    instruction::UPDATE_PROPOSAL_STATUS,
+    math,
+    state::{
+        AccountState, OrgStats, Organization, Proposal, ProposalResults, ProposalStatus, Season,
+    },
+};
+
+pub fn process(
+    program_id: &Address,
+    accounts: &[AccountView],
+    operation_nonce: u64,
+    status: ProposalStatus,
+) -> ProgramResult {
+    let [authority, organization, proposal_account, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut org = load_operator_organization(organization, authority, program_id)?;
+    let (mut audit, remaining) = load_audit_trail(remaining, organization, &org, program_id)?;
+    org.advance_operation_nonce(operation_nonce)?;
+
+    let mut proposal = Proposal::load(proposal_account, program_id)?;
+    if &proposal.organization != organization.address() {
+        return Err(GovernanceError::OrganizationMismatch.into());
+    }
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    match status {
+        ProposalStatus::Open => proposal.open(now, clock.slot)?,
+        _ => proposal.transition(status, now)?,
+    }
+
+    if status == ProposalStatus::Finalized {
+        finalize(
+            program_id,
+            &org,
+            &proposal,
+            proposal_account,
+            remaining,
+            now,
+            true,
+        )?;
+    }
+
+    proposal.store(proposal_account)?;
+    org.store(organization)?;
+    audit.record(
+        authority,
+        UPDATE_PROPOSAL_STATUS,
+        proposal_account.address(),
+        now,
+    )?;
+
+    log_transition(&proposal);
+    log_event("update_proposal_status");
+    Ok(())
+}
+
+/// Locks the committed results of a proposal being finalized and counts them
+/// in its season and the organization's stats. Callers check that the
+/// proposal belongs to `org` and move it to `Finalized`.
+///
+/// `remaining` holds the results account, then the season if the proposal has
+/// one, the stats account if the organization has one, and the challenger if
+/// the results were disputed with a bond, which is returned to them. Disputed
+/// results are only finalized when `allow_disputed` is set.
+pub(super) fn finalize(
+    program_id: &Address,
+    org: &Organization,
+    proposal: &Proposal,
+    proposal_account: &AccountView,
+    remaining: &[AccountView],
+    now: i64,
+    allow_disputed: bool,
+) -> ProgramResult {
+    // Finalizing locks the committed results, so they must exist and be the
+    // account recorded on the proposal when they were committed.
+    let [results_account, remaining @ ..] = remaining else {
+        return Err(GovernanceError::ResultsNotCommitted.into());
+    };
+    if !proposal.has_results() {
+        return Err(GovernanceError::ResultsNotCommitted.into());
+    }
+    if results_account.address() != &proposal.results {
+        return Err(GovernanceError::ProposalMismatch.into());
+    }
+    let mut results = ProposalResults::load(results_account, program_id)?;
+    if &results.proposal != proposal_account.address() {
+        return Err(GovernanceError::ProposalMismatch.into());
+    }
+    results.check_dispute_window_ended(now)?;
+    if results.is_disputed() && !allow_disputed {
+        return Err(GovernanceError::ResultsDisputed.into());
+    }
+    results.finalized_at = now;
+
+    let mut remaining = remaining.iter();
+    if proposal.has_season() {
+        let season_account = remaining
+            .next()
+            .filter(|account| account.address() == &proposal.season)
+            .ok_or(GovernanceError::SeasonMismatch)?;
+        let mut season = Season::load(season_account, program_id)?;
+        if season.organization != proposal.organization {
+            return Err(GovernanceError::SeasonMismatch.into());
+        }
+        math::increment(&mut season.finalized_proposal_count)?;
+        season.total_votes_cast =
+            math::checked_add(season.total_votes_cast, results.total_votes_cast)?;
+        season.updated_at = now;
+        season.store(season_account)?;
+    }
+
+    // Once the organization has a stats account, every finalization must be
+    // counted in it.
+    if org.has_stats {
+        let stats_account = remaining.next().ok_or(GovernanceError::OrgStatsRequired)?;
+        let mut stats = OrgStats::load(stats_account, program_id)?;
+        if stats.organization != proposal.organization {
+            return Err(GovernanceError::OrganizationMismatch.into());
+        }
+        stats.record_finalized(proposal, &results, now);
+        stats.store(stats_account)?;
+    }
+
+    if results.dispute_bond > 0 {
+        let challenger = remaining
+            .next()
+            .filter(|account| account.address() == &results.disputed_by)
+            .ok_or(GovernanceError::ChallengerMismatch)?;
+        results_account.set_lamports(results_account.lamports() - results.dispute_bond);
+        challenger.set_lamports(math::checked_add(
+            challenger.lamports(),
+            results.dispute_bond,
+        )?);
+    }
+    results.store(results_account)
+}
@@ -0,0 +1,65 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use super::log_event;
+use crate::{
+    error::GovernanceError,
+    results::{hash_results_chunks, parse_results_header},
+    state::{AccountState, HashScheme, Proposal, ProposalResults},
+};
+
+/// Hashes a published results preimage with the SHA-256 syscall and marks
+/// the results verified if it matches the committed `results_hash`.
+///
+/// The preimage is the instruction payload followed by the data of every
+/// account after the results account, in order, so encodings too large for
+/// one transaction can be staged in buffer accounts first. It must be a
+/// canonical encoding for this proposal with the committed vote total. Anyone
+/// may call it, also once the proposal is finalized or its organization
+/// frozen.
+pub fn process(program_id: &Address, accounts: &[AccountView], preimage: &[u8]) -> ProgramResult {
+    let [proposal_account, results_account, chunk_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let proposal = Proposal::load(proposal_account, program_id)?;
+    if !proposal.has_results() {
+        return Err(GovernanceError::ResultsNotCommitted.into());
+    }
+    if results_account.address() != &proposal.results {
+        return Err(GovernanceError::ProposalMismatch.into());
+    }
+    let mut results = ProposalResults::load(results_account, program_id)?;
+    if &results.proposal != proposal_account.address() {
+        return Err(GovernanceError::ProposalMismatch.into());
+    }
+    if results.results_hash_scheme != HashScheme::Sha256 {
+        return Err(GovernanceError::UnsupportedHashScheme.into());
+    }
+
+    {
+        let data = chunk_accounts
+            .iter()
+            .map(AccountView::try_borrow)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut chunks = Vec::with_capacity(data.len() + 1);
+        chunks.push(preimage);
+        chunks.extend(data.iter().map(|data| &**data));
+
+        let header =
+            parse_results_header(&chunks).ok_or(GovernanceError::InvalidResultsPreimage)?;
+        if header.proposal_id != proposal.proposal_id
+            || header.total_votes_cast != results.total_votes_cast
+        {
+            return Err(GovernanceError::InvalidResultsPreimage.into());
+        }
+        if hash_results_chunks(&chunks) != results.results_hash {
+            return Err(GovernanceError::ResultsHashMismatch.into());
+        }
+    }
+
+    results.hash_verified = true;
+    results.store(results_account)?;
+
+    log_event("verify_results_hash");
+    Ok(())
+}
@@ -0,0 +1,142 @@
+//! Canonical serialization and hashing of off-chain vote results.
+//!
+//! A results commitment is only verifiable if the backend, the adapter, and any
+//! fan re-computing the tally hash exactly the same bytes. This module defines
+//! that byte layout and the `compute_results_hash()` used to produce the
+//! commitment. It has no on-chain state and can be used from native code.
+//!
+//! Layout (all integers little-endian, fixed width):
+//!
+//! | Field                  | Size          |
+//! |------------------------|---------------|
+//! | version                | 1             |
+//! | proposal_id            | 16            |
+//! | total_votes_cast       | 8             |
+//! | total_voting_power     | 8             |
+//! | option_count           | 4             |
+//! | options (sorted by id) | 32 per option |
+//!
+//! Each option entry is `option_id (16) | vote_count (8) | voting_power (8)`.
+//! Options are always emitted in ascending `option_id` order regardless of the
+//! order they were supplied in, and duplicate option ids are rejected.
+
+/// Version byte prefixed to every canonical encoding.
+pub const RESULTS_ENCODING_VERSION: u8 = 1;
+
+/// Size of the fixed header preceding the option entries.
+pub const RESULTS_HEADER_LEN: usize = 1 + 16 + 8 + 8 + 4;
+
+/// Size of a single encoded option entry.
+pub const OPTION_TALLY_LEN: usize = 16 + 8 + 8;
+
+/// Upper bound on options accepted by the encoder.
+pub const MAX_RESULT_OPTIONS: usize = 255;
+
+/// Tally for a single proposal option.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OptionTally {
+    pub option_id: [u8; 16],
+    pub vote_count: u64,
+    pub voting_power: u64,
+}
+
+/// Aggregated results for a proposal as computed off-chain.
+#[derive(Clone, Copy, Debug)]
+pub struct VoteResults<'a> {
+    pub proposal_id: [u8; 16],
+    pub total_votes_cast: u64,
+    pub total_voting_power: u64,
+    pub options: &'a [OptionTally],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResultsEncodingError {
+    DuplicateOptionId,
+    TooManyOptions,
+}
+
+/// Returns the encoded length for the given number of options.
+pub const fn encoded_len(option_count: usize) -> usize {
+    RESULTS_HEADER_LEN + option_count * OPTION_TALLY_LEN
+}
+
+/// Encodes `results` into its canonical byte representation.
+pub fn canonical_results_bytes(results: &VoteResults) -> Result<Vec<u8>, ResultsEncodingError> {
+    if results.options.len() > MAX_RESULT_OPTIONS {
+        return Err(ResultsEncodingError::TooManyOptions);
+    }
+
+    let mut options = results.options.to_vec();
+    options.sort_unstable_by_key(|option| option.option_id);
+    if options.windows(2).any(|w| w[0].option_id == w[1].option_id) {
+        return Err(ResultsEncodingError::DuplicateOptionId);
+    }
+
+    let mut out = Vec::with_capacity(encoded_len(options.len()));
+    out.push(RESULTS_ENCODING_VERSION);
+    out.extend_from_slice(&results.proposal_id);
+    out.extend_from_slice(&results.total_votes_cast.to_le_bytes());
+    out.extend_from_slice(&results.total_voting_power.to_le_bytes());
+    out.extend_from_slice(&(options.len() as u32).to_le_bytes());
+    for option in &options {
+        out.extend_from_slice(&option.option_id);
+        out.extend_from_slice(&option.vote_count.to_le_bytes());
+        out.extend_from_slice(&option.voting_power.to_le_bytes());
+    }
+
+    Ok(out)
+}
+
+/// SHA-256 of an already-encoded results preimage.
+pub fn hash_results_bytes(preimage: &[u8]) -> [u8; 32] {
+    solana_sha256_hasher::hash(preimage).to_bytes()
+}
+
+/// Computes the results hash committed on-chain for `results`.
+pub fn compute_results_hash(results: &VoteResults) -> Result<[u8; 32], ResultsEncodingError> {
+    let bytes = canonical_results_bytes(results)?;
+    Ok(hash_results_bytes(&bytes))
+}
+
+/// Header of a canonical encoding, as read back by `parse_results_header`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResultsHeader {
+    pub proposal_id: [u8; 16],
+    pub total_votes_cast: u64,
+    pub total_voting_power: u64,
+    pub option_count: u32,
+}
+
+/// Reads the header of an encoding split across `chunks` in order. Returns
+/// `None` unless the chunks hold exactly one current-version encoding with
+/// as many option entries as the header declares.
+pub fn parse_results_header(chunks: &[&[u8]]) -> Option<ResultsHeader> {
+    let mut header = [0u8; RESULTS_HEADER_LEN];
+    let mut filled = 0;
+    for chunk in chunks {
+        let take = chunk.len().min(RESULTS_HEADER_LEN - filled);
+        header[filled..filled + take].copy_from_slice(&chunk[..take]);
+        filled += take;
+    }
+    if filled < RESULTS_HEADER_LEN || header[0] != RESULTS_ENCODING_VERSION {
+        return None;
+    }
+
+    let option_count = u32::from_le_bytes(header[33..37].try_into().ok()?);
+    let len: usize = chunks.iter().map(|chunk| chunk.len()).sum();
+    if option_count as usize > MAX_RESULT_OPTIONS || len != encoded_len(option_count as usize) {
+        return None;
+    }
+    Some(ResultsHeader {
+        proposal_id: header[1..17].try_into().ok()?,
+        total_votes_cast: u64::from_le_bytes(header[17..25].try_into().ok()?),
+        total_voting_power: u64::from_le_bytes(header[25..33].try_into().ok()?),
+        option_count,
+    })
+}
+
+/// SHA-256 of an encoding split across `chunks`, equal to
+/// `hash_results_bytes` of their concatenation.
+pub fn hash_results_chunks(chunks: &[&[u8]]) -> [u8; 32] {
+    solana_sha256_hasher::hashv(chunks).to_bytes()
+}
@@ -0,0 +1,145 @@
+//! Field encodings for the `serde` feature, used through `#[serde(with)]`.
+//!
+//! Addresses are base58 strings, ids and hashes are lowercase hex, and
+//! zero-padded text buffers are UTF-8 strings with the padding trimmed.
+//!
+//! Types with a text length field derive with `#[serde(remote = "Self")]` and
+//! skip the length; the impls at the bottom of this file rebuild it from the
+//! text, so JSON cannot describe a length that disagrees with the text. They
+//! can also name a check that the decoded value must pass, for counts that
+//! `unpack` would otherwise be the first to reject.
+
+use pinocchio::Address;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::state::{Organization, Proposal, MAX_ADAPTER_SIGNERS};
+
+fn encode_address(address: &Address) -> String {
+    bs58::encode(address.as_ref()).into_string()
+}
+
+fn decode_address(encoded: &str) -> Result<Address, &'static str> {
+    let bytes = bs58::decode(encoded)
+        .into_vec()
+        .map_err(|_| "invalid base58 address")?;
+    let bytes: [u8; 32] = bytes.try_into().map_err(|_| "address must be 32 bytes")?;
+    Ok(Address::new_from_array(bytes))
+}
+
+pub mod address {
+    use pinocchio::Address;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(address: &Address, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&super::encode_address(address))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        super::decode_address(&encoded).map_err(D::Error::custom)
+    }
+}
+
+pub mod address_array {
+    use pinocchio::Address;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        addresses: &[Address; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(addresses.iter().map(super::encode_address))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[Address; N], D::Error> {
+        let encoded = Vec::<String>::deserialize(deserializer)?;
+        if encoded.len() != N {
+            return Err(D::Error::invalid_length(encoded.len(), &"one entry per slot"));
+        }
+        let mut addresses: [Address; N] = core::array::from_fn(|_| Address::default());
+        for (address, value) in addresses.iter_mut().zip(&encoded) {
+            *address = super::decode_address(value).map_err(D::Error::custom)?;
+        }
+        Ok(addresses)
+    }
+}
+
+pub mod hex_bytes {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        let mut bytes = [0u8; N];
+        hex::decode_to_slice(&encoded, &mut bytes).map_err(D::Error::custom)?;
+        Ok(bytes)
+    }
+}
+
+pub mod padded_text {
+    use serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+    use crate::state::fixed_bytes;
+
+    /// Length of `text` without its zero padding.
+    pub fn len<const N: usize>(text: &[u8; N]) -> usize {
+        text.iter().rposition(|&byte| byte != 0).map_or(0, |i| i + 1)
+    }
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        text: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let text = core::str::from_utf8(&text[..len(text)]).map_err(ser::Error::custom)?;
+        serializer.serialize_str(text)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        let text = String::deserialize(deserializer)?;
+        if text.len() > N {
+            return Err(de::Error::invalid_length(text.len(), &"text that fits the field"));
+        }
+        Ok(fixed_bytes(text.as_bytes()))
+    }
+}
+
+macro_rules! impl_with_text_len {
+    ($type:ty, $len:ident, $text:ident $(, $check:path)?) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                <$type>::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let mut value = <$type>::deserialize(deserializer)?;
+                value.$len = padded_text::len(&value.$text) as u8;
+                $($check(&value).map_err(de::Error::custom)?;)?
+                Ok(value)
+            }
+        }
+    };
+}
+
+fn check_adapter_signer_count(org: &Organization) -> Result<(), &'static str> {
+    if org.adapter_signer_count as usize > MAX_ADAPTER_SIGNERS {
+        return Err("adapter_signer_count exceeds MAX_ADAPTER_SIGNERS");
+    }
+    Ok(())
+}
+
+impl_with_text_len!(Organization, name_len, name, check_adapter_signer_count);
+impl_with_text_len!(Proposal, title_len, title);
@@ -0,0 +1,1292 @@
+//! On-chain account layouts.
+//!
+//! Every account starts with a one-byte discriminator followed by a fixed-size,
+//! little-endian body. Timestamps use `0` to mean "not set".
+
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    codec::{Reader, Writer},
+    error::GovernanceError,
+    math,
+};
+
+pub const ORGANIZATION_DISCRIMINATOR: u8 = 1;
+pub const PROPOSAL_DISCRIMINATOR: u8 = 2;
+pub const PROPOSAL_RESULTS_DISCRIMINATOR: u8 = 3;
+pub const SEASON_DISCRIMINATOR: u8 = 4;
+pub const PROPOSAL_SUMMARY_DISCRIMINATOR: u8 = 5;
+pub const ORG_STATS_DISCRIMINATOR: u8 = 6;
+pub const ORG_CONFIG_DISCRIMINATOR: u8 = 7;
+pub const AUDIT_LOG_DISCRIMINATOR: u8 = 8;
+pub const CLOSED_ORGANIZATION_DISCRIMINATOR: u8 = 9;
+
+pub const MAX_NAME_LEN: usize = 64;
+pub const MAX_TITLE_LEN: usize = 128;
+pub const MAX_ADAPTER_SIGNERS: usize = 4;
+/// Number of admin actions an `AuditLog` keeps before overwriting the oldest.
+pub const AUDIT_LOG_CAPACITY: usize = 32;
+/// Delay between `initiate_recovery` and `complete_recovery`, giving the
+/// current authority time to notice the announcement and cancel it.
+pub const RECOVERY_TIMELOCK_SECONDS: i64 = 14 * 24 * 60 * 60;
+/// Minimum time between `freeze_organization` and `close_organization`, so a
+/// close can never ride along with an accidental freeze.
+pub const ORGANIZATION_CLOSE_DELAY_SECONDS: i64 = 24 * 60 * 60;
+/// Shortest and longest voting window a proposal may declare, so a mistyped
+/// timestamp cannot create a one-second or ten-year vote.
+pub const MIN_VOTING_DURATION_SECONDS: i64 = 60 * 60;
+pub const MAX_VOTING_DURATION_SECONDS: i64 = 90 * 24 * 60 * 60;
+/// Furthest `extend_voting_period` may push a proposal's `end_at` past the end
+/// it was opened with.
+pub const MAX_VOTING_EXTENSION_SECONDS: i64 = 7 * 24 * 60 * 60;
+/// Longest dispute window an organization may set, so committed results
+/// cannot be kept from finalizing indefinitely.
+pub const MAX_DISPUTE_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Fixed-layout account owned by this program.
+pub trait AccountState: Sized {
+    const DISCRIMINATOR: u8;
+    /// Total account size, including the discriminator byte.
+    const LEN: usize;
+
+    fn read_body(reader: &mut Reader) -> Result<Self, GovernanceError>;
+    fn write_body(&self, writer: &mut Writer);
+
+    fn unpack(data: &[u8]) -> Result<Self, GovernanceError> {
+        if data.len() != Self::LEN || data[0] != Self::DISCRIMINATOR {
+            return Err(GovernanceError::InvalidAccountData);
+        }
+        let mut reader = Reader::new(&data[1..], GovernanceError::InvalidAccountData);
+        let state = Self::read_body(&mut reader)?;
+        reader.finish()?;
+        Ok(state)
+    }
+
+    fn pack(&self, data: &mut [u8]) -> Result<(), GovernanceError> {
+        if data.len() != Self::LEN {
+            return Err(GovernanceError::InvalidAccountData);
+        }
+        let mut writer = Writer::new(data);
+        writer.write_u8(Self::DISCRIMINATOR);
+        self.write_body(&mut writer);
+        Ok(())
+    }
+
+    /// Reads the state from a program-owned account.
+    fn load(account: &AccountView, program_id: &Address) -> Result<Self, ProgramError> {
+        if !account.owned_by(program_id) {
+            return Err(GovernanceError::InvalidAccountOwner.into());
+        }
+        let data = account.try_borrow()?;
+        Ok(Self::unpack(&data)?)
+    }
+
+    fn store(&self, account: &AccountView) -> ProgramResult {
+        let mut data = account.try_borrow_mut()?;
+        Ok(self.pack(&mut data)?)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum ProposalStatus {
+    Draft = 0,
+    Open = 1,
+    Closed = 2,
+    Finalized = 3,
+}
+
+impl TryFrom<u8> for ProposalStatus {
+    type Error = GovernanceError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Draft),
+            1 => Ok(Self::Open),
+            2 => Ok(Self::Closed),
+            3 => Ok(Self::Finalized),
+            _ => Err(GovernanceError::InvalidAccountData),
+        }
+    }
+}
+
+impl ProposalStatus {
+    /// Whether the lifecycle allows moving from `self` to `next`.
+    pub fn can_transition_to(self, next: ProposalStatus) -> bool {
+        matches!(
+            (self, next),
+            (Self::Draft, Self::Open) | (Self::Open, Self::Closed) | (Self::Closed, Self::Finalized)
+        )
+    }
+}
+
+/// Hash function behind a stored `content_hash` or `results_hash`. Stored
+/// next to each hash so a later move off SHA-256 needs no layout change.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum HashScheme {
+    #[default]
+    Sha256 = 0,
+    Keccak256 = 1,
+    Blake3 = 2,
+}
+
+impl TryFrom<u8> for HashScheme {
+    type Error = GovernanceError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Sha256),
+            1 => Ok(Self::Keccak256),
+            2 => Ok(Self::Blake3),
+            _ => Err(GovernanceError::InvalidAccountData),
+        }
+    }
+}
+
+impl HashScheme {
+    /// Fails unless new hashes may be committed under this scheme. Only
+    /// SHA-256 is accepted for now: `verify_results_hash` recomputes results
+    /// hashes with the SHA-256 syscall, and the other schemes are reserved.
+    pub fn check_supported(self) -> Result<(), GovernanceError> {
+        match self {
+            Self::Sha256 => Ok(()),
+            Self::Keccak256 | Self::Blake3 => Err(GovernanceError::UnsupportedHashScheme),
+        }
+    }
+}
+
+/// Copies `value` into a zero-padded fixed buffer.
+pub fn fixed_bytes<const N: usize>(value: &[u8]) -> [u8; N] {
+    let mut out = [0u8; N];
+    out[..value.len()].copy_from_slice(value);
+    out
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(remote = "Self")
+)]
+pub struct Organization {
+    pub bump: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub organization_id: [u8; 16],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub authority: Address,
+    pub proposal_count: u64,
+    /// Proposals reduced to a `ProposalSummary` by `archive_proposal` or
+    /// closed by `discard_proposal`.
+    pub archived_proposal_count: u64,
+    /// Seasons created and not yet closed.
+    pub season_count: u64,
+    /// Whether the `OrgStats` account exists; finalizing then requires it.
+    pub has_stats: bool,
+    /// Whether the `OrgConfig` account exists; creating proposals and changing
+    /// their voting window then require it.
+    pub has_config: bool,
+    /// Whether the `AuditLog` account exists; privileged instructions then
+    /// require it and append to it.
+    pub has_audit_log: bool,
+    /// Next nonce expected by adapter-submitted operations.
+    pub operation_nonce: u64,
+    /// Adapter service keys allowed to open, close, and commit results.
+    pub adapter_signer_count: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address_array"))]
+    pub adapter_signers: [Address; MAX_ADAPTER_SIGNERS],
+    /// Key that may replace a lost authority, or the default address for none.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub recovery_authority: Address,
+    /// Authority announced by `initiate_recovery`, pending the timelock.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub pending_authority: Address,
+    pub recovery_initiated_at: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    /// When `freeze_organization` made the organization read-only, or 0.
+    pub frozen_at: i64,
+    /// How long committed results stay open to disputes before they can be
+    /// finalized, fixed on each results account when it is committed.
+    pub dispute_window_seconds: i64,
+    /// Lamports a challenger other than the authority posts to dispute
+    /// results, or 0 to leave disputes to the authority.
+    pub dispute_bond_lamports: u64,
+    /// Not serialized; rebuilt from the text when deserializing.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub name_len: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::padded_text"))]
+    pub name: [u8; MAX_NAME_LEN],
+}
+
+impl Organization {
+    pub fn name(&self) -> &[u8] {
+        &self.name[..self.name_len as usize]
+    }
+
+    /// Consumes `nonce` if it is the next expected operation nonce, so a
+    /// retried transaction cannot apply the same operation twice.
+    pub fn advance_operation_nonce(&mut self, nonce: u64) -> Result<(), GovernanceError> {
+        if nonce != self.operation_nonce {
+            return Err(GovernanceError::StaleOperationNonce);
+        }
+        self.operation_nonce = math::checked_add(nonce, 1)?;
+        Ok(())
+    }
+
+    pub fn adapter_signers(&self) -> &[Address] {
+        &self.adapter_signers[..self.adapter_signer_count as usize]
+    }
+
+    pub fn is_adapter_signer(&self, key: &Address) -> bool {
+        self.adapter_signers().contains(key)
+    }
+
+    pub fn add_adapter_signer(&mut self, key: Address) -> Result<(), GovernanceError> {
+        if self.is_adapter_signer(&key) {
+            return Err(GovernanceError::AdapterSignerAlreadyRegistered);
+        }
+        let count = self.adapter_signer_count as usize;
+        if count == MAX_ADAPTER_SIGNERS {
+            return Err(GovernanceError::AdapterSignerLimitReached);
+        }
+        self.adapter_signers[count] = key;
+        self.adapter_signer_count += 1;
+        Ok(())
+    }
+
+    pub fn remove_adapter_signer(&mut self, key: &Address) -> Result<(), GovernanceError> {
+        let index = self
+            .adapter_signers()
+            .iter()
+            .position(|signer| signer == key)
+            .ok_or(GovernanceError::AdapterSignerNotFound)?;
+        let last = self.adapter_signer_count as usize - 1;
+        self.adapter_signers.swap(index, last);
+        self.adapter_signers[last] = Address::default();
+        self.adapter_signer_count -= 1;
+        Ok(())
+    }
+
+    pub fn has_recovery_authority(&self) -> bool {
+        self.recovery_authority != Address::default()
+    }
+
+    pub fn recovery_pending(&self) -> bool {
+        self.recovery_initiated_at != 0
+    }
+
+    /// Announces that `new_authority` will replace the current authority once
+    /// the recovery timelock has elapsed.
+    pub fn initiate_recovery(
+        &mut self,
+        new_authority: Address,
+        now: i64,
+    ) -> Result<(), GovernanceError> {
+        if !self.has_recovery_authority() {
+            return Err(GovernanceError::RecoveryNotConfigured);
+        }
+        if self.recovery_pending() {
+            return Err(GovernanceError::RecoveryAlreadyInitiated);
+        }
+        if new_authority == Address::default() {
+            return Err(GovernanceError::InvalidAuthority);
+        }
+        self.pending_authority = new_authority;
+        self.recovery_initiated_at = now;
+        Ok(())
+    }
+
+    pub fn cancel_recovery(&mut self) -> Result<(), GovernanceError> {
+        if !self.recovery_pending() {
+            return Err(GovernanceError::RecoveryNotInitiated);
+        }
+        self.pending_authority = Address::default();
+        self.recovery_initiated_at = 0;
+        Ok(())
+    }
+
+    /// Installs the pending authority if the timelock has elapsed.
+    pub fn complete_recovery(&mut self, now: i64) -> Result<(), GovernanceError> {
+        if !self.recovery_pending() {
+            return Err(GovernanceError::RecoveryNotInitiated);
+        }
+        let unlocks_at =
+            math::checked_add_i64(self.recovery_initiated_at, RECOVERY_TIMELOCK_SECONDS)?;
+        if now < unlocks_at {
+            return Err(GovernanceError::RecoveryTimelockActive);
+        }
+        self.authority = core::mem::take(&mut self.pending_authority);
+        self.recovery_initiated_at = 0;
+        Ok(())
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_at != 0
+    }
+
+    pub fn require_not_frozen(&self) -> Result<(), GovernanceError> {
+        if self.is_frozen() {
+            return Err(GovernanceError::OrganizationFrozen);
+        }
+        Ok(())
+    }
+
+    pub fn require_frozen(&self) -> Result<(), GovernanceError> {
+        if !self.is_frozen() {
+            return Err(GovernanceError::OrganizationNotFrozen);
+        }
+        Ok(())
+    }
+
+    /// Sets how long committed results stay open to disputes and the bond a
+    /// challenger other than the authority posts to raise one.
+    pub fn set_dispute_policy(
+        &mut self,
+        window_seconds: i64,
+        bond_lamports: u64,
+    ) -> Result<(), GovernanceError> {
+        if !(0..=MAX_DISPUTE_WINDOW_SECONDS).contains(&window_seconds) {
+            return Err(GovernanceError::InvalidDisputeWindow);
+        }
+        self.dispute_window_seconds = window_seconds;
+        self.dispute_bond_lamports = bond_lamports;
+        Ok(())
+    }
+
+    /// Makes the organization permanently read-only. Refused while a recovery
+    /// is pending, so a compromised authority cannot freeze the organization
+    /// to stop its own replacement.
+    pub fn freeze(&mut self, now: i64) -> Result<(), GovernanceError> {
+        self.require_not_frozen()?;
+        if self.recovery_pending() {
+            return Err(GovernanceError::RecoveryAlreadyInitiated);
+        }
+        self.frozen_at = now;
+        Ok(())
+    }
+
+    /// Checks that the organization may be closed at `now`: it has been frozen
+    /// for at least the close delay, every proposal it held is archived, and
+    /// its seasons, stats, config and audit log accounts are closed.
+    pub fn check_closable(&self, now: i64) -> Result<(), GovernanceError> {
+        self.require_frozen()?;
+        if self.archived_proposal_count != self.proposal_count
+            || self.season_count != 0
+            || self.has_stats
+            || self.has_config
+            || self.has_audit_log
+        {
+            return Err(GovernanceError::OrganizationNotEmpty);
+        }
+        let closable_at = math::checked_add_i64(self.frozen_at, ORGANIZATION_CLOSE_DELAY_SECONDS)?;
+        if now < closable_at {
+            return Err(GovernanceError::CloseDelayActive);
+        }
+        Ok(())
+    }
+}
+
+impl AccountState for Organization {
+    const DISCRIMINATOR: u8 = ORGANIZATION_DISCRIMINATOR;
+    const LEN: usize =
+        1 + 1 + 16 + 32 + 8 * 4 + 3 + 1 + 32 * MAX_ADAPTER_SIGNERS + 32 + 32 + 8 * 6 + 1
+            + MAX_NAME_LEN;
+
+    fn read_body(reader: &mut Reader) -> Result<Self, GovernanceError> {
+        let bump = reader.read_u8()?;
+        let organization_id = reader.read_array()?;
+        let authority = Address::new_from_array(reader.read_array()?);
+        let proposal_count = reader.read_u64()?;
+        let archived_proposal_count = reader.read_u64()?;
+        let season_count = reader.read_u64()?;
+        let has_stats = reader.read_bool()?;
+        let has_config = reader.read_bool()?;
+        let has_audit_log = reader.read_bool()?;
+        let operation_nonce = reader.read_u64()?;
+        let adapter_signer_count = reader.read_u8()?;
+        if adapter_signer_count as usize > MAX_ADAPTER_SIGNERS {
+            return Err(GovernanceError::InvalidAccountData);
+        }
+        let mut adapter_signers: [Address; MAX_ADAPTER_SIGNERS] = Default::default();
+        for signer in &mut adapter_signers {
+            *signer = Address::new_from_array(reader.read_array()?);
+        }
+
+        Ok(Self {
+            bump,
+            organization_id,
+            authority,
+            proposal_count,
+            archived_proposal_count,
+            season_count,
+            has_stats,
+            has_config,
+            has_audit_log,
+            operation_nonce,
+            adapter_signer_count,
+            adapter_signers,
+            recovery_authority: Address::new_from_array(reader.read_array()?),
+            pending_authority: Address::new_from_array(reader.read_array()?),
+            recovery_initiated_at: reader.read_i64()?,
+            created_at: reader.read_i64()?,
+            updated_at: reader.read_i64()?,
+            frozen_at: reader.read_i64()?,
+            dispute_window_seconds: reader.read_i64()?,
+            dispute_bond_lamports: reader.read_u64()?,
+            name_len: reader.read_u8()?,
+            name: reader.read_array()?,
+        })
+    }
+
+    fn write_body(&self, writer: &mut Writer) {
+        writer.write_u8(self.bump);
+        writer.write_bytes(&self.organization_id);
+        writer.write_bytes(self.authority.as_ref());
+        writer.write_u64(self.proposal_count);
+        writer.write_u64(self.archived_proposal_count);
+        writer.write_u64(self.season_count);
+        writer.write_bool(self.has_stats);
+        writer.write_bool(self.has_config);
+        writer.write_bool(self.has_audit_log);
+        writer.write_u64(self.operation_nonce);
+        writer.write_u8(self.adapter_signer_count);
+        for signer in &self.adapter_signers {
+            writer.write_bytes(signer.as_ref());
+        }
+        writer.write_bytes(self.recovery_authority.as_ref());
+        writer.write_bytes(self.pending_authority.as_ref());
+        writer.write_i64(self.recovery_initiated_at);
+        writer.write_i64(self.created_at);
+        writer.write_i64(self.updated_at);
+        writer.write_i64(self.frozen_at);
+        writer.write_i64(self.dispute_window_seconds);
+        writer.write_u64(self.dispute_bond_lamports);
+        writer.write_u8(self.name_len);
+        writer.write_bytes(&self.name);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(remote = "Self")
+)]
+pub struct Proposal {
+    pub bump: u8,
+    pub status: ProposalStatus,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub organization: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub proposal_id: [u8; 16],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub created_by: Address,
+    /// Season the proposal belongs to, or the default address for none.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub season: Address,
+    /// Results PDA written when the results were committed, or the default
+    /// address before then. Finalizing only accepts this account.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub results: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub content_hash: [u8; 32],
+    pub content_hash_scheme: HashScheme,
+    pub start_at: i64,
+    pub end_at: i64,
+    /// `end_at` before `extend_voting_period` moved it, or 0 if the voting
+    /// period was never extended.
+    pub original_end_at: i64,
+    pub quorum_requirement: u64,
+    pub eligible_voting_power: u64,
+    /// Slot that voting-power snapshots for this proposal are pinned to, or 0
+    /// while it is a draft. Opening records the current slot;
+    /// `open_proposal_with_snapshot` records the slot its snapshot was taken
+    /// at instead, which may be earlier.
+    pub snapshot_slot: u64,
+    /// Merkle root of the eligible voters and their weights at `snapshot_slot`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub voter_merkle_root: [u8; 32],
+    pub created_at: i64,
+    pub updated_at: i64,
+    /// When the proposal moved to `Open`, or 0.
+    pub opened_at: i64,
+    /// When the proposal moved to `Closed`, or 0.
+    pub closed_at: i64,
+    /// Times the draft was amended with `update_proposal_draft`.
+    pub amendment_count: u64,
+    /// Head of the status hash chain: zero at creation, then
+    /// `Proposal::chain_history_hash` of the previous head after every
+    /// transition.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub history_hash: [u8; 32],
+    /// Not serialized; rebuilt from the text when deserializing.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub title_len: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::padded_text"))]
+    pub title: [u8; MAX_TITLE_LEN],
+}
+
+impl Proposal {
+    pub fn title(&self) -> &[u8] {
+        &self.title[..self.title_len as usize]
+    }
+
+    pub fn has_season(&self) -> bool {
+        self.season != Address::default()
+    }
+
+    pub fn has_results(&self) -> bool {
+        self.results != Address::default()
+    }
+
+    pub fn was_extended(&self) -> bool {
+        self.original_end_at != 0
+    }
+
+    /// Checks that the proposal may move to `status` at `now`. Voting opens
+    /// from `start_at` until `end_at`, and closes no earlier than `end_at`.
+    pub fn check_transition(
+        &self,
+        status: ProposalStatus,
+        now: i64,
+    ) -> Result<(), GovernanceError> {
+        if !self.status.can_transition_to(status) {
+            return Err(GovernanceError::InvalidStatusTransition);
+        }
+        match status {
+            ProposalStatus::Open if now < self.start_at => Err(GovernanceError::VotingNotStarted),
+            ProposalStatus::Open if now >= self.end_at => Err(GovernanceError::VotingEnded),
+            ProposalStatus::Closed if now < self.end_at => Err(GovernanceError::VotingNotEnded),
+            _ => Ok(()),
+        }
+    }
+
+    /// Moves the proposal to `status` at `now`, recording when voting opened
+    /// or closed. Handlers open proposals with `open` or `open_with_snapshot`
+    /// instead, so the snapshot slot is always recorded.
+    pub fn transition(&mut self, status: ProposalStatus, now: i64) -> Result<(), GovernanceError> {
+        self.check_transition(status, now)?;
+        self.apply_transition(status, now);
+        Ok(())
+    }
+
+    /// Opens a draft proposal at `now` and pins `snapshot_slot` to the
+    /// current `slot`.
+    pub fn open(&mut self, now: i64, slot: u64) -> Result<(), GovernanceError> {
+        self.transition(ProposalStatus::Open, now)?;
+        self.snapshot_slot = slot;
+        Ok(())
+    }
+
+    fn apply_transition(&mut self, status: ProposalStatus, now: i64) {
+        match status {
+            ProposalStatus::Open => self.opened_at = now,
+            ProposalStatus::Closed => self.closed_at = now,
+            ProposalStatus::Draft | ProposalStatus::Finalized => {}
+        }
+        self.history_hash = Self::chain_history_hash(&self.history_hash, status, now);
+        self.status = status;
+        self.updated_at = now;
+    }
+
+    /// Next head of the status hash chain:
+    /// `sha256(previous || status as u8 || now as i64 little-endian)`.
+    /// Auditors replay it from zero over the recorded transitions.
+    pub fn chain_history_hash(previous: &[u8; 32], status: ProposalStatus, now: i64) -> [u8; 32] {
+        solana_sha256_hasher::hashv(&[previous.as_slice(), &[status as u8], &now.to_le_bytes()])
+            .to_bytes()
+    }
+
+    /// Opens a draft proposal with the eligibility snapshot taken for it.
+    pub fn open_with_snapshot(
+        &mut self,
+        snapshot_slot: u64,
+        voter_merkle_root: [u8; 32],
+        eligible_voting_power: u64,
+        now: i64,
+    ) -> Result<(), GovernanceError> {
+        self.check_transition(ProposalStatus::Open, now)?;
+        self.set_eligible_voting_power(eligible_voting_power)?;
+        self.apply_transition(ProposalStatus::Open, now);
+        self.snapshot_slot = snapshot_slot;
+        self.voter_merkle_root = voter_merkle_root;
+        Ok(())
+    }
+
+    /// Moves the end of an open vote to the later `end_at`, once per proposal
+    /// and by at most `MAX_VOTING_EXTENSION_SECONDS`, keeping the original end
+    /// in `original_end_at`.
+    pub fn extend_voting_period(&mut self, end_at: i64, now: i64) -> Result<(), GovernanceError> {
+        if self.status != ProposalStatus::Open {
+            return Err(GovernanceError::ProposalNotOpen);
+        }
+        if self.was_extended() {
+            return Err(GovernanceError::VotingPeriodAlreadyExtended);
+        }
+        if now >= self.end_at {
+            return Err(GovernanceError::VotingEnded);
+        }
+        if end_at <= self.end_at {
+            return Err(GovernanceError::InvalidVotingExtension);
+        }
+        if end_at.saturating_sub(self.end_at) > MAX_VOTING_EXTENSION_SECONDS {
+            return Err(GovernanceError::VotingExtensionTooLong);
+        }
+        Self::validate_voting_duration(self.start_at, end_at)?;
+        self.original_end_at = self.end_at;
+        self.end_at = end_at;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Overwrites the eligible voting power. Only drafts may change it, since
+    /// quorum is measured against it once voting opens.
+    pub fn set_eligible_voting_power(
+        &mut self,
+        eligible_voting_power: u64,
+    ) -> Result<(), GovernanceError> {
+        if self.status != ProposalStatus::Draft {
+            return Err(GovernanceError::VotingPowerLocked);
+        }
+        Self::validate_quorum(self.quorum_requirement, eligible_voting_power)?;
+        self.eligible_voting_power = eligible_voting_power;
+        Ok(())
+    }
+
+    /// Checks that a quorum requirement can be met by the eligible power.
+    pub fn validate_quorum(
+        quorum_requirement: u64,
+        eligible_voting_power: u64,
+    ) -> Result<(), GovernanceError> {
+        if quorum_requirement > 0 && eligible_voting_power == 0 {
+            return Err(GovernanceError::ZeroEligibleVotingPower);
+        }
+        if quorum_requirement > eligible_voting_power {
+            return Err(GovernanceError::QuorumExceedsEligiblePower);
+        }
+        Ok(())
+    }
+
+    /// Checks that a voting window lasts between `MIN_VOTING_DURATION_SECONDS`
+    /// and `MAX_VOTING_DURATION_SECONDS`.
+    pub fn validate_voting_duration(start_at: i64, end_at: i64) -> Result<(), GovernanceError> {
+        let duration = end_at.saturating_sub(start_at);
+        if duration < MIN_VOTING_DURATION_SECONDS {
+            return Err(GovernanceError::VotingWindowTooShort);
+        }
+        if duration > MAX_VOTING_DURATION_SECONDS {
+            return Err(GovernanceError::VotingWindowTooLong);
+        }
+        Ok(())
+    }
+
+    /// Checks that committed results could have come from this proposal's
+    /// eligible voters.
+    pub fn validate_votes_cast(&self, total_votes_cast: u64) -> Result<(), GovernanceError> {
+        if total_votes_cast > self.eligible_voting_power {
+            return Err(GovernanceError::VotesExceedEligiblePower);
+        }
+        Ok(())
+    }
+}
+
+impl AccountState for Proposal {
+    const DISCRIMINATOR: u8 = PROPOSAL_DISCRIMINATOR;
+    const LEN: usize =
+        1 + 1 + 1 + 32 + 16 + 32 + 32 + 32 + 32 + 1 + 8 * 11 + 32 + 32 + 1 + MAX_TITLE_LEN;
+
+    fn read_body(reader: &mut Reader) -> Result<Self, GovernanceError> {
+        Ok(Self {
+            bump: reader.read_u8()?,
+            status: ProposalStatus::try_from(reader.read_u8()?)?,
+            organization: Address::new_from_array(reader.read_array()?),
+            proposal_id: reader.read_array()?,
+            created_by: Address::new_from_array(reader.read_array()?),
+            season: Address::new_from_array(reader.read_array()?),
+            results: Address::new_from_array(reader.read_array()?),
+            content_hash: reader.read_array()?,
+            content_hash_scheme: HashScheme::try_from(reader.read_u8()?)?,
+            start_at: reader.read_i64()?,
+            end_at: reader.read_i64()?,
+            original_end_at: reader.read_i64()?,
+            quorum_requirement: reader.read_u64()?,
+            eligible_voting_power: reader.read_u64()?,
+            snapshot_slot: reader.read_u64()?,
+            voter_merkle_root: reader.read_array()?,
+            created_at: reader.read_i64()?,
+            updated_at: reader.read_i64()?,
+            opened_at: reader.read_i64()?,
+            closed_at: reader.read_i64()?,
+            amendment_count: reader.read_u64()?,
+            history_hash: reader.read_array()?,
+            title_len: reader.read_u8()?,
+            title: reader.read_array()?,
+        })
+    }
+
+    fn write_body(&self, writer: &mut Writer) {
+        writer.write_u8(self.bump);
+        writer.write_u8(self.status as u8);
+        writer.write_bytes(self.organization.as_ref());
+        writer.write_bytes(&self.proposal_id);
+        writer.write_bytes(self.created_by.as_ref());
+        writer.write_bytes(self.season.as_ref());
+        writer.write_bytes(self.results.as_ref());
+        writer.write_bytes(&self.content_hash);
+        writer.write_u8(self.content_hash_scheme as u8);
+        writer.write_i64(self.start_at);
+        writer.write_i64(self.end_at);
+        writer.write_i64(self.original_end_at);
+        writer.write_u64(self.quorum_requirement);
+        writer.write_u64(self.eligible_voting_power);
+        writer.write_u64(self.snapshot_slot);
+        writer.write_bytes(&self.voter_merkle_root);
+        writer.write_i64(self.created_at);
+        writer.write_i64(self.updated_at);
+        writer.write_i64(self.opened_at);
+        writer.write_i64(self.closed_at);
+        writer.write_u64(self.amendment_count);
+        writer.write_bytes(&self.history_hash);
+        writer.write_u8(self.title_len);
+        writer.write_bytes(&self.title);
+    }
+}
+
+/// Results commitment for a closed proposal.
+///
+/// `results_hash` is `results::compute_results_hash()` over the off-chain tally.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposalResults {
+    pub bump: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub proposal: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub results_hash: [u8; 32],
+    pub results_hash_scheme: HashScheme,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub winning_option_id: [u8; 16],
+    pub total_votes_cast: u64,
+    pub quorum_met: bool,
+    pub committed_at: i64,
+    pub finalized_at: i64,
+    /// End of the dispute window: `committed_at` plus the organization's
+    /// `dispute_window_seconds` at commit time.
+    pub dispute_ends_at: i64,
+    /// Who flagged the results with `dispute_results`, or the default address.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub disputed_by: Address,
+    /// When the results were flagged, or 0.
+    pub disputed_at: i64,
+    /// Lamports the challenger posted, held in this account and returned to
+    /// them when the proposal is finalized.
+    pub dispute_bond: u64,
+    /// Set once `verify_results_hash` has hashed a published preimage for
+    /// this proposal to `results_hash`.
+    pub hash_verified: bool,
+}
+
+impl ProposalResults {
+    pub fn is_disputed(&self) -> bool {
+        self.disputed_at != 0
+    }
+
+    /// Flags the results on behalf of `challenger`, who posted `bond`. Only
+    /// one dispute is recorded, and only before the window ends.
+    pub fn dispute(
+        &mut self,
+        challenger: Address,
+        bond: u64,
+        now: i64,
+    ) -> Result<(), GovernanceError> {
+        if now >= self.dispute_ends_at {
+            return Err(GovernanceError::DisputeWindowClosed);
+        }
+        if self.is_disputed() {
+            return Err(GovernanceError::ResultsDisputed);
+        }
+        self.disputed_by = challenger;
+        self.disputed_at = now;
+        self.dispute_bond = bond;
+        Ok(())
+    }
+
+    /// Checks that the dispute window has ended, so the results may be
+    /// finalized at `now`.
+    pub fn check_dispute_window_ended(&self, now: i64) -> Result<(), GovernanceError> {
+        if now < self.dispute_ends_at {
+            return Err(GovernanceError::DisputeWindowOpen);
+        }
+        Ok(())
+    }
+}
+
+impl AccountState for ProposalResults {
+    const DISCRIMINATOR: u8 = PROPOSAL_RESULTS_DISCRIMINATOR;
+    const LEN: usize = 1 + 1 + 32 + 32 + 1 + 16 + 8 + 1 + 8 + 8 + 8 + 32 + 8 + 8 + 1;
+
+    fn read_body(reader: &mut Reader) -> Result<Self, GovernanceError> {
+        Ok(Self {
+            bump: reader.read_u8()?,
+            proposal: Address::new_from_array(reader.read_array()?),
+            results_hash: reader.read_array()?,
+            results_hash_scheme: HashScheme::try_from(reader.read_u8()?)?,
+            winning_option_id: reader.read_array()?,
+            total_votes_cast: reader.read_u64()?,
+            quorum_met: reader.read_bool()?,
+            committed_at: reader.read_i64()?,
+            finalized_at: reader.read_i64()?,
+            dispute_ends_at: reader.read_i64()?,
+            disputed_by: Address::new_from_array(reader.read_array()?),
+            disputed_at: reader.read_i64()?,
+            dispute_bond: reader.read_u64()?,
+            hash_verified: reader.read_bool()?,
+        })
+    }
+
+    fn write_body(&self, writer: &mut Writer) {
+        writer.write_u8(self.bump);
+        writer.write_bytes(self.proposal.as_ref());
+        writer.write_bytes(&self.results_hash);
+        writer.write_u8(self.results_hash_scheme as u8);
+        writer.write_bytes(&self.winning_option_id);
+        writer.write_u64(self.total_votes_cast);
+        writer.write_bool(self.quorum_met);
+        writer.write_i64(self.committed_at);
+        writer.write_i64(self.finalized_at);
+        writer.write_i64(self.dispute_ends_at);
+        writer.write_bytes(self.disputed_by.as_ref());
+        writer.write_i64(self.disputed_at);
+        writer.write_u64(self.dispute_bond);
+        writer.write_bool(self.hash_verified);
+    }
+}
+
+/// A club season grouping proposals, with aggregates updated on finalize.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Season {
+    pub bump: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub organization: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub season_id: [u8; 16],
+    pub start_at: i64,
+    pub end_at: i64,
+    pub proposal_count: u64,
+    pub finalized_proposal_count: u64,
+    pub total_votes_cast: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl Season {
+    /// Whether a voting window lies entirely within the season.
+    pub fn contains(&self, start_at: i64, end_at: i64) -> bool {
+        start_at >= self.start_at && end_at <= self.end_at
+    }
+}
+
+impl AccountState for Season {
+    const DISCRIMINATOR: u8 = SEASON_DISCRIMINATOR;
+    const LEN: usize = 1 + 1 + 32 + 16 + 8 * 7;
+
+    fn read_body(reader: &mut Reader) -> Result<Self, GovernanceError> {
+        Ok(Self {
+            bump: reader.read_u8()?,
+            organization: Address::new_from_array(reader.read_array()?),
+            season_id: reader.read_array()?,
+            start_at: reader.read_i64()?,
+            end_at: reader.read_i64()?,
+            proposal_count: reader.read_u64()?,
+            finalized_proposal_count: reader.read_u64()?,
+            total_votes_cast: reader.read_u64()?,
+            created_at: reader.read_i64()?,
+            updated_at: reader.read_i64()?,
+        })
+    }
+
+    fn write_body(&self, writer: &mut Writer) {
+        writer.write_u8(self.bump);
+        writer.write_bytes(self.organization.as_ref());
+        writer.write_bytes(&self.season_id);
+        writer.write_i64(self.start_at);
+        writer.write_i64(self.end_at);
+        writer.write_u64(self.proposal_count);
+        writer.write_u64(self.finalized_proposal_count);
+        writer.write_u64(self.total_votes_cast);
+        writer.write_i64(self.created_at);
+        writer.write_i64(self.updated_at);
+    }
+}
+
+/// Compact record that `archive_proposal` writes over a finalized proposal,
+/// at the proposal's address, once its results account is closed. It commits
+/// only to ids and hashes: the content, voter set and tally live off-chain and
+/// can be checked against `content_hash`, `voter_merkle_root` and
+/// `results_hash`. Because it stays at the proposal address, the proposal id
+/// cannot be created again.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposalSummary {
+    pub bump: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub organization: Address,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub proposal_id: [u8; 16],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub content_hash: [u8; 32],
+    pub content_hash_scheme: HashScheme,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub voter_merkle_root: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub results_hash: [u8; 32],
+    pub results_hash_scheme: HashScheme,
+}
+
+impl ProposalSummary {
+    pub fn new(proposal: &Proposal, results: &ProposalResults) -> Self {
+        Self {
+            bump: proposal.bump,
+            organization: proposal.organization,
+            proposal_id: proposal.proposal_id,
+            content_hash: proposal.content_hash,
+            content_hash_scheme: proposal.content_hash_scheme,
+            voter_merkle_root: proposal.voter_merkle_root,
+            results_hash: results.results_hash,
+            results_hash_scheme: results.results_hash_scheme,
+        }
+    }
+}
+
+impl AccountState for ProposalSummary {
+    const DISCRIMINATOR: u8 = PROPOSAL_SUMMARY_DISCRIMINATOR;
+    const LEN: usize = 1 + 1 + 32 + 16 + 32 + 1 + 32 + 32 + 1;
+
+    fn read_body(reader: &mut Reader) -> Result<Self, GovernanceError> {
+        Ok(Self {
+            bump: reader.read_u8()?,
+            organization: Address::new_from_array(reader.read_array()?),
+            proposal_id: reader.read_array()?,
+            content_hash: reader.read_array()?,
+            content_hash_scheme: HashScheme::try_from(reader.read_u8()?)?,
+            voter_merkle_root: reader.read_array()?,
+            results_hash: reader.read_array()?,
+            results_hash_scheme: HashScheme::try_from(reader.read_u8()?)?,
+        })
+    }
+
+    fn write_body(&self, writer: &mut Writer) {
+        writer.write_u8(self.bump);
+        writer.write_bytes(self.organization.as_ref());
+        writer.write_bytes(&self.proposal_id);
+        writer.write_bytes(&self.content_hash);
+        writer.write_u8(self.content_hash_scheme as u8);
+        writer.write_bytes(&self.voter_merkle_root);
+        writer.write_bytes(&self.results_hash);
+        writer.write_u8(self.results_hash_scheme as u8);
+    }
+}
+
+/// Organization-wide aggregates, updated by every finalization once the
+/// account exists. Statistics only: the counters saturate instead of failing
+/// the finalize.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrgStats {
+    pub bump: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub organization: Address,
+    pub quorum_met_count: u64,
+    pub quorum_failed_count: u64,
+    pub total_votes_cast: u64,
+    /// Sum of each finalized proposal's turnout, in basis points of its
+    /// eligible voting power.
+    pub turnout_bps_sum: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl OrgStats {
+    pub fn finalized_proposal_count(&self) -> u64 {
+        self.quorum_met_count
+            .saturating_add(self.quorum_failed_count)
+    }
+
+    /// Mean turnout across finalized proposals, in basis points.
+    pub fn average_turnout_bps(&self) -> u64 {
+        self.turnout_bps_sum
+            .checked_div(self.finalized_proposal_count())
+            .unwrap_or(0)
+    }
+
+    /// Counts a finalized proposal with its committed results.
+    pub fn record_finalized(&mut self, proposal: &Proposal, results: &ProposalResults, now: i64) {
+        let outcome = if results.quorum_met {
+            &mut self.quorum_met_count
+        } else {
+            &mut self.quorum_failed_count
+        };
+        *outcome = outcome.saturating_add(1);
+        self.total_votes_cast = self
+            .total_votes_cast
+            .saturating_add(results.total_votes_cast);
+        let turnout = math::ratio_bps(results.total_votes_cast, proposal.eligible_voting_power);
+        self.turnout_bps_sum = self.turnout_bps_sum.saturating_add(turnout);
+        self.updated_at = now;
+    }
+}
+
+impl AccountState for OrgStats {
+    const DISCRIMINATOR: u8 = ORG_STATS_DISCRIMINATOR;
+    const LEN: usize = 1 + 1 + 32 + 8 * 4 + 8 * 2;
+
+    fn read_body(reader: &mut Reader) -> Result<Self, GovernanceError> {
+        Ok(Self {
+            bump: reader.read_u8()?,
+            organization: Address::new_from_array(reader.read_array()?),
+            quorum_met_count: reader.read_u64()?,
+            quorum_failed_count: reader.read_u64()?,
+            total_votes_cast: reader.read_u64()?,
+            turnout_bps_sum: reader.read_u64()?,
+            created_at: reader.read_i64()?,
+            updated_at: reader.read_i64()?,
+        })
+    }
+
+    fn write_body(&self, writer: &mut Writer) {
+        writer.write_u8(self.bump);
+        writer.write_bytes(self.organization.as_ref());
+        writer.write_u64(self.quorum_met_count);
+        writer.write_u64(self.quorum_failed_count);
+        writer.write_u64(self.total_votes_cast);
+        writer.write_u64(self.turnout_bps_sum);
+        writer.write_i64(self.created_at);
+        writer.write_i64(self.updated_at);
+    }
+}
+
+/// Organization-wide proposal defaults and limits, set by `set_org_config`.
+/// Proposals created without an `end_at` or quorum take the defaults, and
+/// every voting window must also fit the organization's duration limits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrgConfig {
+    pub bump: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub organization: Address,
+    pub default_quorum_requirement: u64,
+    pub default_voting_duration_seconds: i64,
+    pub min_voting_duration_seconds: i64,
+    pub max_voting_duration_seconds: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl OrgConfig {
+    /// Checks that the duration limits are ordered and within the program's
+    /// own limits, with the default duration between them.
+    pub fn validate(&self) -> Result<(), GovernanceError> {
+        if self.min_voting_duration_seconds < MIN_VOTING_DURATION_SECONDS
+            || self.min_voting_duration_seconds > self.default_voting_duration_seconds
+            || self.default_voting_duration_seconds > self.max_voting_duration_seconds
+            || self.max_voting_duration_seconds > MAX_VOTING_DURATION_SECONDS
+        {
+            return Err(GovernanceError::InvalidOrgConfig);
+        }
+        Ok(())
+    }
+
+    /// `end_at` of a proposal starting at `start_at` with the default
+    /// duration.
+    pub fn default_end_at(&self, start_at: i64) -> Result<i64, GovernanceError> {
+        math::checked_add_i64(start_at, self.default_voting_duration_seconds)
+    }
+
+    /// Checks a voting window against the organization's duration limits.
+    pub fn check_voting_duration(&self, start_at: i64, end_at: i64) -> Result<(), GovernanceError> {
+        let duration = end_at.saturating_sub(start_at);
+        if duration < self.min_voting_duration_seconds {
+            return Err(GovernanceError::VotingWindowTooShort);
+        }
+        if duration > self.max_voting_duration_seconds {
+            return Err(GovernanceError::VotingWindowTooLong);
+        }
+        Ok(())
+    }
+}
+
+impl AccountState for OrgConfig {
+    const DISCRIMINATOR: u8 = ORG_CONFIG_DISCRIMINATOR;
+    const LEN: usize = 1 + 1 + 32 + 8 * 4 + 8 * 2;
+
+    fn read_body(reader: &mut Reader) -> Result<Self, GovernanceError> {
+        Ok(Self {
+            bump: reader.read_u8()?,
+            organization: Address::new_from_array(reader.read_array()?),
+            default_quorum_requirement: reader.read_u64()?,
+            default_voting_duration_seconds: reader.read_i64()?,
+            min_voting_duration_seconds: reader.read_i64()?,
+            max_voting_duration_seconds: reader.read_i64()?,
+            created_at: reader.read_i64()?,
+            updated_at: reader.read_i64()?,
+        })
+    }
+
+    fn write_body(&self, writer: &mut Writer) {
+        writer.write_u8(self.bump);
+        writer.write_bytes(self.organization.as_ref());
+        writer.write_u64(self.default_quorum_requirement);
+        writer.write_i64(self.default_voting_duration_seconds);
+        writer.write_i64(self.min_voting_duration_seconds);
+        writer.write_i64(self.max_voting_duration_seconds);
+        writer.write_i64(self.created_at);
+        writer.write_i64(self.updated_at);
+    }
+}
+
+/// One privileged instruction recorded in an `AuditLog`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuditEntry {
+    /// Key that signed the instruction.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub actor: Address,
+    /// Instruction tag, such as `instruction::CREATE_PROPOSAL`.
+    pub instruction: u8,
+    /// Account the instruction acted on: the proposal, season, signer key or
+    /// account it created, or the organization itself.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub target: Address,
+    pub timestamp: i64,
+}
+
+impl AuditEntry {
+    pub const LEN: usize = 32 + 1 + 32 + 8;
+}
+
+/// Ring buffer of an organization's last `AUDIT_LOG_CAPACITY` admin actions,
+/// created by `create_audit_log`. Entry `n` is stored in slot
+/// `n % AUDIT_LOG_CAPACITY`, so slots that were never written are default.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuditLog {
+    pub bump: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub organization: Address,
+    /// Entries appended since the log was created, including overwritten ones.
+    pub entry_count: u64,
+    pub entries: [AuditEntry; AUDIT_LOG_CAPACITY],
+    pub created_at: i64,
+}
+
+impl AuditLog {
+    /// Records `entry`, overwriting the oldest one once the log is full.
+    pub fn append(&mut self, entry: AuditEntry) {
+        let slot = (self.entry_count % AUDIT_LOG_CAPACITY as u64) as usize;
+        self.entries[slot] = entry;
+        self.entry_count = self.entry_count.saturating_add(1);
+    }
+
+    /// The entries still held, oldest first.
+    pub fn recent_entries(&self) -> impl Iterator<Item = &AuditEntry> {
+        let held = self.entry_count.min(AUDIT_LOG_CAPACITY as u64) as usize;
+        let first =
+            (self.entry_count % AUDIT_LOG_CAPACITY as u64) as usize + AUDIT_LOG_CAPACITY - held;
+        (first..first + held).map(|index| &self.entries[index % AUDIT_LOG_CAPACITY])
+    }
+}
+
+impl AccountState for AuditLog {
+    const DISCRIMINATOR: u8 = AUDIT_LOG_DISCRIMINATOR;
+    const LEN: usize = 1 + 1 + 32 + 8 + AuditEntry::LEN * AUDIT_LOG_CAPACITY + 8;
+
+    fn read_body(reader: &mut Reader) -> Result<Self, GovernanceError> {
+        let bump = reader.read_u8()?;
+        let organization = Address::new_from_array(reader.read_array()?);
+        let entry_count = reader.read_u64()?;
+        let mut entries: [AuditEntry; AUDIT_LOG_CAPACITY] = Default::default();
+        for entry in &mut entries {
+            *entry = AuditEntry {
+                actor: Address::new_from_array(reader.read_array()?),
+                instruction: reader.read_u8()?,
+                target: Address::new_from_array(reader.read_array()?),
+                timestamp: reader.read_i64()?,
+            };
+        }
+        Ok(Self {
+            bump,
+            organization,
+            entry_count,
+            entries,
+            created_at: reader.read_i64()?,
+        })
+    }
+
+    fn write_body(&self, writer: &mut Writer) {
+        writer.write_u8(self.bump);
+        writer.write_bytes(self.organization.as_ref());
+        writer.write_u64(self.entry_count);
+        for entry in &self.entries {
+            writer.write_bytes(entry.actor.as_ref());
+            writer.write_u8(entry.instruction);
+            writer.write_bytes(entry.target.as_ref());
+            writer.write_i64(entry.timestamp);
+        }
+        writer.write_i64(self.created_at);
+    }
+}
+
+/// Marker that `close_organization` leaves at the organization address. It
+/// keeps the address taken, so the organization id cannot be created again
+/// and old proposal and season addresses cannot be reused under a new owner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClosedOrganization {
+    pub bump: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::hex_bytes"))]
+    pub organization_id: [u8; 16],
+    /// Authority that closed the organization.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_helpers::address"))]
+    pub closed_by: Address,
+    pub created_at: i64,
+    pub closed_at: i64,
+}
+
+impl ClosedOrganization {
+    pub fn new(org: &Organization, now: i64) -> Self {
+        Self {
+            bump: org.bump,
+            organization_id: org.organization_id,
+            closed_by: org.authority,
+            created_at: org.created_at,
+            closed_at: now,
+        }
+    }
+}
+
+impl AccountState for ClosedOrganization {
+    const DISCRIMINATOR: u8 = CLOSED_ORGANIZATION_DISCRIMINATOR;
+    const LEN: usize = 1 + 1 + 16 + 32 + 8 * 2;
+
+    fn read_body(reader: &mut Reader) -> Result<Self, GovernanceError> {
+        Ok(Self {
+            bump: reader.read_u8()?,
+            organization_id: reader.read_array()?,
+            closed_by: Address::new_from_array(reader.read_array()?),
+            created_at: reader.read_i64()?,
+            closed_at: reader.read_i64()?,
+        })
+    }
+
+    fn write_body(&self, writer: &mut Writer) {
+        writer.write_u8(self.bump);
+        writer.write_bytes(&self.organization_id);
+        writer.write_bytes(self.closed_by.as_ref());
+        writer.write_i64(self.created_at);
+        writer.write_i64(self.closed_at);
+    }
+}
@@ -0,0 +1,124 @@
+//! Minimal System program CPI helpers.
+
+use core::slice;
+
+use pinocchio::{
+    account::AccountView,
+    cpi::{invoke_signed, Signer},
+    instruction::{InstructionAccount, InstructionView},
+    sysvars::{rent::Rent, Sysvar},
+    Address, ProgramResult,
+};
+
+use crate::error::GovernanceError;
+
+pub const SYSTEM_PROGRAM_ID: Address = Address::new_from_array([0; 32]);
+
+const CREATE_ACCOUNT_DISCRIMINATOR: u32 = 0;
+const ASSIGN_DISCRIMINATOR: u32 = 1;
+const TRANSFER_DISCRIMINATOR: u32 = 2;
+const ALLOCATE_DISCRIMINATOR: u32 = 8;
+
+/// Creates a rent-exempt, program-owned account at a PDA, signing with `signer`.
+///
+/// Anyone can send lamports to a PDA before it is created, which would make
+/// `CreateAccount` fail. A funded but otherwise empty system account is
+/// topped up to rent exemption, then allocated and assigned instead.
+pub fn create_pda_account(
+    payer: &AccountView,
+    account: &AccountView,
+    space: usize,
+    owner: &Address,
+    signer: Signer,
+) -> ProgramResult {
+    if !account.is_data_empty() {
+        return Err(GovernanceError::AccountAlreadyInitialized.into());
+    }
+
+    let lamports = Rent::get()?.minimum_balance(space);
+    let funded = account.lamports();
+    if funded == 0 {
+        return create_account(payer, account, lamports, space, owner, signer);
+    }
+    if !account.owned_by(&SYSTEM_PROGRAM_ID) {
+        return Err(GovernanceError::AccountAlreadyInitialized.into());
+    }
+
+    if funded < lamports {
+        transfer(payer, account, lamports - funded)?;
+    }
+    allocate(account, space, &signer)?;
+    assign(account, owner, &signer)
+}
+
+fn create_account(
+    payer: &AccountView,
+    account: &AccountView,
+    lamports: u64,
+    space: usize,
+    owner: &Address,
+    signer: Signer,
+) -> ProgramResult {
+    let mut data = [0u8; 52];
+    data[0..4].copy_from_slice(&CREATE_ACCOUNT_DISCRIMINATOR.to_le_bytes());
+    data[4..12].copy_from_slice(&lamports.to_le_bytes());
+    data[12..20].copy_from_slice(&(space as u64).to_le_bytes());
+    data[20..52].copy_from_slice(owner.as_ref());
+
+    let instruction = InstructionView {
+        program_id: &SYSTEM_PROGRAM_ID,
+        data: &data,
+        accounts: &[
+            InstructionAccount::writable_signer(payer.address()),
+            InstructionAccount::writable_signer(account.address()),
+        ],
+    };
+
+    invoke_signed(&instruction, &[payer, account], &[signer])
+}
+
+/// Moves `lamports` from the signing `payer` to `account`.
+pub fn transfer(payer: &AccountView, account: &AccountView, lamports: u64) -> ProgramResult {
+    let mut data = [0u8; 12];
+    data[0..4].copy_from_slice(&TRANSFER_DISCRIMINATOR.to_le_bytes());
+    data[4..12].copy_from_slice(&lamports.to_le_bytes());
+
+    let instruction = InstructionView {
+        program_id: &SYSTEM_PROGRAM_ID,
+        data: &data,
+        accounts: &[
+            InstructionAccount::writable_signer(payer.address()),
+            InstructionAccount::writable(account.address()),
+        ],
+    };
+
+    invoke_signed(&instruction, &[payer, account], &[])
+}
+
+fn allocate(account: &AccountView, space: usize, signer: &Signer) -> ProgramResult {
+    let mut data = [0u8; 12];
+    data[0..4].copy_from_slice(&ALLOCATE_DISCRIMINATOR.to_le_bytes());
+    data[4..12].copy_from_slice(&(space as u64).to_le_bytes());
+
+    let instruction = InstructionView {
+        program_id: &SYSTEM_PROGRAM_ID,
+        data: &data,
+        accounts: &[InstructionAccount::writable_signer(account.address())],
+    };
+
+    invoke_signed(&instruction, &[account], slice::from_ref(signer))
+}
+
+fn assign(account: &AccountView, owner: &Address, signer: &Signer) -> ProgramResult {
+    let mut data = [0u8; 36];
+    data[0..4].copy_from_slice(&ASSIGN_DISCRIMINATOR.to_le_bytes());
+    data[4..36].copy_from_slice(owner.as_ref());
+
+    let instruction = InstructionView {
+        program_id: &SYSTEM_PROGRAM_ID,
+        data: &data,
+        accounts: &[InstructionAccount::writable_signer(account.address())],
+    };
+
+    invoke_signed(&instruction, &[account], slice::from_ref(signer))
+}
@@ -0,0 +1,125 @@
+//! Guards the declared account sizes. Each `LEN` must match what `write_body`
+//! actually writes, with text fields full, so a field added without updating
+//! `LEN` (or the other way round) fails here rather than on-chain.
+
+mod common;
+
+use common::fixtures::{
+    audit_log, closed_organization, org_config, org_stats, organization, proposal, results, season,
+    summary,
+};
+use fan_engagement_log::{
+    codec::Writer,
+    state::{
+        AccountState, AuditLog, ClosedOrganization, OrgConfig, OrgStats, Organization, Proposal,
+        ProposalResults, ProposalSummary, Season, MAX_NAME_LEN, MAX_TITLE_LEN,
+    },
+};
+use solana_rent::Rent;
+
+/// Rent-exempt deposit ceiling per account, as a budget for layout changes.
+const MAX_RENT_LAMPORTS: u64 = 5_000_000;
+/// The audit log is opt-in and holds `AUDIT_LOG_CAPACITY` entries, so it has
+/// its own ceiling.
+const MAX_AUDIT_LOG_RENT_LAMPORTS: u64 = 20_000_000;
+
+/// The shared organization with the longest name the layout holds.
+fn full_organization() -> Organization {
+    Organization {
+        name_len: MAX_NAME_LEN as u8,
+        name: [b'z'; MAX_NAME_LEN],
+        ..organization()
+    }
+}
+
+/// The shared proposal with the longest title the layout holds.
+fn full_proposal() -> Proposal {
+    Proposal {
+        title_len: MAX_TITLE_LEN as u8,
+        title: [b'z'; MAX_TITLE_LEN],
+        ..proposal()
+    }
+}
+
+/// Bytes `state` serializes to, discriminator included, measured against an
+/// oversized buffer so a short `LEN` cannot truncate the count.
+fn written_len<T: AccountState>(state: &T) -> usize {
+    let mut data = vec![0u8; T::LEN * 2];
+    let mut writer = Writer::new(&mut data);
+    writer.write_u8(T::DISCRIMINATOR);
+    state.write_body(&mut writer);
+    writer.position()
+}
+
+fn assert_fits<T: AccountState + PartialEq + core::fmt::Debug>(state: T) {
+    assert_eq!(written_len(&state), T::LEN);
+
+    let mut data = vec![0u8; T::LEN];
+    state.pack(&mut data).unwrap();
+    assert_eq!(T::unpack(&data).unwrap(), state);
+}
+
+#[test]
+fn accounts_fill_declared_len_exactly() {
+    assert_fits(full_organization());
+    assert_fits(full_proposal());
+    assert_fits(results());
+    assert_fits(season());
+    assert_fits(summary());
+    assert_fits(org_stats());
+    assert_fits(org_config());
+    assert_fits(audit_log());
+    assert_fits(closed_organization());
+}
+
+#[test]
+fn account_sizes_are_pinned() {
+    // Changing any of these changes rent for every new account and breaks
+    // clients that size or filter accounts by length.
+    assert_eq!(Organization::LEN, 391);
+    assert_eq!(Proposal::LEN, 461);
+    assert_eq!(ProposalResults::LEN, 165);
+    assert_eq!(Season::LEN, 106);
+    assert_eq!(ProposalSummary::LEN, 148);
+    assert_eq!(OrgStats::LEN, 82);
+    assert_eq!(OrgConfig::LEN, 82);
+    assert_eq!(AuditLog::LEN, 2_386);
+    assert_eq!(ClosedOrganization::LEN, 66);
+}
+
+/// Prints the rent-exempt deposit per account type; run with
+/// `cargo test --test account_size -- --nocapture` to see the report.
+#[test]
+fn rent_exempt_deposits_stay_within_budget() {
+    let rent = Rent::default();
+    for (name, len, budget) in [
+        ("Organization", Organization::LEN, MAX_RENT_LAMPORTS),
+        ("Proposal", Proposal::LEN, MAX_RENT_LAMPORTS),
+        ("ProposalResults", ProposalResults::LEN, MAX_RENT_LAMPORTS),
+        ("Season", Season::LEN, MAX_RENT_LAMPORTS),
+        ("ProposalSummary", ProposalSummary::LEN, MAX_RENT_LAMPORTS),
+        ("OrgStats", OrgStats::LEN, MAX_RENT_LAMPORTS),
+        ("OrgConfig", OrgConfig::LEN, MAX_RENT_LAMPORTS),
+        ("AuditLog", AuditLog::LEN, MAX_AUDIT_LOG_RENT_LAMPORTS),
+        (
+            "ClosedOrganization",
+            ClosedOrganization::LEN,
+            MAX_RENT_LAMPORTS,
+        ),
+    ] {
+        let lamports = rent.minimum_balance(len);
+        println!("{name:<16} {len:>4} bytes {lamports:>10} lamports");
+        assert!(lamports <= budget, "{name} needs {lamports} lamports");
+    }
+}
+
+#[test]
+fn archiving_cuts_proposal_rent_by_two_thirds() {
+    let rent = Rent::default();
+    let full = rent.minimum_balance(Proposal::LEN) + rent.minimum_balance(ProposalResults::LEN);
+    let summary = rent.minimum_balance(ProposalSummary::LEN);
+    assert!(
+        summary * 3 < full,
+        "summary keeps {summary} of {full} lamports"
+    );
+}
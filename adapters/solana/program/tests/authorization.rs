@@ -0,0 +1,1180 @@
+//! Adversarial tests: every instruction with the wrong signer, a missing
+//! signature, a foreign organization's accounts, or a mismatched PDA/bump must
+//! fail with a specific error and leave state untouched.
+
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::{address, ix, pubkey, Harness, Org, NOW, PROGRAM_ID, SLOT};
+use fan_engagement_log::{
+    error::GovernanceError,
+    pda::{
+        AUDIT_LOG_SEED, ORGANIZATION_SEED, ORG_CONFIG_SEED, ORG_STATS_SEED, PROPOSAL_SEED,
+        RESULTS_SEED, SEASON_SEED,
+    },
+    state::{
+        Organization, Proposal, ProposalResults, ProposalStatus, ORGANIZATION_CLOSE_DELAY_SECONDS,
+    },
+};
+use pinocchio::Address;
+use solana_instruction::{error::InstructionError, Instruction};
+use solana_pubkey::Pubkey;
+
+/// Replaces the signer at index 0 with `key`.
+fn signed_by(mut instruction: Instruction, key: &Pubkey) -> Instruction {
+    instruction.accounts[0].pubkey = *key;
+    instruction.accounts[0].is_signer = true;
+    instruction
+}
+
+fn unsigned(mut instruction: Instruction) -> Instruction {
+    instruction.accounts[0].is_signer = false;
+    instruction
+}
+
+/// Returns a bump that does not derive the canonical PDA.
+fn wrong_bump(bump: u8) -> u8 {
+    bump.wrapping_sub(1)
+}
+
+/// Returns a valid program address for `seeds` at an off-curve bump below
+/// the canonical one, with that bump. Only the canonical bump may be used.
+fn non_canonical_pda(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    let program_id = address(&PROGRAM_ID);
+    let (_, canonical) = Address::find_program_address(seeds, &program_id);
+    (0..canonical)
+        .rev()
+        .find_map(|bump| {
+            let bump_seed = [bump];
+            let mut seeds = seeds.to_vec();
+            seeds.push(&bump_seed);
+            Address::create_program_address(&seeds, &program_id)
+                .ok()
+                .map(|pda| (pubkey(&pda), bump))
+        })
+        .expect("no off-curve bump below the canonical one")
+}
+
+/// Two organizations and a stranger with no role in either.
+fn setup() -> (Harness, Org, Org, Pubkey) {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let foreign = harness.create_org(2);
+    let stranger = harness.funded_key();
+    (harness, org, foreign, stranger)
+}
+
+#[test]
+fn create_organization_rejects_missing_signature_and_wrong_pda() {
+    let (mut harness, _, foreign, stranger) = setup();
+
+    harness.expect_instruction_error(
+        &unsigned(ix::create_organization(
+            &stranger, [3u8; 16], &stranger, "Club",
+        )),
+        InstructionError::MissingRequiredSignature,
+    );
+
+    let mut instruction = ix::create_organization(&stranger, [3u8; 16], &stranger, "Club");
+    instruction.data[18] = wrong_bump(instruction.data[18]);
+    harness.expect_error(&instruction, GovernanceError::InvalidPda);
+
+    let (pda, bump) = non_canonical_pda(&[ORGANIZATION_SEED, &[3u8; 16]]);
+    let mut instruction = ix::create_organization(&stranger, [3u8; 16], &stranger, "Club");
+    instruction.accounts[1].pubkey = pda;
+    instruction.data[18] = bump;
+    harness.expect_error(&instruction, GovernanceError::InvalidPda);
+
+    // An existing organization's address cannot stand in for a new id.
+    let mut instruction = ix::create_organization(&stranger, [3u8; 16], &stranger, "Club");
+    instruction.accounts[1].pubkey = foreign.address;
+    harness.expect_error(&instruction, GovernanceError::InvalidPda);
+}
+
+#[test]
+fn create_proposal_requires_the_organization_authority() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let params = ix::ProposalParams::new(7, NOW);
+    let instruction = ix::create_proposal(&org.authority, &org.address, &params, None);
+
+    harness.expect_error(
+        &signed_by(instruction.clone(), &stranger),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_error(
+        &signed_by(instruction.clone(), &foreign.authority),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_error(
+        &signed_by(instruction.clone(), &org.recovery_authority),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_instruction_error(
+        &unsigned(instruction),
+        InstructionError::MissingRequiredSignature,
+    );
+
+    let adapter = harness.funded_key();
+    harness.run(&ix::add_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+    harness.expect_error(
+        &ix::create_proposal(&adapter, &org.address, &params, None),
+        GovernanceError::Unauthorized,
+    );
+    assert_eq!(
+        harness.state::<Organization>(&org.address).proposal_count,
+        0
+    );
+}
+
+#[test]
+fn create_proposal_rejects_wrong_pdas_and_foreign_accounts() {
+    let (mut harness, org, foreign, _) = setup();
+    let mut params = ix::ProposalParams::new(7, NOW);
+
+    let canonical = ix::proposal_pda(&org.address, params.proposal_id).1;
+    params.bump = Some(wrong_bump(canonical));
+    harness.expect_error(
+        &ix::create_proposal(&org.authority, &org.address, &params, None),
+        GovernanceError::InvalidPda,
+    );
+
+    let (pda, bump) =
+        non_canonical_pda(&[PROPOSAL_SEED, org.address.as_ref(), &params.proposal_id]);
+    params.bump = Some(bump);
+    let mut instruction = ix::create_proposal(&org.authority, &org.address, &params, None);
+    instruction.accounts[2].pubkey = pda;
+    harness.expect_error(&instruction, GovernanceError::InvalidPda);
+    params.bump = None;
+
+    // The proposal PDA of the same id under another organization.
+    let mut instruction = ix::create_proposal(&org.authority, &org.address, &params, None);
+    instruction.accounts[2].pubkey = ix::proposal_pda(&foreign.address, params.proposal_id).0;
+    harness.expect_error(&instruction, GovernanceError::InvalidPda);
+
+    // A system account posing as the organization.
+    let fake = harness.funded_key();
+    harness.expect_error(
+        &ix::create_proposal(&org.authority, &fake, &params, None),
+        GovernanceError::InvalidAccountOwner,
+    );
+
+    // A program account of the wrong type posing as the organization.
+    let proposal = harness.create_proposal(&org, 8);
+    harness.expect_error(
+        &ix::create_proposal(&org.authority, &proposal, &params, None),
+        GovernanceError::InvalidAccountData,
+    );
+
+    // Another organization's season.
+    harness.run(&ix::create_season(
+        &foreign.authority,
+        &foreign.address,
+        [5u8; 16],
+        NOW,
+        NOW + 30 * 86_400,
+    ));
+    let (season, _) = ix::season_pda(&foreign.address, [5u8; 16]);
+    harness.expect_error(
+        &ix::create_proposal(&org.authority, &org.address, &params, Some(&season)),
+        GovernanceError::SeasonMismatch,
+    );
+}
+
+#[test]
+fn create_proposals_batch_is_all_or_nothing() {
+    let (mut harness, org, _, stranger) = setup();
+    let mut params: Vec<_> = (10..13)
+        .map(|id| ix::ProposalParams::new(id, NOW))
+        .collect();
+
+    harness.expect_error(
+        &signed_by(
+            ix::create_proposals_batch(&org.authority, &org.address, &params, None),
+            &stranger,
+        ),
+        GovernanceError::Unauthorized,
+    );
+
+    let canonical = ix::proposal_pda(&org.address, params[2].proposal_id).1;
+    params[2].bump = Some(wrong_bump(canonical));
+    harness.expect_error(
+        &ix::create_proposals_batch(&org.authority, &org.address, &params, None),
+        GovernanceError::InvalidPda,
+    );
+    assert_eq!(
+        harness.state::<Organization>(&org.address).proposal_count,
+        0
+    );
+    assert!(harness
+        .account(&ix::proposal_pda(&org.address, params[0].proposal_id).0)
+        .is_none());
+
+    // Too few proposal accounts for the payload.
+    params[2].bump = None;
+    let mut instruction = ix::create_proposals_batch(&org.authority, &org.address, &params, None);
+    instruction.accounts.truncate(4);
+    // The runtime still maps `ProgramError::NotEnoughAccountKeys` to this variant.
+    #[allow(deprecated)]
+    let error = InstructionError::NotEnoughAccountKeys;
+    harness.expect_instruction_error(&instruction, error);
+}
+
+#[test]
+fn update_proposal_status_rejects_strangers_and_foreign_proposals() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let proposal = harness.create_proposal(&org, 7);
+    let foreign_proposal = harness.create_proposal(&foreign, 7);
+
+    let instruction = ix::update_proposal_status(
+        &org.authority,
+        &org.address,
+        &proposal,
+        0,
+        ProposalStatus::Open,
+        &[],
+    );
+    harness.expect_error(
+        &signed_by(instruction.clone(), &stranger),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_error(
+        &signed_by(instruction.clone(), &foreign.authority),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_error(
+        &signed_by(instruction.clone(), &org.recovery_authority),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_instruction_error(
+        &unsigned(instruction),
+        InstructionError::MissingRequiredSignature,
+    );
+
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &foreign_proposal,
+            0,
+            ProposalStatus::Open,
+            &[],
+        ),
+        GovernanceError::OrganizationMismatch,
+    );
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &proposal,
+            1,
+            ProposalStatus::Open,
+            &[],
+        ),
+        GovernanceError::StaleOperationNonce,
+    );
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &proposal,
+            0,
+            ProposalStatus::Closed,
+            &[],
+        ),
+        GovernanceError::InvalidStatusTransition,
+    );
+    assert_eq!(harness.nonce(&org), 0);
+}
+
+#[test]
+fn removed_adapter_signer_loses_access() {
+    let (mut harness, org, foreign, _) = setup();
+    let proposal = harness.create_proposal(&org, 7);
+    let adapter = harness.funded_key();
+    harness.run(&ix::add_adapter_signer(
+        &foreign.authority,
+        &foreign.address,
+        &adapter,
+    ));
+
+    // Registered on another organization only.
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &adapter,
+            &org.address,
+            &proposal,
+            0,
+            ProposalStatus::Open,
+            &[],
+        ),
+        GovernanceError::Unauthorized,
+    );
+
+    harness.run(&ix::add_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+    harness.run(&ix::remove_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &adapter,
+            &org.address,
+            &proposal,
+            0,
+            ProposalStatus::Open,
+            &[],
+        ),
+        GovernanceError::Unauthorized,
+    );
+}
+
+#[test]
+fn finalize_requires_this_proposals_results() {
+    let (mut harness, org, _, _) = setup();
+    let proposal = harness.create_proposal(&org, 7);
+    let other = harness.create_proposal(&org, 8);
+    for key in [&proposal, &other] {
+        harness.open_proposal(&org, key);
+    }
+    for key in [&proposal, &other] {
+        harness.close_proposal(&org, key);
+    }
+    let nonce = harness.nonce(&org);
+    harness.run(&ix::commit_vote_results(
+        &org.authority,
+        &org.address,
+        &other,
+        nonce,
+        10,
+    ));
+    let (other_results, _) = ix::results_pda(&other);
+
+    let nonce = harness.nonce(&org);
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &proposal,
+            nonce,
+            ProposalStatus::Finalized,
+            &[],
+        ),
+        GovernanceError::ResultsNotCommitted,
+    );
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &proposal,
+            nonce,
+            ProposalStatus::Finalized,
+            &[other_results],
+        ),
+        GovernanceError::ResultsNotCommitted,
+    );
+
+    harness.run(&ix::commit_vote_results(
+        &org.authority,
+        &org.address,
+        &proposal,
+        nonce,
+        10,
+    ));
+    let (results, _) = ix::results_pda(&proposal);
+    let nonce = harness.nonce(&org);
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &proposal,
+            nonce,
+            ProposalStatus::Finalized,
+            &[other_results],
+        ),
+        GovernanceError::ProposalMismatch,
+    );
+
+    // A program-owned account that claims to hold this proposal's results
+    // but is not the PDA recorded at commit time.
+    let mut forged: ProposalResults = harness.state(&results);
+    forged.total_votes_cast = 0;
+    let forged_key = Pubkey::new_unique();
+    harness.set_state(forged_key, &forged);
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &proposal,
+            nonce,
+            ProposalStatus::Finalized,
+            &[forged_key],
+        ),
+        GovernanceError::ProposalMismatch,
+    );
+}
+
+#[test]
+fn commit_vote_results_rejects_wrong_pdas_and_foreign_proposals() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let proposal = harness.create_proposal(&org, 7);
+    let foreign_proposal = harness.create_proposal(&foreign, 7);
+
+    harness.expect_error(
+        &ix::commit_vote_results(&org.authority, &org.address, &proposal, 0, 10),
+        GovernanceError::ProposalNotClosed,
+    );
+
+    harness.open_proposal(&org, &proposal);
+    harness.close_proposal(&org, &proposal);
+    let nonce = harness.nonce(&org);
+
+    let instruction = ix::commit_vote_results(&org.authority, &org.address, &proposal, nonce, 10);
+    harness.expect_error(
+        &signed_by(instruction.clone(), &stranger),
+        GovernanceError::Unauthorized,
+    );
+
+    let mut wrong_bump_instruction = instruction.clone();
+    wrong_bump_instruction.data[10] = wrong_bump(wrong_bump_instruction.data[10]);
+    harness.expect_error(&wrong_bump_instruction, GovernanceError::InvalidPda);
+
+    let (pda, bump) = non_canonical_pda(&[RESULTS_SEED, proposal.as_ref()]);
+    let mut non_canonical = instruction.clone();
+    non_canonical.accounts[3].pubkey = pda;
+    non_canonical.data[10] = bump;
+    harness.expect_error(&non_canonical, GovernanceError::InvalidPda);
+
+    // Results PDA of another proposal.
+    let mut wrong_results = instruction.clone();
+    wrong_results.accounts[3].pubkey = ix::results_pda(&foreign_proposal).0;
+    harness.expect_error(&wrong_results, GovernanceError::InvalidPda);
+
+    harness.expect_error(
+        &ix::commit_vote_results(&org.authority, &org.address, &foreign_proposal, nonce, 10),
+        GovernanceError::OrganizationMismatch,
+    );
+
+    harness.run(&instruction);
+    let nonce = harness.nonce(&org);
+    harness.expect_error(
+        &ix::commit_vote_results(&org.authority, &org.address, &proposal, nonce, 10),
+        GovernanceError::AccountAlreadyInitialized,
+    );
+}
+
+#[test]
+fn close_and_commit_results_checks_like_the_separate_paths() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let proposal = harness.create_proposal(&org, 7);
+    let foreign_proposal = harness.create_proposal(&foreign, 7);
+
+    harness.expect_error(
+        &ix::close_and_commit_results(&org.authority, &org.address, &proposal, 0, 10),
+        GovernanceError::InvalidStatusTransition,
+    );
+    harness.open_proposal(&org, &proposal);
+    let nonce = harness.nonce(&org);
+
+    harness.expect_error(
+        &ix::close_and_commit_results(&stranger, &org.address, &proposal, nonce, 10),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_error(
+        &ix::close_and_commit_results(&org.authority, &org.address, &foreign_proposal, nonce, 10),
+        GovernanceError::OrganizationMismatch,
+    );
+    harness.expect_error(
+        &ix::close_and_commit_results(&org.authority, &org.address, &proposal, nonce, 10),
+        GovernanceError::VotingNotEnded,
+    );
+
+    harness.warp_to_voting_end(&proposal);
+    harness.expect_error(
+        &ix::close_and_commit_results(&org.authority, &org.address, &proposal, nonce, 1_001),
+        GovernanceError::VotesExceedEligiblePower,
+    );
+}
+
+#[test]
+fn create_season_requires_authority_and_canonical_pda() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let instruction = ix::create_season(&org.authority, &org.address, [5u8; 16], NOW, NOW + 86_400);
+
+    harness.expect_error(
+        &signed_by(instruction.clone(), &stranger),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_error(
+        &signed_by(instruction.clone(), &foreign.authority),
+        GovernanceError::Unauthorized,
+    );
+
+    let mut wrong_bump_instruction = instruction.clone();
+    wrong_bump_instruction.data[18] = wrong_bump(wrong_bump_instruction.data[18]);
+    harness.expect_error(&wrong_bump_instruction, GovernanceError::InvalidPda);
+
+    let (pda, bump) = non_canonical_pda(&[SEASON_SEED, org.address.as_ref(), &[5u8; 16]]);
+    let mut non_canonical = instruction.clone();
+    non_canonical.accounts[2].pubkey = pda;
+    non_canonical.data[18] = bump;
+    harness.expect_error(&non_canonical, GovernanceError::InvalidPda);
+
+    let mut foreign_pda = instruction;
+    foreign_pda.accounts[2].pubkey = ix::season_pda(&foreign.address, [5u8; 16]).0;
+    harness.expect_error(&foreign_pda, GovernanceError::InvalidPda);
+}
+
+#[test]
+fn adapter_signer_management_requires_authority() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let adapter = harness.funded_key();
+    harness.run(&ix::add_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+
+    for signer in [
+        &stranger,
+        &adapter,
+        &org.recovery_authority,
+        &foreign.authority,
+    ] {
+        harness.expect_error(
+            &ix::add_adapter_signer(signer, &org.address, signer),
+            GovernanceError::Unauthorized,
+        );
+        harness.expect_error(
+            &ix::remove_adapter_signer(signer, &org.address, &adapter),
+            GovernanceError::Unauthorized,
+        );
+    }
+    harness.expect_instruction_error(
+        &unsigned(ix::add_adapter_signer(
+            &org.authority,
+            &org.address,
+            &stranger,
+        )),
+        InstructionError::MissingRequiredSignature,
+    );
+    assert_eq!(
+        harness
+            .state::<Organization>(&org.address)
+            .adapter_signers()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn recovery_roles_cannot_be_swapped() {
+    let (mut harness, org, foreign, stranger) = setup();
+
+    for signer in [&org.authority, &stranger, &foreign.recovery_authority] {
+        harness.expect_error(
+            &ix::initiate_recovery(signer, &org.address, &stranger),
+            GovernanceError::Unauthorized,
+        );
+    }
+
+    harness.run(&ix::initiate_recovery(
+        &org.recovery_authority,
+        &org.address,
+        &stranger,
+    ));
+    harness.expect_error(
+        &ix::cancel_recovery(&org.recovery_authority, &org.address),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_error(
+        &ix::complete_recovery(&org.authority, &org.address),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_error(
+        &ix::complete_recovery(&org.recovery_authority, &org.address),
+        GovernanceError::RecoveryTimelockActive,
+    );
+}
+
+#[test]
+fn snapshot_and_voting_power_updates_are_guarded() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let proposal = harness.create_proposal(&org, 7);
+    let foreign_proposal = harness.create_proposal(&foreign, 7);
+
+    harness.expect_error(
+        &ix::open_proposal_with_snapshot(&stranger, &org.address, &proposal, 0, SLOT, 5_000),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_error(
+        &ix::open_proposal_with_snapshot(
+            &org.authority,
+            &org.address,
+            &proposal,
+            0,
+            SLOT + 1,
+            5_000,
+        ),
+        GovernanceError::InvalidSnapshotSlot,
+    );
+    harness.expect_error(
+        &ix::set_eligible_voting_power(&stranger, &org.address, &proposal, 0, 5_000),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_error(
+        &ix::set_eligible_voting_power(&org.authority, &org.address, &foreign_proposal, 0, 5_000),
+        GovernanceError::OrganizationMismatch,
+    );
+
+    harness.open_proposal(&org, &proposal);
+    let nonce = harness.nonce(&org);
+    harness.expect_error(
+        &ix::set_eligible_voting_power(&org.authority, &org.address, &proposal, nonce, 5_000),
+        GovernanceError::VotingPowerLocked,
+    );
+}
+
+#[test]
+fn update_proposal_draft_is_limited_to_the_authority_and_author() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let proposal = harness.create_proposal(&org, 7);
+    let foreign_proposal = harness.create_proposal(&foreign, 7);
+    let adapter = harness.funded_key();
+    harness.run(&ix::add_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+    let params = ix::ProposalParams::new(7, NOW);
+    let amend = |signer: &Pubkey, proposal: &Pubkey| {
+        ix::update_proposal_draft(signer, &org.address, proposal, &params, None)
+    };
+
+    for signer in [
+        &stranger,
+        &adapter,
+        &org.recovery_authority,
+        &foreign.authority,
+    ] {
+        harness.expect_error(&amend(signer, &proposal), GovernanceError::Unauthorized);
+    }
+    harness.expect_instruction_error(
+        &unsigned(amend(&org.authority, &proposal)),
+        InstructionError::MissingRequiredSignature,
+    );
+    harness.expect_error(
+        &amend(&org.authority, &foreign_proposal),
+        GovernanceError::OrganizationMismatch,
+    );
+
+    // The author keeps the right to amend after the authority changes hands.
+    let author = harness.funded_key();
+    let mut state: Proposal = harness.state(&proposal);
+    state.created_by = address(&author);
+    harness.set_state(proposal, &state);
+    harness.run(&amend(&author, &proposal));
+    harness.run(&amend(&org.authority, &proposal));
+    assert_eq!(harness.state::<Proposal>(&proposal).amendment_count, 2);
+
+    harness.run(&ix::freeze_organization(&org.authority, &org.address));
+    harness.expect_error(
+        &amend(&org.authority, &proposal),
+        GovernanceError::OrganizationFrozen,
+    );
+}
+
+#[test]
+fn only_the_authority_can_extend_voting() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let proposal = harness.create_proposal(&org, 7);
+    let foreign_proposal = harness.create_proposal(&foreign, 7);
+    harness.open_proposal(&org, &proposal);
+    let adapter = harness.funded_key();
+    harness.run(&ix::add_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+    let end_at = harness.state::<Proposal>(&proposal).end_at + 3_600;
+    let extend = |signer: &Pubkey, proposal: &Pubkey| {
+        ix::extend_voting_period(signer, &org.address, proposal, end_at, None)
+    };
+
+    // Adapter signers and the author cannot extend a vote; only the authority.
+    for signer in [
+        &stranger,
+        &adapter,
+        &org.recovery_authority,
+        &foreign.authority,
+    ] {
+        harness.expect_error(&extend(signer, &proposal), GovernanceError::Unauthorized);
+    }
+    harness.expect_instruction_error(
+        &unsigned(extend(&org.authority, &proposal)),
+        InstructionError::MissingRequiredSignature,
+    );
+    harness.expect_error(
+        &extend(&org.authority, &foreign_proposal),
+        GovernanceError::OrganizationMismatch,
+    );
+    assert_eq!(harness.state::<Proposal>(&proposal).original_end_at, 0);
+
+    harness.run(&ix::freeze_organization(&org.authority, &org.address));
+    harness.expect_error(
+        &extend(&org.authority, &proposal),
+        GovernanceError::OrganizationFrozen,
+    );
+}
+
+#[test]
+fn only_the_authority_sets_the_dispute_policy() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let adapter = harness.funded_key();
+    harness.run(&ix::add_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+    let set = |signer: &Pubkey| ix::set_dispute_policy(signer, &org.address, 86_400, 5_000);
+
+    for signer in [
+        &stranger,
+        &adapter,
+        &org.recovery_authority,
+        &foreign.authority,
+    ] {
+        harness.expect_error(&set(signer), GovernanceError::Unauthorized);
+    }
+    harness.expect_instruction_error(
+        &unsigned(set(&org.authority)),
+        InstructionError::MissingRequiredSignature,
+    );
+    harness.expect_error(
+        &ix::set_dispute_policy(&org.authority, &org.address, -1, 0),
+        GovernanceError::InvalidDisputeWindow,
+    );
+    harness.run(&set(&org.authority));
+    let state: Organization = harness.state(&org.address);
+    assert_eq!(state.dispute_window_seconds, 86_400);
+    assert_eq!(state.dispute_bond_lamports, 5_000);
+}
+
+#[test]
+fn challengers_need_a_bond_and_finalizing_needs_an_open_organization() {
+    let (mut harness, org, foreign, stranger) = setup();
+    harness.run(&ix::set_dispute_policy(
+        &org.authority,
+        &org.address,
+        86_400,
+        0,
+    ));
+    let proposal = harness.create_proposal(&org, 7);
+    harness.open_proposal(&org, &proposal);
+    harness.close_proposal(&org, &proposal);
+    let nonce = harness.nonce(&org);
+    harness.run(&ix::commit_vote_results(
+        &org.authority,
+        &org.address,
+        &proposal,
+        nonce,
+        420,
+    ));
+    let foreign_proposal = harness.create_proposal(&foreign, 7);
+
+    // With no bond set, only the authority may dispute.
+    harness.expect_error(
+        &ix::dispute_results(&stranger, &org.address, &proposal),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_instruction_error(
+        &unsigned(ix::dispute_results(&org.authority, &org.address, &proposal)),
+        InstructionError::MissingRequiredSignature,
+    );
+    harness.expect_error(
+        &ix::dispute_results(&org.authority, &org.address, &foreign_proposal),
+        GovernanceError::OrganizationMismatch,
+    );
+    harness.expect_error(
+        &ix::finalize_proposal(&foreign.address, &proposal, &[]),
+        GovernanceError::OrganizationMismatch,
+    );
+
+    harness.warp_to(harness.now() + 86_400);
+    harness.run(&ix::freeze_organization(&org.authority, &org.address));
+    harness.expect_error(
+        &ix::finalize_proposal(&org.address, &proposal, &[]),
+        GovernanceError::OrganizationFrozen,
+    );
+}
+
+#[test]
+fn only_the_authority_can_freeze() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let adapter = harness.funded_key();
+    harness.run(&ix::add_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+
+    for signer in [
+        &stranger,
+        &adapter,
+        &org.recovery_authority,
+        &foreign.authority,
+    ] {
+        harness.expect_error(
+            &ix::freeze_organization(signer, &org.address),
+            GovernanceError::Unauthorized,
+        );
+    }
+    harness.expect_instruction_error(
+        &unsigned(ix::freeze_organization(&org.authority, &org.address)),
+        InstructionError::MissingRequiredSignature,
+    );
+
+    // A pending recovery cannot be blocked by freezing.
+    harness.run(&ix::initiate_recovery(
+        &org.recovery_authority,
+        &org.address,
+        &stranger,
+    ));
+    harness.expect_error(
+        &ix::freeze_organization(&org.authority, &org.address),
+        GovernanceError::RecoveryAlreadyInitiated,
+    );
+    assert!(!harness.state::<Organization>(&org.address).is_frozen());
+}
+
+#[test]
+fn only_the_authority_can_close() {
+    let (mut harness, org, foreign, stranger) = setup();
+    harness.run(&ix::freeze_organization(&org.authority, &org.address));
+    harness.warp_to(NOW + ORGANIZATION_CLOSE_DELAY_SECONDS);
+
+    for signer in [&stranger, &org.recovery_authority, &foreign.authority] {
+        harness.expect_error(
+            &ix::close_organization(signer, &org.address),
+            GovernanceError::Unauthorized,
+        );
+    }
+    harness.expect_instruction_error(
+        &unsigned(ix::close_organization(&org.authority, &org.address)),
+        InstructionError::MissingRequiredSignature,
+    );
+    let fake = harness.funded_key();
+    harness.expect_error(
+        &ix::close_organization(&org.authority, &fake),
+        GovernanceError::InvalidAccountOwner,
+    );
+    assert!(harness.state::<Organization>(&org.address).is_frozen());
+}
+
+#[test]
+fn archive_proposal_requires_the_authority_and_matching_accounts() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let proposal = harness.create_proposal(&org, 3);
+    harness.finalize_proposal(&org, &proposal, &[]);
+    let other = harness.create_proposal(&org, 4);
+    let other_results = harness.finalize_proposal(&org, &other, &[]);
+    let foreign_proposal = harness.create_proposal(&foreign, 5);
+    harness.finalize_proposal(&foreign, &foreign_proposal, &[]);
+    let adapter = harness.funded_key();
+    harness.run(&ix::add_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+
+    let instruction = ix::archive_proposal(&org.authority, &org.address, &proposal);
+    for signer in [
+        &stranger,
+        &adapter,
+        &org.recovery_authority,
+        &foreign.authority,
+    ] {
+        harness.expect_error(
+            &signed_by(instruction.clone(), signer),
+            GovernanceError::Unauthorized,
+        );
+    }
+    harness.expect_instruction_error(
+        &unsigned(instruction.clone()),
+        InstructionError::MissingRequiredSignature,
+    );
+    harness.expect_error(
+        &ix::archive_proposal(&org.authority, &org.address, &foreign_proposal),
+        GovernanceError::OrganizationMismatch,
+    );
+
+    let mut wrong_results = instruction;
+    wrong_results.accounts[3].pubkey = other_results;
+    harness.expect_error(&wrong_results, GovernanceError::ProposalMismatch);
+
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).status,
+        ProposalStatus::Finalized
+    );
+}
+
+#[test]
+fn discard_proposal_requires_the_authority_and_matching_accounts() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let proposal = harness.create_proposal(&org, 3);
+    let foreign_proposal = harness.create_proposal(&foreign, 4);
+    for frozen in [&org, &foreign] {
+        harness.run(&ix::freeze_organization(&frozen.authority, &frozen.address));
+    }
+
+    let instruction = ix::discard_proposal(&org.authority, &org.address, &proposal, None, None);
+    for signer in [&stranger, &org.recovery_authority, &foreign.authority] {
+        harness.expect_error(
+            &signed_by(instruction.clone(), signer),
+            GovernanceError::Unauthorized,
+        );
+    }
+    harness.expect_instruction_error(
+        &unsigned(instruction),
+        InstructionError::MissingRequiredSignature,
+    );
+    harness.expect_error(
+        &ix::discard_proposal(&org.authority, &org.address, &foreign_proposal, None, None),
+        GovernanceError::OrganizationMismatch,
+    );
+
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).status,
+        ProposalStatus::Draft
+    );
+}
+
+#[test]
+fn org_stats_belong_to_their_organization() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let instruction = ix::create_org_stats(&org.authority, &org.address);
+    harness.expect_error(
+        &signed_by(instruction.clone(), &stranger),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_error(
+        &signed_by(instruction.clone(), &foreign.authority),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_instruction_error(
+        &unsigned(instruction.clone()),
+        InstructionError::MissingRequiredSignature,
+    );
+    let mut wrong_bump_instruction = instruction.clone();
+    wrong_bump_instruction.data[2] = wrong_bump(wrong_bump_instruction.data[2]);
+    harness.expect_error(&wrong_bump_instruction, GovernanceError::InvalidPda);
+    let (pda, bump) = non_canonical_pda(&[ORG_STATS_SEED, org.address.as_ref()]);
+    let mut non_canonical = instruction.clone();
+    non_canonical.accounts[2].pubkey = pda;
+    non_canonical.data[2] = bump;
+    harness.expect_error(&non_canonical, GovernanceError::InvalidPda);
+    let mut foreign_stats = instruction;
+    foreign_stats.accounts[2].pubkey = ix::org_stats_pda(&foreign.address).0;
+    harness.expect_error(&foreign_stats, GovernanceError::InvalidPda);
+
+    // Finalizing with another organization's stats account fails.
+    harness.run(&ix::create_org_stats(&foreign.authority, &foreign.address));
+    let proposal = harness.create_proposal(&org, 3);
+    harness.open_proposal(&org, &proposal);
+    harness.close_proposal(&org, &proposal);
+    let nonce = harness.nonce(&org);
+    harness.run(&ix::commit_vote_results(
+        &org.authority,
+        &org.address,
+        &proposal,
+        nonce,
+        10,
+    ));
+    let nonce = harness.nonce(&org);
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &proposal,
+            nonce,
+            ProposalStatus::Finalized,
+            &[
+                ix::results_pda(&proposal).0,
+                ix::org_stats_pda(&foreign.address).0,
+            ],
+        ),
+        GovernanceError::OrganizationMismatch,
+    );
+}
+
+#[test]
+fn org_config_belongs_to_its_organization() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let instruction = ix::set_org_config(&org.authority, &org.address, 100, 86_400, 3_600, 86_400);
+    harness.expect_error(
+        &signed_by(instruction.clone(), &stranger),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_error(
+        &signed_by(instruction.clone(), &foreign.authority),
+        GovernanceError::Unauthorized,
+    );
+    harness.expect_instruction_error(
+        &unsigned(instruction.clone()),
+        InstructionError::MissingRequiredSignature,
+    );
+    let mut wrong_bump_instruction = instruction.clone();
+    wrong_bump_instruction.data[2] = wrong_bump(wrong_bump_instruction.data[2]);
+    harness.expect_error(&wrong_bump_instruction, GovernanceError::InvalidPda);
+    let (pda, bump) = non_canonical_pda(&[ORG_CONFIG_SEED, org.address.as_ref()]);
+    let mut non_canonical = instruction.clone();
+    non_canonical.accounts[2].pubkey = pda;
+    non_canonical.data[2] = bump;
+    harness.expect_error(&non_canonical, GovernanceError::InvalidPda);
+    let mut foreign_config = instruction.clone();
+    foreign_config.accounts[2].pubkey = ix::org_config_pda(&foreign.address).0;
+    harness.expect_error(&foreign_config, GovernanceError::InvalidPda);
+
+    // Once both exist, neither organization can use the other's config.
+    harness.run(&instruction);
+    harness.run(&ix::set_org_config(
+        &foreign.authority,
+        &foreign.address,
+        100,
+        86_400,
+        3_600,
+        86_400,
+    ));
+    harness.expect_error(&foreign_config, GovernanceError::OrganizationMismatch);
+    let params = ix::ProposalParams::new(3, NOW);
+    let create = ix::create_proposal(&org.authority, &org.address, &params, None);
+    harness.expect_error(
+        &ix::with_org_config(create, 4, &foreign.address),
+        GovernanceError::OrganizationMismatch,
+    );
+
+    harness.run(&ix::freeze_organization(&org.authority, &org.address));
+    let close_config = ix::close_org_config(&org.authority, &org.address);
+    for signer in [&stranger, &foreign.authority] {
+        harness.expect_error(
+            &signed_by(close_config.clone(), signer),
+            GovernanceError::Unauthorized,
+        );
+    }
+    harness.expect_instruction_error(
+        &unsigned(close_config.clone()),
+        InstructionError::MissingRequiredSignature,
+    );
+    let mut foreign_close = close_config;
+    foreign_close.accounts[2].pubkey = ix::org_config_pda(&foreign.address).0;
+    harness.expect_error(&foreign_close, GovernanceError::OrganizationMismatch);
+    assert!(harness.state::<Organization>(&org.address).has_config);
+}
+
+#[test]
+fn audit_log_belongs_to_its_organization() {
+    let (mut harness, org, foreign, stranger) = setup();
+    let instruction = ix::create_audit_log(&org.authority, &org.address);
+    for signer in [&stranger, &foreign.authority] {
+        harness.expect_error(
+            &signed_by(instruction.clone(), signer),
+            GovernanceError::Unauthorized,
+        );
+    }
+    harness.expect_instruction_error(
+        &unsigned(instruction.clone()),
+        InstructionError::MissingRequiredSignature,
+    );
+    let mut wrong_bump_instruction = instruction.clone();
+    wrong_bump_instruction.data[2] = wrong_bump(wrong_bump_instruction.data[2]);
+    harness.expect_error(&wrong_bump_instruction, GovernanceError::InvalidPda);
+    let (pda, bump) = non_canonical_pda(&[AUDIT_LOG_SEED, org.address.as_ref()]);
+    let mut non_canonical = instruction.clone();
+    non_canonical.accounts[2].pubkey = pda;
+    non_canonical.data[2] = bump;
+    harness.expect_error(&non_canonical, GovernanceError::InvalidPda);
+    let mut foreign_log = instruction.clone();
+    foreign_log.accounts[2].pubkey = ix::audit_log_pda(&foreign.address).0;
+    harness.expect_error(&foreign_log, GovernanceError::InvalidPda);
+
+    // Once both exist, neither organization can record into the other's log.
+    harness.run(&instruction);
+    harness.run(&ix::create_audit_log(&foreign.authority, &foreign.address));
+    let freeze = ix::freeze_organization(&org.authority, &org.address);
+    harness.expect_error(
+        &ix::with_audit_log(freeze.clone(), &foreign.address),
+        GovernanceError::OrganizationMismatch,
+    );
+    harness.expect_error(&freeze, GovernanceError::AuditLogRequired);
+    harness.run(&ix::with_audit_log(freeze, &org.address));
+
+    let close_log = ix::close_audit_log(&org.authority, &org.address);
+    for signer in [&stranger, &foreign.authority] {
+        harness.expect_error(
+            &signed_by(close_log.clone(), signer),
+            GovernanceError::Unauthorized,
+        );
+    }
+    harness.expect_instruction_error(
+        &unsigned(close_log.clone()),
+        InstructionError::MissingRequiredSignature,
+    );
+    let mut foreign_close = close_log;
+    foreign_close.accounts[2].pubkey = ix::audit_log_pda(&foreign.address).0;
+    harness.expect_error(&foreign_close, GovernanceError::OrganizationMismatch);
+    assert!(harness.state::<Organization>(&org.address).has_audit_log);
+}
+
+#[test]
+fn closing_seasons_and_stats_requires_the_authority_and_own_accounts() {
+    let (mut harness, org, foreign, stranger) = setup();
+    for owner in [&org, &foreign] {
+        harness.run(&ix::create_season(
+            &owner.authority,
+            &owner.address,
+            [5u8; 16],
+            NOW,
+            NOW + 30 * 86_400,
+        ));
+        harness.run(&ix::create_org_stats(&owner.authority, &owner.address));
+    }
+    harness.run(&ix::freeze_organization(&org.authority, &org.address));
+
+    let (season, _) = ix::season_pda(&org.address, [5u8; 16]);
+    let close_season = ix::close_season(&org.authority, &org.address, &season);
+    let close_stats = ix::close_org_stats(&org.authority, &org.address);
+    for instruction in [&close_season, &close_stats] {
+        for signer in [&stranger, &foreign.authority] {
+            harness.expect_error(
+                &signed_by(instruction.clone(), signer),
+                GovernanceError::Unauthorized,
+            );
+        }
+        harness.expect_instruction_error(
+            &unsigned(instruction.clone()),
+            InstructionError::MissingRequiredSignature,
+        );
+    }
+
+    let (foreign_season, _) = ix::season_pda(&foreign.address, [5u8; 16]);
+    harness.expect_error(
+        &ix::close_season(&org.authority, &org.address, &foreign_season),
+        GovernanceError::SeasonMismatch,
+    );
+    let mut foreign_stats = close_stats;
+    foreign_stats.accounts[2].pubkey = ix::org_stats_pda(&foreign.address).0;
+    harness.expect_error(&foreign_stats, GovernanceError::OrganizationMismatch);
+
+    let state: Organization = harness.state(&org.address);
+    assert_eq!(state.season_count, 1);
+    assert!(state.has_stats);
+}
@@ -0,0 +1,384 @@
+//! Lifecycle tests that move the cluster clock across proposal windows and the
+//! recovery timelock, checking each time-based rule at its boundary.
+
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::{ix, Harness, NOW, SLOT};
+use fan_engagement_log::{
+    error::GovernanceError,
+    state::{
+        Organization, Proposal, ProposalResults, ProposalStatus, Season, RECOVERY_TIMELOCK_SECONDS,
+    },
+};
+
+#[test]
+fn proposal_cannot_be_created_once_its_window_has_ended() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let params = ix::ProposalParams::new(2, NOW);
+
+    harness.warp_to(params.end_at);
+    harness.expect_error(
+        &ix::create_proposal(&org.authority, &org.address, &params, None),
+        GovernanceError::EndBeforeNow,
+    );
+
+    harness.warp_to(params.end_at - 1);
+    harness.run(&ix::create_proposal(
+        &org.authority,
+        &org.address,
+        &params,
+        None,
+    ));
+}
+
+#[test]
+fn proposal_may_be_created_after_its_window_has_started() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let params = ix::ProposalParams::new(2, NOW);
+
+    harness.warp_to(params.start_at + 3_600);
+    harness.run(&ix::create_proposal(
+        &org.authority,
+        &org.address,
+        &params,
+        None,
+    ));
+
+    let (proposal, _) = ix::proposal_pda(&org.address, params.proposal_id);
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).created_at,
+        params.start_at + 3_600
+    );
+}
+
+#[test]
+fn proposal_opens_only_inside_its_voting_window() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let params = ix::ProposalParams::new(2, NOW);
+    let proposal = harness.create_proposal(&org, 2);
+    let late = harness.create_proposal(&org, 3);
+    let open = |proposal: &_| {
+        ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            proposal,
+            0,
+            ProposalStatus::Open,
+            &[],
+        )
+    };
+
+    harness.warp_to(params.start_at - 1);
+    harness.expect_error(&open(&proposal), GovernanceError::VotingNotStarted);
+    harness.expect_error(
+        &ix::open_proposal_with_snapshot(&org.authority, &org.address, &proposal, 0, SLOT, 500),
+        GovernanceError::VotingNotStarted,
+    );
+
+    harness.warp_to(params.start_at);
+    harness.run(&open(&proposal));
+
+    harness.warp_to(params.end_at);
+    harness.expect_error(&open(&late), GovernanceError::VotingEnded);
+    harness.expect_error(
+        &ix::open_proposal_with_snapshot(&org.authority, &org.address, &late, 1, SLOT, 500),
+        GovernanceError::VotingEnded,
+    );
+    harness.warp_to(params.end_at - 1);
+    harness.run(&ix::update_proposal_status(
+        &org.authority,
+        &org.address,
+        &late,
+        1,
+        ProposalStatus::Open,
+        &[],
+    ));
+}
+
+#[test]
+fn proposal_closes_only_once_its_voting_window_has_ended() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let params = ix::ProposalParams::new(2, NOW);
+    let proposal = harness.create_proposal(&org, 2);
+    harness.open_proposal(&org, &proposal);
+
+    harness.warp_to(params.end_at - 1);
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &proposal,
+            1,
+            ProposalStatus::Closed,
+            &[],
+        ),
+        GovernanceError::VotingNotEnded,
+    );
+    harness.expect_error(
+        &ix::close_and_commit_results(&org.authority, &org.address, &proposal, 1, 420),
+        GovernanceError::VotingNotEnded,
+    );
+
+    harness.warp_to(params.end_at);
+    harness.run(&ix::close_and_commit_results(
+        &org.authority,
+        &org.address,
+        &proposal,
+        1,
+        420,
+    ));
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).status,
+        ProposalStatus::Closed
+    );
+}
+
+#[test]
+fn lifecycle_records_the_clock_at_each_step() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let params = ix::ProposalParams::new(2, NOW);
+    harness.run(&ix::create_proposal(
+        &org.authority,
+        &org.address,
+        &params,
+        None,
+    ));
+    let (proposal, _) = ix::proposal_pda(&org.address, params.proposal_id);
+    let (results, _) = ix::results_pda(&proposal);
+
+    harness.warp_to(params.start_at);
+    harness.run(&ix::update_proposal_status(
+        &org.authority,
+        &org.address,
+        &proposal,
+        0,
+        ProposalStatus::Open,
+        &[],
+    ));
+    let opened: Proposal = harness.state(&proposal);
+    assert_eq!(opened.updated_at, params.start_at);
+    assert_eq!(opened.opened_at, params.start_at);
+    assert_eq!(opened.closed_at, 0);
+
+    harness.warp_to(params.end_at);
+    harness.run(&ix::update_proposal_status(
+        &org.authority,
+        &org.address,
+        &proposal,
+        1,
+        ProposalStatus::Closed,
+        &[],
+    ));
+    let closed: Proposal = harness.state(&proposal);
+    assert_eq!(closed.updated_at, params.end_at);
+    assert_eq!(closed.closed_at, params.end_at);
+
+    harness.warp_to(params.end_at + 600);
+    harness.run(&ix::commit_vote_results(
+        &org.authority,
+        &org.address,
+        &proposal,
+        2,
+        420,
+    ));
+    assert_eq!(
+        harness.state::<ProposalResults>(&results).committed_at,
+        params.end_at + 600
+    );
+
+    harness.warp_to(params.end_at + 86_400);
+    harness.run(&ix::update_proposal_status(
+        &org.authority,
+        &org.address,
+        &proposal,
+        3,
+        ProposalStatus::Finalized,
+        &[results],
+    ));
+
+    let committed: ProposalResults = harness.state(&results);
+    assert_eq!(committed.committed_at, params.end_at + 600);
+    assert_eq!(committed.finalized_at, params.end_at + 86_400);
+    let state: Proposal = harness.state(&proposal);
+    assert_eq!(state.created_at, NOW);
+    assert_eq!(state.updated_at, params.end_at + 86_400);
+    assert_eq!(state.opened_at, params.start_at);
+    assert_eq!(state.closed_at, params.end_at);
+
+    let history = [
+        (ProposalStatus::Open, params.start_at),
+        (ProposalStatus::Closed, params.end_at),
+        (ProposalStatus::Finalized, params.end_at + 86_400),
+    ]
+    .into_iter()
+    .fold([0; 32], |previous, (status, at)| {
+        Proposal::chain_history_hash(&previous, status, at)
+    });
+    assert_eq!(state.history_hash, history);
+}
+
+#[test]
+fn season_only_accepts_windows_inside_it_at_any_time() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let season_end = NOW + 7 * 86_400;
+    harness.run(&ix::create_season(
+        &org.authority,
+        &org.address,
+        [5u8; 16],
+        NOW,
+        season_end,
+    ));
+    let (season, _) = ix::season_pda(&org.address, [5u8; 16]);
+
+    // Late in the season, a poll ending on its last second still fits.
+    harness.warp_to(season_end - 7_200);
+    let mut params = ix::ProposalParams::new(2, harness.now());
+    params.end_at = season_end;
+    harness.run(&ix::create_proposal(
+        &org.authority,
+        &org.address,
+        &params,
+        Some(&season),
+    ));
+
+    let mut params = ix::ProposalParams::new(3, harness.now());
+    params.end_at = season_end + 1;
+    harness.expect_error(
+        &ix::create_proposal(&org.authority, &org.address, &params, Some(&season)),
+        GovernanceError::ProposalOutsideSeason,
+    );
+
+    assert_eq!(harness.state::<Season>(&season).proposal_count, 1);
+    assert_eq!(
+        harness.state::<Season>(&season).updated_at,
+        season_end - 7_200
+    );
+}
+
+#[test]
+fn recovery_completes_exactly_when_the_timelock_expires() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let new_authority = harness.funded_key();
+
+    harness.warp_to(NOW + 1_000);
+    harness.run(&ix::initiate_recovery(
+        &org.recovery_authority,
+        &org.address,
+        &new_authority,
+    ));
+    let unlocks_at = NOW + 1_000 + RECOVERY_TIMELOCK_SECONDS;
+
+    harness.warp_to(unlocks_at - 1);
+    harness.expect_error(
+        &ix::complete_recovery(&org.recovery_authority, &org.address),
+        GovernanceError::RecoveryTimelockActive,
+    );
+
+    harness.warp_to(unlocks_at);
+    harness.run(&ix::complete_recovery(
+        &org.recovery_authority,
+        &org.address,
+    ));
+
+    let state: Organization = harness.state(&org.address);
+    assert_eq!(common::pubkey(&state.authority), new_authority);
+    assert_eq!(state.updated_at, unlocks_at);
+}
+
+#[test]
+fn cancelled_recovery_restarts_the_timelock() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let new_authority = harness.funded_key();
+
+    harness.run(&ix::initiate_recovery(
+        &org.recovery_authority,
+        &org.address,
+        &new_authority,
+    ));
+    harness.warp_to(NOW + RECOVERY_TIMELOCK_SECONDS - 60);
+    harness.run(&ix::cancel_recovery(&org.authority, &org.address));
+
+    harness.run(&ix::initiate_recovery(
+        &org.recovery_authority,
+        &org.address,
+        &new_authority,
+    ));
+    harness.warp_to(NOW + RECOVERY_TIMELOCK_SECONDS);
+    harness.expect_error(
+        &ix::complete_recovery(&org.recovery_authority, &org.address),
+        GovernanceError::RecoveryTimelockActive,
+    );
+
+    harness.warp_to(NOW + 2 * RECOVERY_TIMELOCK_SECONDS - 60);
+    harness.run(&ix::complete_recovery(
+        &org.recovery_authority,
+        &org.address,
+    ));
+}
+
+#[test]
+fn snapshot_slot_becomes_valid_once_the_cluster_reaches_it() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+
+    harness.expect_error(
+        &ix::open_proposal_with_snapshot(
+            &org.authority,
+            &org.address,
+            &proposal,
+            0,
+            SLOT + 10,
+            500,
+        ),
+        GovernanceError::InvalidSnapshotSlot,
+    );
+
+    harness.warp_to_slot(SLOT + 10);
+    harness.warp_to_voting_start(&proposal);
+    harness.run(&ix::open_proposal_with_snapshot(
+        &org.authority,
+        &org.address,
+        &proposal,
+        0,
+        SLOT + 10,
+        500,
+    ));
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).snapshot_slot,
+        SLOT + 10
+    );
+}
+
+#[test]
+fn opening_records_the_current_slot() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    assert_eq!(harness.state::<Proposal>(&proposal).snapshot_slot, 0);
+
+    harness.warp_to_slot(SLOT + 1_234);
+    harness.open_proposal(&org, &proposal);
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).snapshot_slot,
+        SLOT + 1_234
+    );
+
+    // Later slots leave the pinned slot alone.
+    harness.warp_to_slot(SLOT + 5_000);
+    harness.close_proposal(&org, &proposal);
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).snapshot_slot,
+        SLOT + 1_234
+    );
+}
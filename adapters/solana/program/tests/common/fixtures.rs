@@ -0,0 +1,163 @@
+//! Typical account states shared by the state, serde and account-size tests,
+//! so a layout change updates one constructor per account type.
+
+use fan_engagement_log::{
+    instruction::{CREATE_AUDIT_LOG, CREATE_PROPOSAL},
+    state::{
+        fixed_bytes, AuditEntry, AuditLog, ClosedOrganization, HashScheme, OrgConfig, OrgStats,
+        Organization, Proposal, ProposalResults, ProposalStatus, ProposalSummary, Season,
+    },
+};
+use pinocchio::Address;
+
+pub fn organization() -> Organization {
+    Organization {
+        bump: 255,
+        organization_id: [3u8; 16],
+        authority: Address::new_from_array([8u8; 32]),
+        proposal_count: 12,
+        archived_proposal_count: 3,
+        season_count: 2,
+        has_stats: true,
+        has_config: true,
+        has_audit_log: true,
+        operation_nonce: 41,
+        adapter_signer_count: 1,
+        adapter_signers: [
+            Address::new_from_array([10u8; 32]),
+            Address::default(),
+            Address::default(),
+            Address::default(),
+        ],
+        recovery_authority: Address::new_from_array([11u8; 32]),
+        pending_authority: Address::default(),
+        recovery_initiated_at: 0,
+        created_at: 1_700_000_000,
+        updated_at: 1_700_000_500,
+        frozen_at: 0,
+        dispute_window_seconds: 86_400,
+        dispute_bond_lamports: 1_000_000,
+        name_len: 4,
+        name: fixed_bytes(b"Club"),
+    }
+}
+
+pub fn proposal() -> Proposal {
+    Proposal {
+        bump: 254,
+        status: ProposalStatus::Open,
+        organization: Address::new_from_array([1u8; 32]),
+        proposal_id: [2u8; 16],
+        created_by: Address::new_from_array([8u8; 32]),
+        season: Address::new_from_array([9u8; 32]),
+        results: Address::new_from_array([4u8; 32]),
+        content_hash: [6u8; 32],
+        content_hash_scheme: HashScheme::Sha256,
+        start_at: 1_700_000_000,
+        end_at: 1_700_086_400,
+        original_end_at: 1_700_050_000,
+        quorum_requirement: 100,
+        eligible_voting_power: 10_000,
+        snapshot_slot: 250_000_000,
+        voter_merkle_root: [13u8; 32],
+        created_at: 1_699_999_000,
+        updated_at: 1_700_000_000,
+        opened_at: 1_700_000_000,
+        closed_at: 0,
+        amendment_count: 1,
+        history_hash: [15u8; 32],
+        title_len: 8,
+        title: fixed_bytes(b"New kit?"),
+    }
+}
+
+pub fn results() -> ProposalResults {
+    ProposalResults {
+        bump: 253,
+        proposal: Address::new_from_array([4u8; 32]),
+        results_hash: [5u8; 32],
+        results_hash_scheme: HashScheme::Sha256,
+        winning_option_id: [7u8; 16],
+        total_votes_cast: 321,
+        quorum_met: true,
+        committed_at: 1_700_090_000,
+        finalized_at: 0,
+        dispute_ends_at: 1_700_176_400,
+        disputed_by: Address::new_from_array([12u8; 32]),
+        disputed_at: 1_700_100_000,
+        dispute_bond: 1_000_000,
+        hash_verified: true,
+    }
+}
+
+pub fn season() -> Season {
+    Season {
+        bump: 252,
+        organization: Address::new_from_array([1u8; 32]),
+        season_id: [25u8; 16],
+        start_at: 1_690_000_000,
+        end_at: 1_720_000_000,
+        proposal_count: 4,
+        finalized_proposal_count: 2,
+        total_votes_cast: 900,
+        created_at: 1_689_000_000,
+        updated_at: 1_700_000_000,
+    }
+}
+
+pub fn org_stats() -> OrgStats {
+    OrgStats {
+        bump: 250,
+        organization: Address::new_from_array([1u8; 32]),
+        quorum_met_count: 3,
+        quorum_failed_count: 1,
+        total_votes_cast: 2_400,
+        turnout_bps_sum: 18_000,
+        created_at: 1_690_000_000,
+        updated_at: 1_700_000_000,
+    }
+}
+
+pub fn org_config() -> OrgConfig {
+    OrgConfig {
+        bump: 249,
+        organization: Address::new_from_array([1u8; 32]),
+        default_quorum_requirement: 100,
+        default_voting_duration_seconds: 3 * 86_400,
+        min_voting_duration_seconds: 86_400,
+        max_voting_duration_seconds: 14 * 86_400,
+        created_at: 1_690_000_000,
+        updated_at: 1_700_000_000,
+    }
+}
+
+pub fn audit_log() -> AuditLog {
+    let mut log = AuditLog {
+        bump: 248,
+        organization: Address::new_from_array([1u8; 32]),
+        entry_count: 0,
+        entries: Default::default(),
+        created_at: 1_690_000_000,
+    };
+    log.append(AuditEntry {
+        actor: Address::new_from_array([8u8; 32]),
+        instruction: CREATE_AUDIT_LOG,
+        target: Address::new_from_array([15u8; 32]),
+        timestamp: 1_690_000_000,
+    });
+    log.append(AuditEntry {
+        actor: Address::new_from_array([8u8; 32]),
+        instruction: CREATE_PROPOSAL,
+        target: Address::new_from_array([2u8; 32]),
+        timestamp: 1_699_999_000,
+    });
+    log
+}
+
+pub fn closed_organization() -> ClosedOrganization {
+    ClosedOrganization::new(&organization(), 1_700_200_000)
+}
+
+pub fn summary() -> ProposalSummary {
+    ProposalSummary::new(&proposal(), &results())
+}
@@ -0,0 +1,683 @@
+//! Instruction builders following the payload layouts and account orders
+//! documented on `GovernanceInstruction`.
+
+use fan_engagement_log::{
+    instruction::{
+        ADD_ADAPTER_SIGNER, ARCHIVE_PROPOSAL, CANCEL_RECOVERY, CLOSE_AND_COMMIT_RESULTS,
+        CLOSE_AUDIT_LOG, CLOSE_ORGANIZATION, CLOSE_ORG_CONFIG, CLOSE_ORG_STATS, CLOSE_SEASON,
+        COMMIT_VOTE_RESULTS, COMPLETE_RECOVERY, CREATE_AUDIT_LOG, CREATE_ORGANIZATION,
+        CREATE_ORG_STATS, CREATE_PROPOSAL, CREATE_PROPOSALS_BATCH, CREATE_SEASON, DISCARD_PROPOSAL,
+        DISPUTE_RESULTS, EXTEND_VOTING_PERIOD, FINALIZE_PROPOSAL, FREEZE_ORGANIZATION,
+        GOVERNANCE_PREFIX, INITIATE_RECOVERY, LOG_VERSION, OPEN_PROPOSAL_WITH_SNAPSHOT,
+        REMOVE_ADAPTER_SIGNER, SET_DISPUTE_POLICY, SET_ELIGIBLE_VOTING_POWER, SET_ORG_CONFIG,
+        UPDATE_PROPOSAL_DRAFT, UPDATE_PROPOSAL_STATUS, VERIFY_RESULTS_HASH,
+    },
+    pda::{
+        find_audit_log_pda, find_org_config_pda, find_org_stats_pda, find_organization_pda,
+        find_proposal_pda, find_results_pda, find_season_pda,
+    },
+    state::{HashScheme, ProposalStatus},
+};
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+use super::{address, pubkey, PROGRAM_ID};
+
+const SYSTEM_PROGRAM: Pubkey = Pubkey::new_from_array([0u8; 32]);
+
+pub fn organization_pda(organization_id: [u8; 16]) -> (Pubkey, u8) {
+    let (pda, bump) = find_organization_pda(&organization_id, &address(&PROGRAM_ID));
+    (pubkey(&pda), bump)
+}
+
+pub fn proposal_pda(organization: &Pubkey, proposal_id: [u8; 16]) -> (Pubkey, u8) {
+    let (pda, bump) =
+        find_proposal_pda(&address(organization), &proposal_id, &address(&PROGRAM_ID));
+    (pubkey(&pda), bump)
+}
+
+pub fn results_pda(proposal: &Pubkey) -> (Pubkey, u8) {
+    let (pda, bump) = find_results_pda(&address(proposal), &address(&PROGRAM_ID));
+    (pubkey(&pda), bump)
+}
+
+pub fn org_stats_pda(organization: &Pubkey) -> (Pubkey, u8) {
+    let (pda, bump) = find_org_stats_pda(&address(organization), &address(&PROGRAM_ID));
+    (pubkey(&pda), bump)
+}
+
+pub fn org_config_pda(organization: &Pubkey) -> (Pubkey, u8) {
+    let (pda, bump) = find_org_config_pda(&address(organization), &address(&PROGRAM_ID));
+    (pubkey(&pda), bump)
+}
+
+pub fn audit_log_pda(organization: &Pubkey) -> (Pubkey, u8) {
+    let (pda, bump) = find_audit_log_pda(&address(organization), &address(&PROGRAM_ID));
+    (pubkey(&pda), bump)
+}
+
+pub fn season_pda(organization: &Pubkey, season_id: [u8; 16]) -> (Pubkey, u8) {
+    let (pda, bump) = find_season_pda(&address(organization), &season_id, &address(&PROGRAM_ID));
+    (pubkey(&pda), bump)
+}
+
+fn push_short(data: &mut Vec<u8>, bytes: &[u8]) {
+    data.push(bytes.len() as u8);
+    data.extend_from_slice(bytes);
+}
+
+/// Builds a governance instruction from its tag and payload.
+fn instruction(mut data: Vec<u8>, accounts: Vec<AccountMeta>) -> Instruction {
+    data.insert(0, GOVERNANCE_PREFIX);
+    Instruction::new_with_bytes(PROGRAM_ID, &data, accounts)
+}
+
+pub fn log(signer: &Pubkey, payload: &[u8]) -> Instruction {
+    Instruction::new_with_bytes(
+        PROGRAM_ID,
+        payload,
+        vec![AccountMeta::new_readonly(*signer, true)],
+    )
+}
+
+pub fn create_organization(
+    authority: &Pubkey,
+    organization_id: [u8; 16],
+    recovery_authority: &Pubkey,
+    name: &str,
+) -> Instruction {
+    let (organization, bump) = organization_pda(organization_id);
+    let mut data = vec![CREATE_ORGANIZATION];
+    data.extend_from_slice(&organization_id);
+    data.push(bump);
+    data.extend_from_slice(recovery_authority.as_ref());
+    push_short(&mut data, name.as_bytes());
+
+    instruction(
+        data,
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(organization, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
+        ],
+    )
+}
+
+/// `CreateProposal` payload. `bump` defaults to the canonical bump.
+#[derive(Clone, Debug)]
+pub struct ProposalParams {
+    pub proposal_id: [u8; 16],
+    pub bump: Option<u8>,
+    pub content_hash_scheme: HashScheme,
+    pub start_at: i64,
+    pub end_at: i64,
+    pub quorum_requirement: u64,
+    pub eligible_voting_power: u64,
+    pub title: &'static str,
+}
+
+impl ProposalParams {
+    /// A one-day poll starting a minute after `now`.
+    pub fn new(id: u8, now: i64) -> Self {
+        Self {
+            proposal_id: [id; 16],
+            bump: None,
+            content_hash_scheme: HashScheme::Sha256,
+            start_at: now + 60,
+            end_at: now + 86_400,
+            quorum_requirement: 100,
+            eligible_voting_power: 1_000,
+            title: "Kit vote",
+        }
+    }
+
+    fn write(&self, organization: &Pubkey, data: &mut Vec<u8>) {
+        let bump = self
+            .bump
+            .unwrap_or_else(|| proposal_pda(organization, self.proposal_id).1);
+        data.extend_from_slice(&self.proposal_id);
+        data.push(bump);
+        data.extend_from_slice(&[9u8; 32]);
+        data.push(self.content_hash_scheme as u8);
+        data.extend_from_slice(&self.start_at.to_le_bytes());
+        data.extend_from_slice(&self.end_at.to_le_bytes());
+        data.extend_from_slice(&self.quorum_requirement.to_le_bytes());
+        data.extend_from_slice(&self.eligible_voting_power.to_le_bytes());
+        push_short(data, self.title.as_bytes());
+    }
+}
+
+pub fn create_proposal(
+    authority: &Pubkey,
+    organization: &Pubkey,
+    params: &ProposalParams,
+    season: Option<&Pubkey>,
+) -> Instruction {
+    let mut data = vec![CREATE_PROPOSAL];
+    params.write(organization, &mut data);
+
+    let mut accounts = vec![
+        AccountMeta::new(*authority, true),
+        AccountMeta::new(*organization, false),
+        AccountMeta::new(proposal_pda(organization, params.proposal_id).0, false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
+    ];
+    accounts.extend(season.map(|season| AccountMeta::new(*season, false)));
+    instruction(data, accounts)
+}
+
+pub fn create_proposals_batch(
+    authority: &Pubkey,
+    organization: &Pubkey,
+    proposals: &[ProposalParams],
+    season: Option<&Pubkey>,
+) -> Instruction {
+    let mut data = vec![CREATE_PROPOSALS_BATCH, proposals.len() as u8];
+    for params in proposals {
+        params.write(organization, &mut data);
+    }
+
+    let mut accounts = vec![
+        AccountMeta::new(*authority, true),
+        AccountMeta::new(*organization, false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
+    ];
+    accounts.extend(
+        proposals.iter().map(|params| {
+            AccountMeta::new(proposal_pda(organization, params.proposal_id).0, false)
+        }),
+    );
+    accounts.extend(season.map(|season| AccountMeta::new(*season, false)));
+    instruction(data, accounts)
+}
+
+pub fn update_proposal_status(
+    signer: &Pubkey,
+    organization: &Pubkey,
+    proposal: &Pubkey,
+    operation_nonce: u64,
+    status: ProposalStatus,
+    extra: &[Pubkey],
+) -> Instruction {
+    let mut data = vec![UPDATE_PROPOSAL_STATUS];
+    data.extend_from_slice(&operation_nonce.to_le_bytes());
+    data.push(status as u8);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*signer, true),
+        AccountMeta::new(*organization, false),
+        AccountMeta::new(*proposal, false),
+    ];
+    accounts.extend(extra.iter().map(|key| AccountMeta::new(*key, false)));
+    instruction(data, accounts)
+}
+
+fn results_payload(tag: u8, operation_nonce: u64, bump: u8, total_votes_cast: u64) -> Vec<u8> {
+    let mut data = vec![tag];
+    data.extend_from_slice(&operation_nonce.to_le_bytes());
+    data.push(bump);
+    data.extend_from_slice(&[4u8; 32]);
+    data.push(HashScheme::Sha256 as u8);
+    data.extend_from_slice(&[5u8; 16]);
+    data.extend_from_slice(&total_votes_cast.to_le_bytes());
+    data.push(1);
+    data
+}
+
+fn results_instruction(
+    tag: u8,
+    signer: &Pubkey,
+    organization: &Pubkey,
+    proposal: &Pubkey,
+    operation_nonce: u64,
+    total_votes_cast: u64,
+) -> Instruction {
+    let (results, bump) = results_pda(proposal);
+    instruction(
+        results_payload(tag, operation_nonce, bump, total_votes_cast),
+        vec![
+            AccountMeta::new(*signer, true),
+            AccountMeta::new(*organization, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new(results, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
+        ],
+    )
+}
+
+pub fn commit_vote_results(
+    signer: &Pubkey,
+    organization: &Pubkey,
+    proposal: &Pubkey,
+    operation_nonce: u64,
+    total_votes_cast: u64,
+) -> Instruction {
+    results_instruction(
+        COMMIT_VOTE_RESULTS,
+        signer,
+        organization,
+        proposal,
+        operation_nonce,
+        total_votes_cast,
+    )
+}
+
+pub fn close_and_commit_results(
+    signer: &Pubkey,
+    organization: &Pubkey,
+    proposal: &Pubkey,
+    operation_nonce: u64,
+    total_votes_cast: u64,
+) -> Instruction {
+    results_instruction(
+        CLOSE_AND_COMMIT_RESULTS,
+        signer,
+        organization,
+        proposal,
+        operation_nonce,
+        total_votes_cast,
+    )
+}
+
+pub fn create_season(
+    authority: &Pubkey,
+    organization: &Pubkey,
+    season_id: [u8; 16],
+    start_at: i64,
+    end_at: i64,
+) -> Instruction {
+    let (season, bump) = season_pda(organization, season_id);
+    let mut data = vec![CREATE_SEASON];
+    data.extend_from_slice(&season_id);
+    data.push(bump);
+    data.extend_from_slice(&start_at.to_le_bytes());
+    data.extend_from_slice(&end_at.to_le_bytes());
+
+    instruction(
+        data,
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*organization, false),
+            AccountMeta::new(season, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
+        ],
+    )
+}
+
+fn organization_instruction(
+    tag: u8,
+    signer: &Pubkey,
+    organization: &Pubkey,
+    key: Option<&Pubkey>,
+) -> Instruction {
+    let mut data = vec![tag];
+    data.extend(key.into_iter().flat_map(|key| key.to_bytes()));
+    instruction(
+        data,
+        vec![
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new(*organization, false),
+        ],
+    )
+}
+
+pub fn add_adapter_signer(
+    authority: &Pubkey,
+    organization: &Pubkey,
+    signer: &Pubkey,
+) -> Instruction {
+    organization_instruction(ADD_ADAPTER_SIGNER, authority, organization, Some(signer))
+}
+
+pub fn remove_adapter_signer(
+    authority: &Pubkey,
+    organization: &Pubkey,
+    signer: &Pubkey,
+) -> Instruction {
+    organization_instruction(REMOVE_ADAPTER_SIGNER, authority, organization, Some(signer))
+}
+
+pub fn initiate_recovery(
+    recovery_authority: &Pubkey,
+    organization: &Pubkey,
+    new_authority: &Pubkey,
+) -> Instruction {
+    organization_instruction(
+        INITIATE_RECOVERY,
+        recovery_authority,
+        organization,
+        Some(new_authority),
+    )
+}
+
+pub fn cancel_recovery(authority: &Pubkey, organization: &Pubkey) -> Instruction {
+    organization_instruction(CANCEL_RECOVERY, authority, organization, None)
+}
+
+pub fn complete_recovery(recovery_authority: &Pubkey, organization: &Pubkey) -> Instruction {
+    organization_instruction(COMPLETE_RECOVERY, recovery_authority, organization, None)
+}
+
+pub fn open_proposal_with_snapshot(
+    signer: &Pubkey,
+    organization: &Pubkey,
+    proposal: &Pubkey,
+    operation_nonce: u64,
+    snapshot_slot: u64,
+    eligible_voting_power: u64,
+) -> Instruction {
+    let mut data = vec![OPEN_PROPOSAL_WITH_SNAPSHOT];
+    data.extend_from_slice(&operation_nonce.to_le_bytes());
+    data.extend_from_slice(&snapshot_slot.to_le_bytes());
+    data.extend_from_slice(&[3u8; 32]);
+    data.extend_from_slice(&eligible_voting_power.to_le_bytes());
+    instruction(
+        data,
+        vec![
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new(*organization, false),
+            AccountMeta::new(*proposal, false),
+        ],
+    )
+}
+
+pub fn set_eligible_voting_power(
+    signer: &Pubkey,
+    organization: &Pubkey,
+    proposal: &Pubkey,
+    operation_nonce: u64,
+    eligible_voting_power: u64,
+) -> Instruction {
+    let mut data = vec![SET_ELIGIBLE_VOTING_POWER];
+    data.extend_from_slice(&operation_nonce.to_le_bytes());
+    data.extend_from_slice(&eligible_voting_power.to_le_bytes());
+    instruction(
+        data,
+        vec![
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new(*organization, false),
+            AccountMeta::new(*proposal, false),
+        ],
+    )
+}
+
+pub fn log_version() -> Instruction {
+    instruction(vec![LOG_VERSION], vec![])
+}
+
+pub fn freeze_organization(authority: &Pubkey, organization: &Pubkey) -> Instruction {
+    organization_instruction(FREEZE_ORGANIZATION, authority, organization, None)
+}
+
+pub fn close_organization(authority: &Pubkey, organization: &Pubkey) -> Instruction {
+    instruction(
+        vec![CLOSE_ORGANIZATION],
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*organization, false),
+        ],
+    )
+}
+
+pub fn archive_proposal(
+    authority: &Pubkey,
+    organization: &Pubkey,
+    proposal: &Pubkey,
+) -> Instruction {
+    instruction(
+        vec![ARCHIVE_PROPOSAL],
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*organization, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new(results_pda(proposal).0, false),
+        ],
+    )
+}
+
+pub fn create_org_stats(authority: &Pubkey, organization: &Pubkey) -> Instruction {
+    let (stats, bump) = org_stats_pda(organization);
+    instruction(
+        vec![CREATE_ORG_STATS, bump],
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*organization, false),
+            AccountMeta::new(stats, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
+        ],
+    )
+}
+
+pub fn close_season(authority: &Pubkey, organization: &Pubkey, season: &Pubkey) -> Instruction {
+    instruction(
+        vec![CLOSE_SEASON],
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*organization, false),
+            AccountMeta::new(*season, false),
+        ],
+    )
+}
+
+pub fn close_org_stats(authority: &Pubkey, organization: &Pubkey) -> Instruction {
+    instruction(
+        vec![CLOSE_ORG_STATS],
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*organization, false),
+            AccountMeta::new(org_stats_pda(organization).0, false),
+        ],
+    )
+}
+
+/// Amends a draft to `params`' window, quorum and title, with content hash
+/// `[10; 32]`. `params.proposal_id` and `eligible_voting_power` are ignored.
+pub fn update_proposal_draft(
+    signer: &Pubkey,
+    organization: &Pubkey,
+    proposal: &Pubkey,
+    params: &ProposalParams,
+    season: Option<&Pubkey>,
+) -> Instruction {
+    let mut data = vec![UPDATE_PROPOSAL_DRAFT];
+    data.extend_from_slice(&[10u8; 32]);
+    data.push(params.content_hash_scheme as u8);
+    data.extend_from_slice(&params.start_at.to_le_bytes());
+    data.extend_from_slice(&params.end_at.to_le_bytes());
+    data.extend_from_slice(&params.quorum_requirement.to_le_bytes());
+    push_short(&mut data, params.title.as_bytes());
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*signer, true),
+        AccountMeta::new_readonly(*organization, false),
+        AccountMeta::new(*proposal, false),
+    ];
+    if let Some(season) = season {
+        accounts.push(AccountMeta::new_readonly(*season, false));
+    }
+    instruction(data, accounts)
+}
+
+pub fn extend_voting_period(
+    authority: &Pubkey,
+    organization: &Pubkey,
+    proposal: &Pubkey,
+    end_at: i64,
+    season: Option<&Pubkey>,
+) -> Instruction {
+    let mut data = vec![EXTEND_VOTING_PERIOD];
+    data.extend_from_slice(&end_at.to_le_bytes());
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(*organization, false),
+        AccountMeta::new(*proposal, false),
+    ];
+    if let Some(season) = season {
+        accounts.push(AccountMeta::new_readonly(*season, false));
+    }
+    instruction(data, accounts)
+}
+
+pub fn set_dispute_policy(
+    authority: &Pubkey,
+    organization: &Pubkey,
+    window_seconds: i64,
+    bond_lamports: u64,
+) -> Instruction {
+    let mut data = vec![SET_DISPUTE_POLICY];
+    data.extend_from_slice(&window_seconds.to_le_bytes());
+    data.extend_from_slice(&bond_lamports.to_le_bytes());
+    instruction(
+        data,
+        vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*organization, false),
+        ],
+    )
+}
+
+pub fn dispute_results(
+    challenger: &Pubkey,
+    organization: &Pubkey,
+    proposal: &Pubkey,
+) -> Instruction {
+    instruction(
+        vec![DISPUTE_RESULTS],
+        vec![
+            AccountMeta::new(*challenger, true),
+            AccountMeta::new_readonly(*organization, false),
+            AccountMeta::new_readonly(*proposal, false),
+            AccountMeta::new(results_pda(proposal).0, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
+        ],
+    )
+}
+
+/// Finalizes without a signer, passing `extra` after the results PDA.
+pub fn finalize_proposal(
+    organization: &Pubkey,
+    proposal: &Pubkey,
+    extra: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*organization, false),
+        AccountMeta::new(*proposal, false),
+        AccountMeta::new(results_pda(proposal).0, false),
+    ];
+    accounts.extend(extra.iter().map(|key| AccountMeta::new(*key, false)));
+    instruction(vec![FINALIZE_PROPOSAL], accounts)
+}
+
+/// `chunks` are accounts whose data continue `preimage`, in order.
+pub fn verify_results_hash(proposal: &Pubkey, preimage: &[u8], chunks: &[Pubkey]) -> Instruction {
+    let mut data = vec![VERIFY_RESULTS_HASH];
+    data.extend_from_slice(preimage);
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*proposal, false),
+        AccountMeta::new(results_pda(proposal).0, false),
+    ];
+    accounts.extend(
+        chunks
+            .iter()
+            .map(|key| AccountMeta::new_readonly(*key, false)),
+    );
+    instruction(data, accounts)
+}
+
+pub fn set_org_config(
+    authority: &Pubkey,
+    organization: &Pubkey,
+    default_quorum_requirement: u64,
+    default_voting_duration_seconds: i64,
+    min_voting_duration_seconds: i64,
+    max_voting_duration_seconds: i64,
+) -> Instruction {
+    let (config, bump) = org_config_pda(organization);
+    let mut data = vec![SET_ORG_CONFIG, bump];
+    data.extend_from_slice(&default_quorum_requirement.to_le_bytes());
+    data.extend_from_slice(&default_voting_duration_seconds.to_le_bytes());
+    data.extend_from_slice(&min_voting_duration_seconds.to_le_bytes());
+    data.extend_from_slice(&max_voting_duration_seconds.to_le_bytes());
+    instruction(
+        data,
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*organization, false),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
+        ],
+    )
+}
+
+pub fn close_org_config(authority: &Pubkey, organization: &Pubkey) -> Instruction {
+    instruction(
+        vec![CLOSE_ORG_CONFIG],
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*organization, false),
+            AccountMeta::new(org_config_pda(organization).0, false),
+        ],
+    )
+}
+
+/// Inserts the organization's config PDA at `index`, where instructions that
+/// check proposal windows expect it once the organization has a config.
+pub fn with_org_config(
+    mut instruction: Instruction,
+    index: usize,
+    organization: &Pubkey,
+) -> Instruction {
+    let config = AccountMeta::new_readonly(org_config_pda(organization).0, false);
+    instruction.accounts.insert(index, config);
+    instruction
+}
+
+pub fn create_audit_log(authority: &Pubkey, organization: &Pubkey) -> Instruction {
+    let (log, bump) = audit_log_pda(organization);
+    instruction(
+        vec![CREATE_AUDIT_LOG, bump],
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*organization, false),
+            AccountMeta::new(log, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM, false),
+        ],
+    )
+}
+
+pub fn close_audit_log(authority: &Pubkey, organization: &Pubkey) -> Instruction {
+    instruction(
+        vec![CLOSE_AUDIT_LOG],
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*organization, false),
+            AccountMeta::new(audit_log_pda(organization).0, false),
+        ],
+    )
+}
+
+/// Discards an unfinalized proposal, passing its results PDA and the
+/// challenger who posted a dispute bond when given.
+pub fn discard_proposal(
+    authority: &Pubkey,
+    organization: &Pubkey,
+    proposal: &Pubkey,
+    results: Option<&Pubkey>,
+    challenger: Option<&Pubkey>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*authority, true),
+        AccountMeta::new(*organization, false),
+        AccountMeta::new(*proposal, false),
+    ];
+    accounts.extend(results.map(|results| AccountMeta::new(*results, false)));
+    accounts.extend(challenger.map(|challenger| AccountMeta::new(*challenger, false)));
+    instruction(vec![DISCARD_PROPOSAL], accounts)
+}
+
+/// Appends the organization's audit log PDA, which privileged instructions
+/// expect last once the organization has one.
+pub fn with_audit_log(mut instruction: Instruction, organization: &Pubkey) -> Instruction {
+    let log = AccountMeta::new(audit_log_pda(organization).0, false);
+    instruction.accounts.push(log);
+    instruction
+}
@@ -0,0 +1,272 @@
+//! Mollusk harness for running the compiled program without a validator.
+//!
+//! Mollusk loads `fan_engagement_log.so` from `SBF_OUT_DIR` (or
+//! `target/deploy`), so the test files using `Harness` are gated on the
+//! `test-sbf` feature. Run them with `cargo test-sbf`, or with
+//! `cargo test --features test-sbf` after `cargo build-sbf`.
+//!
+//! `Harness` keeps an account store: `process` feeds an instruction the stored
+//! accounts it references and writes the resulting accounts back on success,
+//! so multi-step flows read like a sequence of transactions.
+//!
+//! `fixtures` and `ix` do not run the program, so tests that only need
+//! account states or instruction bytes can use them without `test-sbf`.
+
+#![allow(dead_code)]
+
+pub mod fixtures;
+pub mod ix;
+
+use std::collections::HashMap;
+
+use fan_engagement_log::{
+    error::GovernanceError,
+    state::{AccountState, Organization, Proposal, ProposalStatus},
+};
+use mollusk_svm::{program::keyed_account_for_system_program, result::InstructionResult, Mollusk};
+use pinocchio::Address;
+use solana_account::Account;
+use solana_instruction::{error::InstructionError, Instruction};
+use solana_pubkey::Pubkey;
+
+pub const PROGRAM_ID: Pubkey = Pubkey::new_from_array([42u8; 32]);
+
+/// Cluster time at the start of every test.
+pub const NOW: i64 = 1_750_000_000;
+pub const SLOT: u64 = 300_000_000;
+
+const FUNDED_LAMPORTS: u64 = 100_000_000_000;
+
+pub fn address(key: &Pubkey) -> Address {
+    Address::new_from_array(key.to_bytes())
+}
+
+pub fn pubkey(address: &Address) -> Pubkey {
+    Pubkey::new_from_array(address.to_bytes())
+}
+
+pub struct Harness {
+    pub mollusk: Mollusk,
+    accounts: HashMap<Pubkey, Account>,
+}
+
+impl Harness {
+    pub fn new() -> Self {
+        let mut mollusk = Mollusk::new(&PROGRAM_ID, "fan_engagement_log");
+        mollusk.sysvars.clock.unix_timestamp = NOW;
+        mollusk.sysvars.clock.slot = SLOT;
+
+        let (system_program, system_account) = keyed_account_for_system_program();
+        let mut accounts = HashMap::new();
+        accounts.insert(system_program, system_account);
+        Self { mollusk, accounts }
+    }
+
+    /// Moves the cluster clock to `unix_timestamp`.
+    pub fn warp_to(&mut self, unix_timestamp: i64) {
+        self.mollusk.sysvars.clock.unix_timestamp = unix_timestamp;
+    }
+
+    /// Moves the cluster clock to `slot`.
+    pub fn warp_to_slot(&mut self, slot: u64) {
+        self.mollusk.sysvars.clock.slot = slot;
+    }
+
+    pub fn now(&self) -> i64 {
+        self.mollusk.sysvars.clock.unix_timestamp
+    }
+
+    /// Moves the clock forward to `proposal`'s `start_at`, so it can open.
+    /// Never moves it back.
+    pub fn warp_to_voting_start(&mut self, proposal: &Pubkey) {
+        let start_at = self.state::<Proposal>(proposal).start_at;
+        self.warp_to(self.now().max(start_at));
+    }
+
+    /// Moves the clock forward to `proposal`'s `end_at`, so it can close.
+    /// Never moves it back.
+    pub fn warp_to_voting_end(&mut self, proposal: &Pubkey) {
+        let end_at = self.state::<Proposal>(proposal).end_at;
+        self.warp_to(self.now().max(end_at));
+    }
+
+    /// Returns a new system account with enough lamports to pay rent.
+    pub fn funded_key(&mut self) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.accounts
+            .insert(key, Account::new(FUNDED_LAMPORTS, 0, &Pubkey::default()));
+        key
+    }
+
+    pub fn set_account(&mut self, key: Pubkey, account: Account) {
+        self.accounts.insert(key, account);
+    }
+
+    /// Writes `state` directly into a rent-exempt, program-owned account.
+    pub fn set_state<T: AccountState>(&mut self, key: Pubkey, state: &T) {
+        let mut data = vec![0u8; T::LEN];
+        state.pack(&mut data).unwrap();
+        let account = Account {
+            lamports: self.mollusk.sysvars.rent.minimum_balance(T::LEN),
+            data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.accounts.insert(key, account);
+    }
+
+    pub fn account(&self, key: &Pubkey) -> Option<&Account> {
+        self.accounts.get(key)
+    }
+
+    /// Decodes the stored state of a program account.
+    pub fn state<T: AccountState>(&self, key: &Pubkey) -> T {
+        let account = self.account(key).expect("account not in store");
+        T::unpack(&account.data).expect("account does not hold this state")
+    }
+
+    /// Runs `instruction` against the stored accounts, keeping the resulting
+    /// accounts if it succeeds. Accounts not in the store start empty.
+    pub fn process(&mut self, instruction: &Instruction) -> InstructionResult {
+        let accounts: Vec<(Pubkey, Account)> = instruction
+            .accounts
+            .iter()
+            .map(|meta| {
+                let account = self.accounts.get(&meta.pubkey).cloned().unwrap_or_default();
+                (meta.pubkey, account)
+            })
+            .collect();
+
+        let result = self.mollusk.process_instruction(instruction, &accounts);
+        if result.raw_result.is_ok() {
+            for (key, account) in &result.resulting_accounts {
+                self.accounts.insert(*key, account.clone());
+            }
+        }
+        result
+    }
+
+    /// Runs `instruction` and panics unless it succeeds.
+    pub fn run(&mut self, instruction: &Instruction) -> InstructionResult {
+        let result = self.process(instruction);
+        assert_eq!(result.raw_result, Ok(()), "instruction failed");
+        result
+    }
+
+    /// Runs `instruction` and asserts that it fails with `error`.
+    pub fn expect_error(&mut self, instruction: &Instruction, error: GovernanceError) {
+        let result = self.process(instruction);
+        assert_eq!(
+            result.raw_result,
+            Err(InstructionError::Custom(error.code())),
+            "expected {error}"
+        );
+    }
+
+    /// Runs `instruction` and asserts that it fails with a built-in error.
+    pub fn expect_instruction_error(&mut self, instruction: &Instruction, error: InstructionError) {
+        let result = self.process(instruction);
+        assert_eq!(result.raw_result, Err(error));
+    }
+}
+
+impl Default for Harness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An organization created through `create_organization`, with the keys
+/// that control it.
+pub struct Org {
+    pub id: [u8; 16],
+    pub address: Pubkey,
+    pub authority: Pubkey,
+    pub recovery_authority: Pubkey,
+}
+
+impl Harness {
+    pub fn create_org(&mut self, id: u8) -> Org {
+        let authority = self.funded_key();
+        let recovery_authority = self.funded_key();
+        let instruction =
+            ix::create_organization(&authority, [id; 16], &recovery_authority, "Club");
+        self.run(&instruction);
+        Org {
+            id: [id; 16],
+            address: ix::organization_pda([id; 16]).0,
+            authority,
+            recovery_authority,
+        }
+    }
+
+    /// Creates a draft proposal with default parameters and returns its address.
+    pub fn create_proposal(&mut self, org: &Org, id: u8) -> Pubkey {
+        let params = ix::ProposalParams::new(id, self.now());
+        self.run(&ix::create_proposal(
+            &org.authority,
+            &org.address,
+            &params,
+            None,
+        ));
+        ix::proposal_pda(&org.address, params.proposal_id).0
+    }
+
+    /// Warps to the proposal's `start_at` and opens it.
+    pub fn open_proposal(&mut self, org: &Org, proposal: &Pubkey) {
+        self.warp_to_voting_start(proposal);
+        self.set_status(org, proposal, ProposalStatus::Open);
+    }
+
+    /// Warps to the proposal's `end_at` and closes it.
+    pub fn close_proposal(&mut self, org: &Org, proposal: &Pubkey) {
+        self.warp_to_voting_end(proposal);
+        self.set_status(org, proposal, ProposalStatus::Closed);
+    }
+
+    fn set_status(&mut self, org: &Org, proposal: &Pubkey, status: ProposalStatus) {
+        let nonce = self.nonce(org);
+        self.run(&ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            proposal,
+            nonce,
+            status,
+            &[],
+        ));
+    }
+
+    /// Takes a draft proposal through open, close with 420 votes committed,
+    /// and finalize, passing `extra` after the results PDA. Warps the clock
+    /// through the voting window on the way. Returns the results PDA.
+    pub fn finalize_proposal(&mut self, org: &Org, proposal: &Pubkey, extra: &[Pubkey]) -> Pubkey {
+        self.open_proposal(org, proposal);
+        self.close_proposal(org, proposal);
+        let nonce = self.nonce(org);
+        self.run(&ix::commit_vote_results(
+            &org.authority,
+            &org.address,
+            proposal,
+            nonce,
+            420,
+        ));
+        let results = ix::results_pda(proposal).0;
+        let accounts: Vec<_> = [results].into_iter().chain(extra.iter().copied()).collect();
+        let nonce = self.nonce(org);
+        self.run(&ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            proposal,
+            nonce,
+            ProposalStatus::Finalized,
+            &accounts,
+        ));
+        results
+    }
+
+    /// The organization's next operation nonce.
+    pub fn nonce(&self, org: &Org) -> u64 {
+        self.state::<Organization>(&org.address).operation_nonce
+    }
+}
@@ -0,0 +1,186 @@
+//! Many organizations with interleaved proposals. Instructions for different
+//! organizations must write disjoint accounts, so the runtime can schedule
+//! them in parallel, and must never change another organization's state.
+
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use std::collections::HashSet;
+
+use common::{ix, Harness, Org, NOW};
+use fan_engagement_log::state::{Organization, Proposal, ProposalStatus};
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+const ORGANIZATIONS: u8 = 6;
+const PROPOSALS_PER_ORG: u8 = 5;
+
+fn writable_accounts(instruction: &Instruction) -> HashSet<Pubkey> {
+    instruction
+        .accounts
+        .iter()
+        .filter(|meta| meta.is_writable)
+        .map(|meta| meta.pubkey)
+        .collect()
+}
+
+/// Runs `instruction` and asserts it left every account outside its own
+/// writable set untouched.
+fn run_isolated(harness: &mut Harness, instruction: &Instruction, watched: &[Pubkey]) {
+    let writable = writable_accounts(instruction);
+    let before: Vec<_> = watched
+        .iter()
+        .filter(|key| !writable.contains(key))
+        .map(|key| (*key, harness.account(key).cloned()))
+        .collect();
+
+    harness.run(instruction);
+
+    for (key, account) in before {
+        assert_eq!(harness.account(&key).cloned(), account, "{key} changed");
+    }
+}
+
+fn create_orgs(harness: &mut Harness) -> Vec<Org> {
+    (1..=ORGANIZATIONS)
+        .map(|id| harness.create_org(id))
+        .collect()
+}
+
+#[test]
+fn interleaved_proposals_are_counted_per_organization() {
+    let mut harness = Harness::new();
+    let orgs = create_orgs(&mut harness);
+    let watched: Vec<Pubkey> = orgs.iter().map(|org| org.address).collect();
+
+    // Round-robin across organizations, reusing the same proposal ids in each.
+    for id in 0..PROPOSALS_PER_ORG {
+        for org in &orgs {
+            let params = ix::ProposalParams::new(id, NOW);
+            let instruction = ix::create_proposal(&org.authority, &org.address, &params, None);
+            run_isolated(&mut harness, &instruction, &watched);
+        }
+    }
+
+    let mut addresses = HashSet::new();
+    for org in &orgs {
+        assert_eq!(
+            harness.state::<Organization>(&org.address).proposal_count,
+            PROPOSALS_PER_ORG as u64
+        );
+        for id in 0..PROPOSALS_PER_ORG {
+            let (proposal, _) = ix::proposal_pda(&org.address, [id; 16]);
+            assert!(addresses.insert(proposal), "duplicate PDA {proposal}");
+            assert_eq!(
+                common::pubkey(&harness.state::<Proposal>(&proposal).organization),
+                org.address
+            );
+        }
+    }
+}
+
+#[test]
+fn instructions_for_different_organizations_write_disjoint_accounts() {
+    let mut harness = Harness::new();
+    let orgs = create_orgs(&mut harness);
+    let proposals: Vec<Pubkey> = orgs
+        .iter()
+        .map(|org| harness.create_proposal(org, 1))
+        .collect();
+
+    let batch: Vec<Instruction> = orgs
+        .iter()
+        .zip(&proposals)
+        .map(|(org, proposal)| {
+            ix::update_proposal_status(
+                &org.authority,
+                &org.address,
+                proposal,
+                0,
+                ProposalStatus::Open,
+                &[],
+            )
+        })
+        .collect();
+
+    let mut written = HashSet::new();
+    for instruction in &batch {
+        for key in writable_accounts(instruction) {
+            assert!(written.insert(key), "{key} is written by two organizations");
+        }
+    }
+
+    // Any execution order gives the same result when write sets are disjoint.
+    harness.warp_to_voting_start(&proposals[0]);
+    for instruction in batch.iter().rev() {
+        harness.run(instruction);
+    }
+    for (org, proposal) in orgs.iter().zip(&proposals) {
+        assert_eq!(
+            harness.state::<Proposal>(proposal).status,
+            ProposalStatus::Open
+        );
+        assert_eq!(harness.nonce(org), 1);
+    }
+}
+
+#[test]
+fn operation_nonces_advance_independently() {
+    let mut harness = Harness::new();
+    let orgs = create_orgs(&mut harness);
+    let watched: Vec<Pubkey> = orgs.iter().map(|org| org.address).collect();
+    let proposals: Vec<Pubkey> = orgs
+        .iter()
+        .map(|org| harness.create_proposal(org, 1))
+        .collect();
+
+    // Each organization moves a different number of steps through the
+    // lifecycle; the others' nonces must not move. Every proposal shares the
+    // same voting window, so the clock returns to each step's time.
+    let params = ix::ProposalParams::new(1, NOW);
+    for (steps, (org, proposal)) in orgs.iter().zip(&proposals).enumerate() {
+        let statuses = [
+            (ProposalStatus::Open, params.start_at),
+            (ProposalStatus::Closed, params.end_at),
+        ];
+        for (nonce, (status, at)) in statuses.into_iter().take(steps % 3).enumerate() {
+            harness.warp_to(at);
+            let instruction = ix::update_proposal_status(
+                &org.authority,
+                &org.address,
+                proposal,
+                nonce as u64,
+                status,
+                &[],
+            );
+            run_isolated(&mut harness, &instruction, &watched);
+        }
+    }
+
+    for (steps, org) in orgs.iter().enumerate() {
+        assert_eq!(harness.nonce(org), (steps % 3) as u64);
+    }
+}
+
+#[test]
+fn batches_for_different_organizations_do_not_interfere() {
+    let mut harness = Harness::new();
+    let orgs = create_orgs(&mut harness);
+    let watched: Vec<Pubkey> = orgs.iter().map(|org| org.address).collect();
+
+    for org in &orgs {
+        let params: Vec<_> = (0..PROPOSALS_PER_ORG)
+            .map(|id| ix::ProposalParams::new(id, NOW))
+            .collect();
+        let instruction = ix::create_proposals_batch(&org.authority, &org.address, &params, None);
+        run_isolated(&mut harness, &instruction, &watched);
+    }
+
+    for org in &orgs {
+        assert_eq!(
+            harness.state::<Organization>(&org.address).proposal_count,
+            PROPOSALS_PER_ORG as u64
+        );
+    }
+}
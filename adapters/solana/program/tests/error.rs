@@ -0,0 +1,141 @@
+use fan_engagement_log::error::GovernanceError;
+use pinocchio::error::ProgramError;
+
+/// Lists the variants once for both an exhaustive `match`, which stops
+/// compiling when a variant is added to the enum but not here, and
+/// `DECLARED`, which `all_lists_every_variant` compares against `ALL`.
+macro_rules! declared_variants {
+    ($($variant:ident),* $(,)?) => {
+        const DECLARED: &[GovernanceError] = &[$(GovernanceError::$variant),*];
+
+        #[allow(dead_code)]
+        fn exhaustive(error: GovernanceError) {
+            match error {
+                $(GovernanceError::$variant => {})*
+            }
+        }
+    };
+}
+
+declared_variants!(
+    InvalidInstruction,
+    InvalidPda,
+    InvalidAccountOwner,
+    InvalidAccountData,
+    AccountAlreadyInitialized,
+    Unauthorized,
+    InvalidName,
+    InvalidTitle,
+    InvalidVotingWindow,
+    QuorumExceedsEligiblePower,
+    InvalidStatusTransition,
+    ProposalNotClosed,
+    ResultsNotCommitted,
+    OrganizationMismatch,
+    ProposalMismatch,
+    ArithmeticOverflow,
+    ProposalOutsideSeason,
+    SeasonMismatch,
+    StaleOperationNonce,
+    AdapterSignerLimitReached,
+    AdapterSignerAlreadyRegistered,
+    AdapterSignerNotFound,
+    RecoveryNotConfigured,
+    RecoveryAlreadyInitiated,
+    RecoveryNotInitiated,
+    RecoveryTimelockActive,
+    InvalidAuthority,
+    InvalidSnapshotSlot,
+    VotingPowerLocked,
+    ZeroEligibleVotingPower,
+    VotesExceedEligiblePower,
+    MissingStartDate,
+    EndBeforeNow,
+    OrganizationFrozen,
+    OrganizationNotFrozen,
+    OrganizationNotEmpty,
+    CloseDelayActive,
+    ProposalNotFinalized,
+    VotingNotStarted,
+    VotingEnded,
+    VotingNotEnded,
+    VotingWindowTooShort,
+    VotingWindowTooLong,
+    OrgStatsRequired,
+    ProposalNotDraft,
+    ProposalNotOpen,
+    VotingPeriodAlreadyExtended,
+    InvalidVotingExtension,
+    VotingExtensionTooLong,
+    InvalidDisputeWindow,
+    DisputeWindowOpen,
+    DisputeWindowClosed,
+    ResultsDisputed,
+    ChallengerMismatch,
+    InvalidResultsPreimage,
+    ResultsHashMismatch,
+    UnsupportedHashScheme,
+    OrgConfigRequired,
+    InvalidOrgConfig,
+    AuditLogRequired,
+    OrganizationClosed,
+    ProposalFinalized,
+    ProposalArchived,
+);
+
+#[test]
+fn codes_are_contiguous_from_6000() {
+    for (offset, error) in GovernanceError::ALL.into_iter().enumerate() {
+        assert_eq!(error.code(), 6000 + offset as u32);
+        assert_eq!(GovernanceError::from_code(error.code()), Some(error));
+    }
+    assert_eq!(GovernanceError::from_code(5999), None);
+    assert_eq!(
+        GovernanceError::from_code(6000 + GovernanceError::ALL.len() as u32),
+        None
+    );
+}
+
+#[test]
+fn all_lists_every_variant() {
+    assert_eq!(GovernanceError::ALL.as_slice(), DECLARED);
+}
+
+#[test]
+fn decodes_program_errors() {
+    let error: ProgramError = GovernanceError::StaleOperationNonce.into();
+    assert_eq!(
+        GovernanceError::from_program_error(&error),
+        Some(GovernanceError::StaleOperationNonce)
+    );
+    assert_eq!(
+        GovernanceError::from_program_error(&ProgramError::MissingRequiredSignature),
+        None
+    );
+}
+
+#[test]
+fn decodes_custom_error_from_logs() {
+    let logs = [
+        "Program FanGov111 invoke [1]",
+        "Program log: FE_GOV: commit_vote_results v0.1.0",
+        "Program FanGov111 consumed 4120 of 200000 compute units",
+        "Program FanGov111 failed: custom program error: 0x1771",
+    ];
+    assert_eq!(
+        GovernanceError::from_logs(&logs),
+        Some(GovernanceError::InvalidPda)
+    );
+
+    let unrelated = ["Program 11111111111111111111111111111111 failed: custom program error: 0x1"];
+    assert_eq!(GovernanceError::from_logs(&unrelated), None);
+    assert_eq!(GovernanceError::from_logs::<&str>(&[]), None);
+}
+
+#[test]
+fn display_includes_code_and_message() {
+    assert_eq!(
+        GovernanceError::Unauthorized.to_string(),
+        "6005 (Unauthorized): signer is not allowed to perform this operation"
+    );
+}
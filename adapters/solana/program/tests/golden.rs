@@ -0,0 +1,209 @@
+//! Golden fixtures: committed account bytes for every layout version,
+//! decoded field by field. A refactor that moves, resizes, or re-encodes a
+//! field breaks these even when round-trip tests still pass.
+//!
+//! The program has no deployment yet, so a layout change replaces the
+//! fixture: bump its `_v<N>` suffix and delete the old file. Once accounts
+//! exist on-chain, old fixtures stay next to the new ones and must keep
+//! decoding, which needs a versioned reader in `state`.
+//!
+//! Each fixture describes a state the program can actually reach.
+
+use fan_engagement_log::{
+    instruction::{ADD_ADAPTER_SIGNER, CREATE_AUDIT_LOG, UPDATE_PROPOSAL_STATUS},
+    state::{
+        AccountState, AuditLog, ClosedOrganization, HashScheme, OrgConfig, OrgStats, Organization,
+        Proposal, ProposalResults, ProposalStatus, ProposalSummary, Season,
+    },
+};
+use pinocchio::Address;
+
+const ORGANIZATION_V7: &[u8] = include_bytes!("fixtures/organization_v7.bin");
+const PROPOSAL_V7: &[u8] = include_bytes!("fixtures/proposal_v7.bin");
+const PROPOSAL_RESULTS_V4: &[u8] = include_bytes!("fixtures/proposal_results_v4.bin");
+const SEASON_V1: &[u8] = include_bytes!("fixtures/season_v1.bin");
+const PROPOSAL_SUMMARY_V4: &[u8] = include_bytes!("fixtures/proposal_summary_v4.bin");
+const ORG_STATS_V1: &[u8] = include_bytes!("fixtures/org_stats_v1.bin");
+const ORG_CONFIG_V1: &[u8] = include_bytes!("fixtures/org_config_v1.bin");
+const AUDIT_LOG_V1: &[u8] = include_bytes!("fixtures/audit_log_v1.bin");
+const CLOSED_ORGANIZATION_V1: &[u8] = include_bytes!("fixtures/closed_organization_v1.bin");
+
+fn address(byte: u8) -> Address {
+    Address::new_from_array([byte; 32])
+}
+
+/// Decodes `fixture` and checks that encoding it again reproduces the bytes.
+fn decode<T: AccountState>(fixture: &[u8]) -> T {
+    let state = T::unpack(fixture).unwrap();
+    let mut data = vec![0u8; T::LEN];
+    state.pack(&mut data).unwrap();
+    assert_eq!(data, fixture);
+    state
+}
+
+#[test]
+fn organization_v7() {
+    let org: Organization = decode(ORGANIZATION_V7);
+    assert_eq!(org.bump, 254);
+    assert_eq!(org.organization_id, [0x11; 16]);
+    assert_eq!(org.authority, address(0x21));
+    assert_eq!(org.proposal_count, 7);
+    assert_eq!(org.archived_proposal_count, 5);
+    assert_eq!(org.season_count, 3);
+    assert!(org.has_stats);
+    assert!(org.has_config);
+    assert!(org.has_audit_log);
+    assert_eq!(org.operation_nonce, 42);
+    assert_eq!(org.adapter_signers(), &[address(0x31), address(0x32)]);
+    assert_eq!(org.recovery_authority, address(0x41));
+    assert_eq!(org.pending_authority, Address::default());
+    assert_eq!(org.recovery_initiated_at, 0);
+    assert_eq!(org.created_at, 1_750_000_000);
+    assert_eq!(org.updated_at, 1_770_000_000);
+    assert_eq!(org.frozen_at, 1_770_000_000);
+    assert_eq!(org.dispute_window_seconds, 172_800);
+    assert_eq!(org.dispute_bond_lamports, 50_000_000);
+    assert_eq!(org.name(), b"Harbor City");
+}
+
+#[test]
+fn proposal_v7() {
+    let proposal: Proposal = decode(PROPOSAL_V7);
+    assert_eq!(proposal.bump, 253);
+    assert_eq!(proposal.status, ProposalStatus::Closed);
+    assert_eq!(proposal.organization, address(0x51));
+    assert_eq!(proposal.proposal_id, [0x52; 16]);
+    assert_eq!(proposal.created_by, address(0x53));
+    assert_eq!(proposal.season, address(0x54));
+    assert_eq!(proposal.results, address(0x57));
+    assert_eq!(proposal.content_hash, [0x55; 32]);
+    assert_eq!(proposal.content_hash_scheme, HashScheme::Sha256);
+    assert_eq!(proposal.start_at, 1_750_000_060);
+    assert_eq!(proposal.end_at, 1_750_086_400);
+    assert_eq!(proposal.original_end_at, 1_750_050_000);
+    assert_eq!(proposal.quorum_requirement, 2_500);
+    assert_eq!(proposal.eligible_voting_power, 10_000);
+    assert_eq!(proposal.snapshot_slot, 300_000_000);
+    assert_eq!(proposal.voter_merkle_root, [0x56; 32]);
+    assert_eq!(proposal.created_at, 1_750_000_000);
+    assert_eq!(proposal.updated_at, 1_750_086_500);
+    assert_eq!(proposal.opened_at, 1_750_000_060);
+    assert_eq!(proposal.closed_at, 1_750_086_400);
+    assert_eq!(proposal.amendment_count, 2);
+    let opened = Proposal::chain_history_hash(&[0; 32], ProposalStatus::Open, 1_750_000_060);
+    assert_eq!(
+        proposal.history_hash,
+        Proposal::chain_history_hash(&opened, ProposalStatus::Closed, 1_750_086_400)
+    );
+    assert_eq!(proposal.title(), b"Home kit 2027?");
+}
+
+#[test]
+fn proposal_results_v4() {
+    let results: ProposalResults = decode(PROPOSAL_RESULTS_V4);
+    assert_eq!(results.bump, 252);
+    assert_eq!(results.proposal, address(0x61));
+    assert_eq!(results.results_hash, [0x62; 32]);
+    assert_eq!(results.results_hash_scheme, HashScheme::Sha256);
+    assert_eq!(results.winning_option_id, [0x63; 16]);
+    assert_eq!(results.total_votes_cast, 7_321);
+    assert!(results.quorum_met);
+    assert_eq!(results.committed_at, 1_750_090_000);
+    assert_eq!(results.finalized_at, 1_750_176_400);
+    assert_eq!(results.dispute_ends_at, 1_750_176_400);
+    assert_eq!(results.disputed_by, address(0x64));
+    assert_eq!(results.disputed_at, 1_750_100_000);
+    assert_eq!(results.dispute_bond, 25_000_000);
+    assert!(results.hash_verified);
+}
+
+#[test]
+fn season_v1() {
+    let season: Season = decode(SEASON_V1);
+    assert_eq!(season.bump, 251);
+    assert_eq!(season.organization, address(0x71));
+    assert_eq!(season.season_id, [0x72; 16]);
+    assert_eq!(season.start_at, 1_740_000_000);
+    assert_eq!(season.end_at, 1_770_000_000);
+    assert_eq!(season.proposal_count, 12);
+    assert_eq!(season.finalized_proposal_count, 9);
+    assert_eq!(season.total_votes_cast, 45_000);
+    assert_eq!(season.created_at, 1_739_000_000);
+    assert_eq!(season.updated_at, 1_760_000_000);
+}
+
+#[test]
+fn proposal_summary_v4() {
+    let summary: ProposalSummary = decode(PROPOSAL_SUMMARY_V4);
+    assert_eq!(summary.bump, 250);
+    assert_eq!(summary.organization, address(0x81));
+    assert_eq!(summary.proposal_id, [0x82; 16]);
+    assert_eq!(summary.content_hash, [0x83; 32]);
+    assert_eq!(summary.content_hash_scheme, HashScheme::Sha256);
+    assert_eq!(summary.voter_merkle_root, [0x84; 32]);
+    assert_eq!(summary.results_hash, [0x85; 32]);
+    assert_eq!(summary.results_hash_scheme, HashScheme::Sha256);
+}
+
+#[test]
+fn org_stats_v1() {
+    let stats: OrgStats = decode(ORG_STATS_V1);
+    assert_eq!(stats.bump, 249);
+    assert_eq!(stats.organization, address(0x91));
+    assert_eq!(stats.quorum_met_count, 41);
+    assert_eq!(stats.quorum_failed_count, 9);
+    assert_eq!(stats.total_votes_cast, 120_000);
+    assert_eq!(stats.turnout_bps_sum, 310_000);
+    assert_eq!(stats.created_at, 1_740_000_000);
+    assert_eq!(stats.updated_at, 1_770_000_000);
+    assert_eq!(stats.average_turnout_bps(), 6_200);
+}
+
+#[test]
+fn org_config_v1() {
+    let config: OrgConfig = decode(ORG_CONFIG_V1);
+    assert_eq!(config.bump, 248);
+    assert_eq!(config.organization, address(0xA1));
+    assert_eq!(config.default_quorum_requirement, 500);
+    assert_eq!(config.default_voting_duration_seconds, 259_200);
+    assert_eq!(config.min_voting_duration_seconds, 86_400);
+    assert_eq!(config.max_voting_duration_seconds, 1_209_600);
+    assert_eq!(config.created_at, 1_745_000_000);
+    assert_eq!(config.updated_at, 1_760_000_000);
+    assert_eq!(config.validate(), Ok(()));
+}
+
+#[test]
+fn audit_log_v1() {
+    let log: AuditLog = decode(AUDIT_LOG_V1);
+    assert_eq!(log.bump, 247);
+    assert_eq!(log.organization, address(0xB1));
+    assert_eq!(log.entry_count, 3);
+    assert_eq!(log.created_at, 1_760_000_000);
+
+    let entries: Vec<_> = log.recent_entries().collect();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].actor, address(0xB2));
+    assert_eq!(entries[0].instruction, CREATE_AUDIT_LOG);
+    assert_eq!(entries[0].target, address(0xB3));
+    assert_eq!(entries[0].timestamp, 1_760_000_000);
+    assert_eq!(entries[1].actor, address(0xB2));
+    assert_eq!(entries[1].instruction, ADD_ADAPTER_SIGNER);
+    assert_eq!(entries[1].target, address(0xB4));
+    assert_eq!(entries[1].timestamp, 1_760_000_600);
+    assert_eq!(entries[2].actor, address(0xB5));
+    assert_eq!(entries[2].instruction, UPDATE_PROPOSAL_STATUS);
+    assert_eq!(entries[2].target, address(0xB6));
+    assert_eq!(entries[2].timestamp, 1_760_003_600);
+    assert_eq!(log.entries[3], Default::default());
+}
+
+#[test]
+fn closed_organization_v1() {
+    let closed: ClosedOrganization = decode(CLOSED_ORGANIZATION_V1);
+    assert_eq!(closed.bump, 253);
+    assert_eq!(closed.organization_id, [0xC1; 16]);
+    assert_eq!(closed.closed_by, address(0xC2));
+    assert_eq!(closed.created_at, 1_750_000_000);
+    assert_eq!(closed.closed_at, 1_770_086_400);
+}
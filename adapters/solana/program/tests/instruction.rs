@@ -0,0 +1,601 @@
+use fan_engagement_log::{
+    error::GovernanceError,
+    instruction::{
+        is_governance_instruction, CommitVoteResultsArgs, CreateProposalArgs,
+        GovernanceInstruction, OrgConfigArgs, UpdateProposalDraftArgs, ADD_ADAPTER_SIGNER,
+        ARCHIVE_PROPOSAL, CANCEL_RECOVERY, CLOSE_AND_COMMIT_RESULTS, CLOSE_AUDIT_LOG,
+        CLOSE_ORGANIZATION, CLOSE_ORG_CONFIG, CLOSE_ORG_STATS, CLOSE_SEASON, COMMIT_VOTE_RESULTS,
+        COMPLETE_RECOVERY, CREATE_AUDIT_LOG, CREATE_ORGANIZATION, CREATE_ORG_STATS,
+        CREATE_PROPOSAL, CREATE_PROPOSALS_BATCH, CREATE_SEASON, DISCARD_PROPOSAL, DISPUTE_RESULTS,
+        EXTEND_VOTING_PERIOD, FINALIZE_PROPOSAL, FREEZE_ORGANIZATION, GOVERNANCE_PREFIX,
+        INITIATE_RECOVERY, LOG_VERSION, MAX_BATCH_PROPOSALS, OPEN_PROPOSAL_WITH_SNAPSHOT,
+        REMOVE_ADAPTER_SIGNER, SET_DISPUTE_POLICY, SET_ELIGIBLE_VOTING_POWER, SET_ORG_CONFIG,
+        UPDATE_PROPOSAL_DRAFT, UPDATE_PROPOSAL_STATUS, USE_DEFAULT_END_AT, USE_DEFAULT_QUORUM,
+        VERIFY_RESULTS_HASH,
+    },
+    state::{HashScheme, ProposalStatus},
+    PROGRAM_VERSION,
+};
+use pinocchio::Address;
+
+#[test]
+fn only_prefixed_data_is_a_governance_instruction() {
+    // No UTF-8 text starts with the prefix, so no log payload is ever decoded
+    // as a governance instruction, whatever its first character.
+    assert!(core::str::from_utf8(&[GOVERNANCE_PREFIX]).is_err());
+    for byte in (0..=u8::MAX).filter(|&byte| byte != GOVERNANCE_PREFIX) {
+        assert!(!is_governance_instruction(&[byte, CREATE_ORGANIZATION]));
+    }
+    for payload in [
+        &b"{}"[..],
+        b" {}",
+        b"\t{}",
+        b"\n{}",
+        b"\r\n{}",
+        "\u{feff}{}".as_bytes(),
+    ] {
+        assert!(!is_governance_instruction(payload));
+    }
+    assert!(!is_governance_instruction(&[]));
+    assert!(is_governance_instruction(&[GOVERNANCE_PREFIX, LOG_VERSION]));
+}
+
+#[test]
+fn rejects_unknown_tags_and_missing_prefix() {
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, DISCARD_PROPOSAL + 1]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[LOG_VERSION]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_create_organization() {
+    let mut data = vec![GOVERNANCE_PREFIX, CREATE_ORGANIZATION];
+    data.extend_from_slice(&[7u8; 16]);
+    data.push(254);
+    data.extend_from_slice(&[8u8; 32]);
+    data.push(3);
+    data.extend_from_slice(b"FCB");
+
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::CreateOrganization {
+            organization_id: [7u8; 16],
+            bump: 254,
+            recovery_authority: Address::new_from_array([8u8; 32]),
+            name: b"FCB",
+        }
+    );
+}
+
+fn proposal_payload(id: u8, title: &[u8]) -> Vec<u8> {
+    let mut data = vec![id; 16];
+    data.push(200);
+    data.extend_from_slice(&[9u8; 32]);
+    data.push(HashScheme::Sha256 as u8);
+    data.extend_from_slice(&100i64.to_le_bytes());
+    data.extend_from_slice(&200i64.to_le_bytes());
+    data.extend_from_slice(&50u64.to_le_bytes());
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.push(title.len() as u8);
+    data.extend_from_slice(title);
+    data
+}
+
+#[test]
+fn unpacks_create_proposal() {
+    let mut data = vec![GOVERNANCE_PREFIX, CREATE_PROPOSAL];
+    data.extend_from_slice(&proposal_payload(1, b"Kit 1"));
+
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::CreateProposal(CreateProposalArgs {
+            proposal_id: [1u8; 16],
+            bump: 200,
+            content_hash: [9u8; 32],
+            content_hash_scheme: HashScheme::Sha256,
+            start_at: 100,
+            end_at: 200,
+            quorum_requirement: 50,
+            eligible_voting_power: 1_000,
+            title: b"Kit 1",
+        })
+    );
+}
+
+#[test]
+fn unpacks_create_proposals_batch() {
+    let mut data = vec![GOVERNANCE_PREFIX, CREATE_PROPOSALS_BATCH, 2];
+    data.extend_from_slice(&proposal_payload(1, b"Kit 1"));
+    data.extend_from_slice(&proposal_payload(2, b"Man of the match"));
+
+    let GovernanceInstruction::CreateProposalsBatch(batch) =
+        GovernanceInstruction::unpack(&data).unwrap()
+    else {
+        panic!("expected a proposal batch");
+    };
+    assert_eq!(batch.len(), 2);
+    let proposals: Vec<_> = batch.iter().collect();
+    assert_eq!(proposals.len(), 2);
+    assert_eq!(proposals[0].proposal_id, [1u8; 16]);
+    assert_eq!(proposals[0].title, b"Kit 1");
+    assert_eq!(proposals[1].proposal_id, [2u8; 16]);
+    assert_eq!(proposals[1].title, b"Man of the match");
+    assert_eq!(proposals[1].eligible_voting_power, 1_000);
+
+    // The count must match the payloads that follow.
+    data[2] = 3;
+    assert_eq!(
+        GovernanceInstruction::unpack(&data),
+        Err(GovernanceError::InvalidInstruction)
+    );
+    data[2] = 1;
+    assert_eq!(
+        GovernanceInstruction::unpack(&data),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn rejects_empty_and_oversized_batches() {
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CREATE_PROPOSALS_BATCH, 0]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+
+    let count = MAX_BATCH_PROPOSALS + 1;
+    let mut data = vec![GOVERNANCE_PREFIX, CREATE_PROPOSALS_BATCH, count as u8];
+    for id in 0..count {
+        data.extend_from_slice(&proposal_payload(id as u8, b"Poll"));
+    }
+    assert_eq!(
+        GovernanceInstruction::unpack(&data),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_update_proposal_draft() {
+    let mut data = vec![GOVERNANCE_PREFIX, UPDATE_PROPOSAL_DRAFT];
+    data.extend_from_slice(&[6u8; 32]);
+    data.push(HashScheme::Keccak256 as u8);
+    data.extend_from_slice(&300i64.to_le_bytes());
+    data.extend_from_slice(&4_000i64.to_le_bytes());
+    data.extend_from_slice(&75u64.to_le_bytes());
+    data.push(6);
+    data.extend_from_slice(b"Kit 2?");
+
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::UpdateProposalDraft(UpdateProposalDraftArgs {
+            content_hash: [6u8; 32],
+            content_hash_scheme: HashScheme::Keccak256,
+            start_at: 300,
+            end_at: 4_000,
+            quorum_requirement: 75,
+            title: b"Kit 2?",
+        })
+    );
+
+    data.pop();
+    assert_eq!(
+        GovernanceInstruction::unpack(&data),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_extend_voting_period() {
+    let mut data = vec![GOVERNANCE_PREFIX, EXTEND_VOTING_PERIOD];
+    data.extend_from_slice(&7_200i64.to_le_bytes());
+
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::ExtendVotingPeriod { end_at: 7_200 }
+    );
+
+    data.pop();
+    assert_eq!(
+        GovernanceInstruction::unpack(&data),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_update_proposal_status() {
+    let mut data = vec![GOVERNANCE_PREFIX, UPDATE_PROPOSAL_STATUS];
+    data.extend_from_slice(&7u64.to_le_bytes());
+    data.push(2);
+
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::UpdateProposalStatus {
+            operation_nonce: 7,
+            status: ProposalStatus::Closed
+        }
+    );
+
+    *data.last_mut().unwrap() = 9;
+    assert_eq!(
+        GovernanceInstruction::unpack(&data),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_open_proposal_with_snapshot() {
+    let mut data = vec![GOVERNANCE_PREFIX, OPEN_PROPOSAL_WITH_SNAPSHOT];
+    data.extend_from_slice(&8u64.to_le_bytes());
+    data.extend_from_slice(&250_000_000u64.to_le_bytes());
+    data.extend_from_slice(&[3u8; 32]);
+    data.extend_from_slice(&5_000u64.to_le_bytes());
+
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::OpenProposalWithSnapshot {
+            operation_nonce: 8,
+            snapshot_slot: 250_000_000,
+            voter_merkle_root: [3u8; 32],
+            eligible_voting_power: 5_000,
+        }
+    );
+}
+
+#[test]
+fn unpacks_set_eligible_voting_power() {
+    let mut data = vec![GOVERNANCE_PREFIX, SET_ELIGIBLE_VOTING_POWER];
+    data.extend_from_slice(&4u64.to_le_bytes());
+    data.extend_from_slice(&7_500u64.to_le_bytes());
+
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::SetEligibleVotingPower {
+            operation_nonce: 4,
+            eligible_voting_power: 7_500,
+        }
+    );
+}
+
+#[test]
+fn unpacks_commit_vote_results() {
+    let mut data = vec![GOVERNANCE_PREFIX, COMMIT_VOTE_RESULTS];
+    data.extend_from_slice(&3u64.to_le_bytes());
+    data.push(251);
+    data.extend_from_slice(&[4u8; 32]);
+    data.push(HashScheme::Blake3 as u8);
+    data.extend_from_slice(&[5u8; 16]);
+    data.extend_from_slice(&42u64.to_le_bytes());
+    data.push(1);
+
+    let args = CommitVoteResultsArgs {
+        operation_nonce: 3,
+        bump: 251,
+        results_hash: [4u8; 32],
+        results_hash_scheme: HashScheme::Blake3,
+        winning_option_id: [5u8; 16],
+        total_votes_cast: 42,
+        quorum_met: true,
+    };
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::CommitVoteResults(args.clone())
+    );
+
+    data[1] = CLOSE_AND_COMMIT_RESULTS;
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::CloseAndCommitResults(args)
+    );
+
+    // Unknown schemes fail to decode; known but unsupported ones are left
+    // to the processor.
+    data[2 + 8 + 1 + 32] = 3;
+    assert_eq!(
+        GovernanceInstruction::unpack(&data),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_create_season() {
+    let mut data = vec![GOVERNANCE_PREFIX, CREATE_SEASON];
+    data.extend_from_slice(&[2u8; 16]);
+    data.push(249);
+    data.extend_from_slice(&1_000i64.to_le_bytes());
+    data.extend_from_slice(&2_000i64.to_le_bytes());
+
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::CreateSeason {
+            season_id: [2u8; 16],
+            bump: 249,
+            start_at: 1_000,
+            end_at: 2_000,
+        }
+    );
+}
+
+#[test]
+fn unpacks_adapter_signer_changes() {
+    let mut data = vec![GOVERNANCE_PREFIX, ADD_ADAPTER_SIGNER];
+    data.extend_from_slice(&[6u8; 32]);
+
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::AddAdapterSigner {
+            signer: Address::new_from_array([6u8; 32]),
+        }
+    );
+
+    data[1] = REMOVE_ADAPTER_SIGNER;
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::RemoveAdapterSigner {
+            signer: Address::new_from_array([6u8; 32]),
+        }
+    );
+}
+
+#[test]
+fn unpacks_recovery_instructions() {
+    let mut data = vec![GOVERNANCE_PREFIX, INITIATE_RECOVERY];
+    data.extend_from_slice(&[9u8; 32]);
+
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::InitiateRecovery {
+            new_authority: Address::new_from_array([9u8; 32]),
+        }
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CANCEL_RECOVERY]).unwrap(),
+        GovernanceInstruction::CancelRecovery
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, COMPLETE_RECOVERY]).unwrap(),
+        GovernanceInstruction::CompleteRecovery
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CANCEL_RECOVERY, 0]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_log_version() {
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, LOG_VERSION]).unwrap(),
+        GovernanceInstruction::LogVersion
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, LOG_VERSION, 0]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_freeze_organization() {
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, FREEZE_ORGANIZATION]).unwrap(),
+        GovernanceInstruction::FreezeOrganization
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, FREEZE_ORGANIZATION, 0]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_close_organization() {
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CLOSE_ORGANIZATION]).unwrap(),
+        GovernanceInstruction::CloseOrganization
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CLOSE_ORGANIZATION, 0]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_archive_proposal() {
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, ARCHIVE_PROPOSAL]).unwrap(),
+        GovernanceInstruction::ArchiveProposal
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, ARCHIVE_PROPOSAL, 253]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_create_org_stats() {
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CREATE_ORG_STATS, 252]).unwrap(),
+        GovernanceInstruction::CreateOrgStats { bump: 252 }
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CREATE_ORG_STATS, 252, 0]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_close_season_and_close_org_stats() {
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CLOSE_SEASON]).unwrap(),
+        GovernanceInstruction::CloseSeason
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CLOSE_ORG_STATS]).unwrap(),
+        GovernanceInstruction::CloseOrgStats
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CLOSE_SEASON, 0]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CLOSE_ORG_STATS, 0]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_dispute_instructions() {
+    let mut data = vec![GOVERNANCE_PREFIX, SET_DISPUTE_POLICY];
+    data.extend_from_slice(&86_400i64.to_le_bytes());
+    data.extend_from_slice(&5_000u64.to_le_bytes());
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::SetDisputePolicy {
+            window_seconds: 86_400,
+            bond_lamports: 5_000,
+        }
+    );
+    data.pop();
+    assert_eq!(
+        GovernanceInstruction::unpack(&data),
+        Err(GovernanceError::InvalidInstruction)
+    );
+
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, DISPUTE_RESULTS]).unwrap(),
+        GovernanceInstruction::DisputeResults
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, FINALIZE_PROPOSAL]).unwrap(),
+        GovernanceInstruction::FinalizeProposal
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, FINALIZE_PROPOSAL, 0]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn unpacks_verify_results_hash_with_the_rest_as_preimage() {
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, VERIFY_RESULTS_HASH, 1, 2, 3]).unwrap(),
+        GovernanceInstruction::VerifyResultsHash {
+            preimage: &[1, 2, 3]
+        }
+    );
+    // The preimage may live entirely in chunk accounts.
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, VERIFY_RESULTS_HASH]).unwrap(),
+        GovernanceInstruction::VerifyResultsHash { preimage: &[] }
+    );
+}
+
+#[test]
+fn unpacks_org_config_instructions() {
+    let mut data = vec![GOVERNANCE_PREFIX, SET_ORG_CONFIG, 247];
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(&259_200i64.to_le_bytes());
+    data.extend_from_slice(&86_400i64.to_le_bytes());
+    data.extend_from_slice(&1_209_600i64.to_le_bytes());
+    assert_eq!(
+        GovernanceInstruction::unpack(&data).unwrap(),
+        GovernanceInstruction::SetOrgConfig(OrgConfigArgs {
+            bump: 247,
+            default_quorum_requirement: 100,
+            default_voting_duration_seconds: 259_200,
+            min_voting_duration_seconds: 86_400,
+            max_voting_duration_seconds: 1_209_600,
+        })
+    );
+    data.pop();
+    assert_eq!(
+        GovernanceInstruction::unpack(&data),
+        Err(GovernanceError::InvalidInstruction)
+    );
+
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CLOSE_ORG_CONFIG]).unwrap(),
+        GovernanceInstruction::CloseOrgConfig
+    );
+}
+
+#[test]
+fn unpacks_audit_log_instructions() {
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CREATE_AUDIT_LOG, 246]).unwrap(),
+        GovernanceInstruction::CreateAuditLog { bump: 246 }
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CREATE_AUDIT_LOG]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CLOSE_AUDIT_LOG]).unwrap(),
+        GovernanceInstruction::CloseAuditLog
+    );
+}
+
+#[test]
+fn unpacks_discard_proposal() {
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, DISCARD_PROPOSAL]).unwrap(),
+        GovernanceInstruction::DiscardProposal
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, DISCARD_PROPOSAL, 0]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
+
+#[test]
+fn default_sentinels_are_invalid_values_of_their_own() {
+    // A proposal cannot end at the epoch or need more votes than exist.
+    assert_eq!(USE_DEFAULT_END_AT, 0);
+    assert_eq!(USE_DEFAULT_QUORUM, u64::MAX);
+}
+
+#[test]
+fn program_version_is_semver() {
+    let parts: Vec<_> = PROGRAM_VERSION.split('.').collect();
+    assert_eq!(parts.len(), 3);
+    assert!(parts.iter().all(|part| part.parse::<u32>().is_ok()));
+}
+
+#[test]
+fn rejects_truncated_and_trailing_data() {
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, CREATE_ORGANIZATION, 1, 2]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[GOVERNANCE_PREFIX, UPDATE_PROPOSAL_STATUS, 1]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[
+            GOVERNANCE_PREFIX,
+            UPDATE_PROPOSAL_STATUS,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            1,
+            0
+        ]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+    assert_eq!(
+        GovernanceInstruction::unpack(&[]),
+        Err(GovernanceError::InvalidInstruction)
+    );
+}
@@ -0,0 +1,254 @@
+//! Snapshot of the instruction interface: for every governance instruction,
+//! the account order with signer/writable flags, the encoded payload of a
+//! fixed example, and what the program decodes it to. The program has no
+//! generated IDL, so this file plays that role; any change to a tag, account
+//! order, payload layout, or decoder shows up as a diff against
+//! `tests/snapshots/interface.txt`.
+//!
+//! After a deliberate interface change, regenerate the snapshot with
+//! `UPDATE_SNAPSHOTS=1 cargo test --test interface` and commit it.
+
+mod common;
+
+use std::{env, fs, path::PathBuf};
+
+use common::{ix, NOW, SLOT};
+use fan_engagement_log::{instruction::GovernanceInstruction, state::ProposalStatus};
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::new_from_array([byte; 32])
+}
+
+fn render(out: &mut String, name: &str, instruction: &Instruction, roles: &[(Pubkey, &str)]) {
+    let decoded = GovernanceInstruction::unpack(&instruction.data)
+        .unwrap_or_else(|error| panic!("{name} does not decode: {error}"));
+
+    out.push_str(name);
+    out.push('\n');
+    for meta in &instruction.accounts {
+        let (_, role) = roles
+            .iter()
+            .find(|(key, _)| *key == meta.pubkey)
+            .unwrap_or_else(|| panic!("{name} uses an unlabelled account"));
+        out.push_str("  account ");
+        out.push_str(role);
+        if meta.is_signer {
+            out.push_str(" signer");
+        }
+        if meta.is_writable {
+            out.push_str(" writable");
+        }
+        out.push('\n');
+    }
+    out.push_str("  data ");
+    for byte in &instruction.data {
+        out.push_str(&format!("{byte:02x}"));
+    }
+    out.push('\n');
+    out.push_str(&format!("  decoded {decoded:?}\n"));
+}
+
+fn interface() -> String {
+    let authority = key(1);
+    let recovery_authority = key(2);
+    let adapter = key(3);
+    let new_authority = key(4);
+    let (organization, _) = ix::organization_pda([7u8; 16]);
+    let (proposal, _) = ix::proposal_pda(&organization, [8u8; 16]);
+    let (results, _) = ix::results_pda(&proposal);
+    let (season, _) = ix::season_pda(&organization, [9u8; 16]);
+    let (stats, _) = ix::org_stats_pda(&organization);
+    let (config, _) = ix::org_config_pda(&organization);
+    let (log, _) = ix::audit_log_pda(&organization);
+    let roles = [
+        (authority, "authority"),
+        (recovery_authority, "recovery_authority"),
+        (adapter, "adapter_signer"),
+        (organization, "organization"),
+        (proposal, "proposal"),
+        (results, "proposal_results"),
+        (season, "season"),
+        (stats, "org_stats"),
+        (config, "org_config"),
+        (log, "audit_log"),
+        (Pubkey::default(), "system_program"),
+    ];
+    let params = ix::ProposalParams::new(8, NOW);
+
+    let instructions = [
+        (
+            "create_organization",
+            ix::create_organization(&authority, [7u8; 16], &recovery_authority, "Club"),
+        ),
+        (
+            "create_proposal",
+            ix::create_proposal(&authority, &organization, &params, Some(&season)),
+        ),
+        (
+            "update_proposal_status",
+            ix::update_proposal_status(
+                &adapter,
+                &organization,
+                &proposal,
+                5,
+                ProposalStatus::Finalized,
+                &[results, season],
+            ),
+        ),
+        (
+            "commit_vote_results",
+            ix::commit_vote_results(&adapter, &organization, &proposal, 5, 420),
+        ),
+        (
+            "create_season",
+            ix::create_season(&authority, &organization, [9u8; 16], NOW, NOW + 30 * 86_400),
+        ),
+        (
+            "add_adapter_signer",
+            ix::add_adapter_signer(&authority, &organization, &adapter),
+        ),
+        (
+            "remove_adapter_signer",
+            ix::remove_adapter_signer(&authority, &organization, &adapter),
+        ),
+        (
+            "initiate_recovery",
+            ix::initiate_recovery(&recovery_authority, &organization, &new_authority),
+        ),
+        (
+            "cancel_recovery",
+            ix::cancel_recovery(&authority, &organization),
+        ),
+        (
+            "complete_recovery",
+            ix::complete_recovery(&recovery_authority, &organization),
+        ),
+        (
+            "create_proposals_batch",
+            ix::create_proposals_batch(
+                &authority,
+                &organization,
+                std::slice::from_ref(&params),
+                Some(&season),
+            ),
+        ),
+        (
+            "open_proposal_with_snapshot",
+            ix::open_proposal_with_snapshot(&adapter, &organization, &proposal, 5, SLOT, 5_000),
+        ),
+        (
+            "close_and_commit_results",
+            ix::close_and_commit_results(&adapter, &organization, &proposal, 5, 420),
+        ),
+        (
+            "set_eligible_voting_power",
+            ix::set_eligible_voting_power(&adapter, &organization, &proposal, 5, 5_000),
+        ),
+        ("log_version", ix::log_version()),
+        (
+            "freeze_organization",
+            ix::freeze_organization(&authority, &organization),
+        ),
+        (
+            "close_organization",
+            ix::close_organization(&authority, &organization),
+        ),
+        (
+            "archive_proposal",
+            ix::archive_proposal(&authority, &organization, &proposal),
+        ),
+        (
+            "create_org_stats",
+            ix::create_org_stats(&authority, &organization),
+        ),
+        (
+            "close_season",
+            ix::close_season(&authority, &organization, &season),
+        ),
+        (
+            "close_org_stats",
+            ix::close_org_stats(&authority, &organization),
+        ),
+        (
+            "update_proposal_draft",
+            ix::update_proposal_draft(&authority, &organization, &proposal, &params, Some(&season)),
+        ),
+        (
+            "extend_voting_period",
+            ix::extend_voting_period(
+                &authority,
+                &organization,
+                &proposal,
+                params.end_at + 3_600,
+                Some(&season),
+            ),
+        ),
+        (
+            "set_dispute_policy",
+            ix::set_dispute_policy(&authority, &organization, 86_400, 1_000_000),
+        ),
+        (
+            "dispute_results",
+            ix::dispute_results(&authority, &organization, &proposal),
+        ),
+        (
+            "finalize_proposal",
+            ix::finalize_proposal(&organization, &proposal, &[season]),
+        ),
+        (
+            "verify_results_hash",
+            ix::verify_results_hash(&proposal, &[1; 37], &[season]),
+        ),
+        (
+            "set_org_config",
+            ix::set_org_config(
+                &authority,
+                &organization,
+                100,
+                3 * 86_400,
+                86_400,
+                14 * 86_400,
+            ),
+        ),
+        (
+            "close_org_config",
+            ix::close_org_config(&authority, &organization),
+        ),
+        (
+            "create_audit_log",
+            ix::create_audit_log(&authority, &organization),
+        ),
+        (
+            "close_audit_log",
+            ix::close_audit_log(&authority, &organization),
+        ),
+        (
+            "discard_proposal",
+            ix::discard_proposal(&authority, &organization, &proposal, Some(&results), None),
+        ),
+    ];
+
+    let mut out = String::new();
+    for (name, instruction) in &instructions {
+        render(&mut out, name, instruction, &roles);
+    }
+    out
+}
+
+#[test]
+fn interface_matches_snapshot() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots/interface.txt");
+    let actual = interface();
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap();
+    assert_eq!(
+        actual, expected,
+        "instruction interface changed; if intended, rerun with UPDATE_SNAPSHOTS=1"
+    );
+}
@@ -0,0 +1,133 @@
+use fan_engagement_log::{
+    error::GovernanceError,
+    math::{
+        apply_bps, apply_bps_ceil, checked_add, checked_add_i64, checked_mul, decrement, increment,
+        isqrt, ratio_bps, saturating_mul, BPS_DENOMINATOR,
+    },
+};
+
+#[test]
+fn checked_ops_fail_only_on_overflow() {
+    assert_eq!(checked_add(u64::MAX - 1, 1), Ok(u64::MAX));
+    assert_eq!(
+        checked_add(u64::MAX, 1),
+        Err(GovernanceError::ArithmeticOverflow)
+    );
+    assert_eq!(checked_add(0, 0), Ok(0));
+
+    assert_eq!(checked_mul(u64::MAX, 1), Ok(u64::MAX));
+    assert_eq!(checked_mul(u64::MAX, 0), Ok(0));
+    assert_eq!(checked_mul(1 << 32, 1 << 31), Ok(1 << 63));
+    assert_eq!(
+        checked_mul(1 << 32, 1 << 32),
+        Err(GovernanceError::ArithmeticOverflow)
+    );
+
+    assert_eq!(checked_add_i64(i64::MAX - 1, 1), Ok(i64::MAX));
+    assert_eq!(
+        checked_add_i64(i64::MAX, 1),
+        Err(GovernanceError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        checked_add_i64(i64::MIN, -1),
+        Err(GovernanceError::ArithmeticOverflow)
+    );
+}
+
+#[test]
+fn increment_leaves_counter_untouched_on_overflow() {
+    let mut counter = u64::MAX - 1;
+    increment(&mut counter).unwrap();
+    assert_eq!(counter, u64::MAX);
+    assert_eq!(
+        increment(&mut counter),
+        Err(GovernanceError::ArithmeticOverflow)
+    );
+    assert_eq!(counter, u64::MAX);
+}
+
+#[test]
+fn decrement_leaves_counter_untouched_on_underflow() {
+    let mut counter = 1;
+    decrement(&mut counter).unwrap();
+    assert_eq!(counter, 0);
+    assert_eq!(
+        decrement(&mut counter),
+        Err(GovernanceError::ArithmeticOverflow)
+    );
+    assert_eq!(counter, 0);
+
+    let mut counter = u64::MAX;
+    decrement(&mut counter).unwrap();
+    assert_eq!(counter, u64::MAX - 1);
+}
+
+#[test]
+fn saturating_mul_clamps_at_max() {
+    assert_eq!(saturating_mul(u64::MAX, 2), u64::MAX);
+    assert_eq!(saturating_mul(1 << 32, 1 << 32), u64::MAX);
+    assert_eq!(saturating_mul(3, 4), 12);
+    assert_eq!(saturating_mul(u64::MAX, 0), 0);
+}
+
+#[test]
+fn apply_bps_rounds_down_and_up() {
+    assert_eq!(apply_bps(3, 5_000), Ok(1));
+    assert_eq!(apply_bps_ceil(3, 5_000), Ok(2));
+    assert_eq!(apply_bps(10_000, 1), Ok(1));
+    assert_eq!(apply_bps_ceil(10_000, 1), Ok(1));
+    assert_eq!(apply_bps(9_999, 1), Ok(0));
+    assert_eq!(apply_bps_ceil(9_999, 1), Ok(1));
+    assert_eq!(apply_bps(0, BPS_DENOMINATOR), Ok(0));
+    assert_eq!(apply_bps(1_000, 0), Ok(0));
+    assert_eq!(apply_bps_ceil(1_000, 0), Ok(0));
+}
+
+#[test]
+fn apply_bps_handles_extremes() {
+    assert_eq!(apply_bps(u64::MAX, BPS_DENOMINATOR), Ok(u64::MAX));
+    assert_eq!(apply_bps_ceil(u64::MAX, BPS_DENOMINATOR), Ok(u64::MAX));
+    assert_eq!(apply_bps(u64::MAX, 5_000), Ok(u64::MAX / 2));
+    assert_eq!(apply_bps_ceil(u64::MAX, 5_000), Ok(u64::MAX / 2 + 1));
+    assert_eq!(
+        apply_bps(u64::MAX, BPS_DENOMINATOR + 1),
+        Err(GovernanceError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        apply_bps_ceil(u64::MAX, BPS_DENOMINATOR + 1),
+        Err(GovernanceError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        apply_bps(u64::MAX / 6, u16::MAX),
+        Err(GovernanceError::ArithmeticOverflow)
+    );
+    assert_eq!(apply_bps(100, u16::MAX), Ok(655));
+    assert_eq!(apply_bps_ceil(100, u16::MAX), Ok(656));
+}
+
+#[test]
+fn isqrt_is_exact_at_boundaries() {
+    for value in 0..=1_000u64 {
+        let root = isqrt(value);
+        assert!(root * root <= value);
+        assert!((root + 1) * (root + 1) > value);
+    }
+    for root in [1u64, 2, 255, 65_535, 1 << 31, u32::MAX as u64] {
+        assert_eq!(isqrt(root * root), root);
+        assert_eq!(isqrt(root * root - 1), root - 1);
+    }
+    assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    assert_eq!(isqrt(1 << 62), 1 << 31);
+    assert_eq!(isqrt(1 << 63), 3_037_000_499);
+}
+
+#[test]
+fn ratio_bps_rounds_down_and_handles_zero_whole() {
+    assert_eq!(ratio_bps(420, 1_000), 4_200);
+    assert_eq!(ratio_bps(1, 3), 3_333);
+    assert_eq!(ratio_bps(1_000, 1_000), BPS_DENOMINATOR as u64);
+    assert_eq!(ratio_bps(0, 0), 0);
+    assert_eq!(ratio_bps(5, 0), 0);
+    assert_eq!(ratio_bps(u64::MAX, u64::MAX), 10_000);
+    assert_eq!(ratio_bps(u64::MAX, 1), u64::MAX);
+}
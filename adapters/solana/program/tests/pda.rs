@@ -0,0 +1,91 @@
+use fan_engagement_log::pda::{
+    find_audit_log_pda, find_org_config_pda, find_org_stats_pda, find_organization_pda,
+    find_proposal_pda, find_results_pda, find_season_pda, AUDIT_LOG_SEED, ORGANIZATION_SEED,
+    ORG_CONFIG_SEED, ORG_STATS_SEED, PROPOSAL_SEED, RESULTS_SEED, SEASON_SEED,
+};
+use pinocchio::Address;
+
+const PROGRAM_ID: Address = Address::new_from_array([42u8; 32]);
+
+#[test]
+fn seed_strings_are_stable() {
+    // The first three are the archived Anchor program's seed strings.
+    assert_eq!(ORGANIZATION_SEED, b"organization");
+    assert_eq!(PROPOSAL_SEED, b"proposal");
+    assert_eq!(RESULTS_SEED, b"proposal_results");
+    assert_eq!(SEASON_SEED, b"season");
+    assert_eq!(ORG_STATS_SEED, b"org_stats");
+    assert_eq!(ORG_CONFIG_SEED, b"org_config");
+    assert_eq!(AUDIT_LOG_SEED, b"audit_log");
+}
+
+#[test]
+fn find_helpers_return_bumps_accepted_by_the_program() {
+    let (organization, bump) = find_organization_pda(&[1u8; 16], &PROGRAM_ID);
+    assert_eq!(
+        Address::create_program_address(&[ORGANIZATION_SEED, &[1u8; 16], &[bump]], &PROGRAM_ID),
+        Ok(organization)
+    );
+
+    let (proposal, bump) = find_proposal_pda(&organization, &[2u8; 16], &PROGRAM_ID);
+    assert_eq!(
+        Address::create_program_address(
+            &[PROPOSAL_SEED, organization.as_ref(), &[2u8; 16], &[bump]],
+            &PROGRAM_ID
+        ),
+        Ok(proposal)
+    );
+
+    let (results, bump) = find_results_pda(&proposal, &PROGRAM_ID);
+    assert_eq!(
+        Address::create_program_address(&[RESULTS_SEED, proposal.as_ref(), &[bump]], &PROGRAM_ID),
+        Ok(results)
+    );
+
+    let (season, bump) = find_season_pda(&organization, &[3u8; 16], &PROGRAM_ID);
+    assert_eq!(
+        Address::create_program_address(
+            &[SEASON_SEED, organization.as_ref(), &[3u8; 16], &[bump]],
+            &PROGRAM_ID
+        ),
+        Ok(season)
+    );
+
+    let (stats, bump) = find_org_stats_pda(&organization, &PROGRAM_ID);
+    assert_eq!(
+        Address::create_program_address(
+            &[ORG_STATS_SEED, organization.as_ref(), &[bump]],
+            &PROGRAM_ID
+        ),
+        Ok(stats)
+    );
+
+    let (config, bump) = find_org_config_pda(&organization, &PROGRAM_ID);
+    assert_eq!(
+        Address::create_program_address(
+            &[ORG_CONFIG_SEED, organization.as_ref(), &[bump]],
+            &PROGRAM_ID
+        ),
+        Ok(config)
+    );
+
+    let (log, bump) = find_audit_log_pda(&organization, &PROGRAM_ID);
+    assert_eq!(
+        Address::create_program_address(
+            &[AUDIT_LOG_SEED, organization.as_ref(), &[bump]],
+            &PROGRAM_ID
+        ),
+        Ok(log)
+    );
+}
+
+#[test]
+fn proposal_addresses_are_scoped_to_their_organization() {
+    let (first, _) = find_organization_pda(&[1u8; 16], &PROGRAM_ID);
+    let (second, _) = find_organization_pda(&[2u8; 16], &PROGRAM_ID);
+
+    assert_ne!(
+        find_proposal_pda(&first, &[7u8; 16], &PROGRAM_ID).0,
+        find_proposal_pda(&second, &[7u8; 16], &PROGRAM_ID).0
+    );
+}
@@ -0,0 +1,1632 @@
+//! Per-instruction tests against the compiled program. See `common` for how
+//! to run them.
+
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::{address, ix, pubkey, Harness, Org, NOW, SLOT};
+use fan_engagement_log::{
+    error::GovernanceError,
+    instruction::{
+        ADD_ADAPTER_SIGNER, CREATE_AUDIT_LOG, CREATE_PROPOSAL, CREATE_SEASON, FREEZE_ORGANIZATION,
+        UPDATE_PROPOSAL_STATUS, USE_DEFAULT_END_AT, USE_DEFAULT_QUORUM,
+    },
+    results::{canonical_results_bytes, hash_results_bytes, OptionTally, VoteResults},
+    state::{
+        AccountState, AuditLog, ClosedOrganization, HashScheme, OrgConfig, OrgStats, Organization,
+        Proposal, ProposalResults, ProposalStatus, ProposalSummary, Season,
+        MAX_VOTING_DURATION_SECONDS, MIN_VOTING_DURATION_SECONDS, ORGANIZATION_CLOSE_DELAY_SECONDS,
+        RECOVERY_TIMELOCK_SECONDS,
+    },
+};
+use solana_account::Account;
+use solana_instruction::{error::InstructionError, AccountMeta};
+use solana_pubkey::Pubkey;
+use solana_rent::Rent;
+
+#[test]
+fn log_requires_signed_utf8_payload() {
+    let mut harness = Harness::new();
+    let signer = harness.funded_key();
+
+    harness.run(&ix::log(&signer, br#"{"action":"vote"}"#));
+    // Leading whitespace and control characters used to collide with
+    // governance tags; every UTF-8 payload now reaches the log.
+    for payload in [&b"\t{}"[..], b"\n{}", b"\r\n{}", b"\x00", b"\x14"] {
+        harness.run(&ix::log(&signer, payload));
+    }
+
+    let mut unsigned = ix::log(&signer, br#"{"action":"vote"}"#);
+    unsigned.accounts[0] = AccountMeta::new_readonly(signer, false);
+    harness.expect_instruction_error(&unsigned, InstructionError::MissingRequiredSignature);
+
+    harness.expect_instruction_error(
+        &ix::log(&signer, &[0xff, 0xfe]),
+        InstructionError::InvalidInstructionData,
+    );
+}
+
+#[test]
+fn create_organization_initializes_state() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+
+    let state: Organization = harness.state(&org.address);
+    assert_eq!(common::pubkey(&state.authority), org.authority);
+    assert_eq!(
+        common::pubkey(&state.recovery_authority),
+        org.recovery_authority
+    );
+    assert_eq!(state.name(), b"Club");
+    assert_eq!(state.proposal_count, 0);
+    assert_eq!(state.operation_nonce, 0);
+    assert_eq!(state.created_at, NOW);
+
+    harness.expect_error(
+        &ix::create_organization(&org.authority, org.id, &org.recovery_authority, "Club"),
+        GovernanceError::AccountAlreadyInitialized,
+    );
+}
+
+#[test]
+fn create_organization_tops_up_prefunded_pda() {
+    let mut harness = Harness::new();
+    let authority = harness.funded_key();
+    let recovery_authority = harness.funded_key();
+    let (address, _) = ix::organization_pda([1; 16]);
+    harness.set_account(address, Account::new(1, 0, &Pubkey::default()));
+
+    harness.run(&ix::create_organization(
+        &authority,
+        [1; 16],
+        &recovery_authority,
+        "Club",
+    ));
+
+    let rent = harness
+        .mollusk
+        .sysvars
+        .rent
+        .minimum_balance(Organization::LEN);
+    let account = harness.account(&address).unwrap();
+    assert_eq!(account.owner, common::PROGRAM_ID);
+    assert_eq!(account.lamports, rent);
+    assert_eq!(harness.state::<Organization>(&address).name(), b"Club");
+}
+
+#[test]
+fn create_organization_rejects_pda_with_foreign_owner() {
+    let mut harness = Harness::new();
+    let authority = harness.funded_key();
+    let recovery_authority = harness.funded_key();
+    let (address, _) = ix::organization_pda([1; 16]);
+    harness.set_account(address, Account::new(1, 0, &Pubkey::new_unique()));
+
+    harness.expect_error(
+        &ix::create_organization(&authority, [1; 16], &recovery_authority, "Club"),
+        GovernanceError::AccountAlreadyInitialized,
+    );
+}
+
+#[test]
+fn create_proposal_starts_in_draft() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+
+    let state: Proposal = harness.state(&proposal);
+    assert_eq!(state.status, ProposalStatus::Draft);
+    assert_eq!(common::pubkey(&state.organization), org.address);
+    assert_eq!(common::pubkey(&state.created_by), org.authority);
+    assert_eq!(state.title(), b"Kit vote");
+    assert!(!state.has_season());
+    assert_eq!(
+        harness.state::<Organization>(&org.address).proposal_count,
+        1
+    );
+}
+
+#[test]
+fn create_proposal_rejects_voting_windows_out_of_bounds() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+
+    let mut params = ix::ProposalParams::new(2, NOW);
+    params.end_at = params.start_at + MIN_VOTING_DURATION_SECONDS - 1;
+    harness.expect_error(
+        &ix::create_proposal(&org.authority, &org.address, &params, None),
+        GovernanceError::VotingWindowTooShort,
+    );
+
+    params.end_at = params.start_at + MAX_VOTING_DURATION_SECONDS + 1;
+    harness.expect_error(
+        &ix::create_proposal(&org.authority, &org.address, &params, None),
+        GovernanceError::VotingWindowTooLong,
+    );
+
+    params.end_at = params.start_at + MAX_VOTING_DURATION_SECONDS;
+    harness.run(&ix::create_proposal(
+        &org.authority,
+        &org.address,
+        &params,
+        None,
+    ));
+}
+
+#[test]
+fn hashes_must_use_a_supported_scheme() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+
+    let mut params = ix::ProposalParams::new(2, NOW);
+    params.content_hash_scheme = HashScheme::Keccak256;
+    harness.expect_error(
+        &ix::create_proposal(&org.authority, &org.address, &params, None),
+        GovernanceError::UnsupportedHashScheme,
+    );
+
+    let proposal = harness.create_proposal(&org, 2);
+    harness.expect_error(
+        &ix::update_proposal_draft(&org.authority, &org.address, &proposal, &params, None),
+        GovernanceError::UnsupportedHashScheme,
+    );
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).content_hash_scheme,
+        HashScheme::Sha256
+    );
+
+    harness.open_proposal(&org, &proposal);
+    harness.close_proposal(&org, &proposal);
+    let nonce = harness.nonce(&org);
+    let mut commit = ix::commit_vote_results(&org.authority, &org.address, &proposal, nonce, 420);
+    // Prefix, tag, nonce and bump come before the results hash.
+    commit.data[2 + 8 + 1 + 32] = HashScheme::Blake3 as u8;
+    harness.expect_error(&commit, GovernanceError::UnsupportedHashScheme);
+}
+
+#[test]
+fn create_proposals_batch_creates_every_proposal() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let params: Vec<_> = (10..13)
+        .map(|id| ix::ProposalParams::new(id, NOW))
+        .collect();
+
+    harness.run(&ix::create_proposals_batch(
+        &org.authority,
+        &org.address,
+        &params,
+        None,
+    ));
+
+    for params in &params {
+        let (proposal, _) = ix::proposal_pda(&org.address, params.proposal_id);
+        assert_eq!(
+            harness.state::<Proposal>(&proposal).proposal_id,
+            params.proposal_id
+        );
+    }
+    assert_eq!(
+        harness.state::<Organization>(&org.address).proposal_count,
+        3
+    );
+}
+
+#[test]
+fn create_season_counts_its_proposals() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    harness.run(&ix::create_season(
+        &org.authority,
+        &org.address,
+        [5u8; 16],
+        NOW,
+        NOW + 30 * 86_400,
+    ));
+    let (season, _) = ix::season_pda(&org.address, [5u8; 16]);
+
+    let params = ix::ProposalParams::new(2, NOW);
+    harness.run(&ix::create_proposal(
+        &org.authority,
+        &org.address,
+        &params,
+        Some(&season),
+    ));
+
+    let (proposal, _) = ix::proposal_pda(&org.address, params.proposal_id);
+    assert_eq!(
+        common::pubkey(&harness.state::<Proposal>(&proposal).season),
+        season
+    );
+    assert_eq!(harness.state::<Season>(&season).proposal_count, 1);
+}
+
+#[test]
+fn update_and_commit_walk_the_lifecycle() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    let (results, _) = ix::results_pda(&proposal);
+    let params = ix::ProposalParams::new(2, NOW);
+
+    for (nonce, status, at) in [
+        (0, ProposalStatus::Open, params.start_at),
+        (1, ProposalStatus::Closed, params.end_at),
+    ] {
+        harness.warp_to(at);
+        harness.run(&ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &proposal,
+            nonce,
+            status,
+            &[],
+        ));
+        assert_eq!(harness.state::<Proposal>(&proposal).status, status);
+    }
+
+    harness.run(&ix::commit_vote_results(
+        &org.authority,
+        &org.address,
+        &proposal,
+        2,
+        420,
+    ));
+    let committed: ProposalResults = harness.state(&results);
+    assert_eq!(common::pubkey(&committed.proposal), proposal);
+    assert_eq!(
+        common::pubkey(&harness.state::<Proposal>(&proposal).results),
+        results
+    );
+    assert_eq!(committed.total_votes_cast, 420);
+    assert_eq!(committed.finalized_at, 0);
+
+    harness.run(&ix::update_proposal_status(
+        &org.authority,
+        &org.address,
+        &proposal,
+        3,
+        ProposalStatus::Finalized,
+        &[results],
+    ));
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).status,
+        ProposalStatus::Finalized
+    );
+    assert_eq!(
+        harness.state::<ProposalResults>(&results).finalized_at,
+        params.end_at
+    );
+    assert_eq!(harness.nonce(&org), 4);
+}
+
+#[test]
+fn close_and_commit_results_closes_and_commits() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    harness.open_proposal(&org, &proposal);
+
+    harness.warp_to_voting_end(&proposal);
+    harness.run(&ix::close_and_commit_results(
+        &org.authority,
+        &org.address,
+        &proposal,
+        1,
+        300,
+    ));
+
+    let state: Proposal = harness.state(&proposal);
+    assert_eq!(state.status, ProposalStatus::Closed);
+    assert_eq!(state.closed_at, harness.now());
+    let (results, _) = ix::results_pda(&proposal);
+    assert_eq!(
+        harness.state::<ProposalResults>(&results).total_votes_cast,
+        300
+    );
+    assert_eq!(harness.nonce(&org), 2);
+}
+
+#[test]
+fn open_proposal_with_snapshot_records_snapshot() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+
+    harness.warp_to_voting_start(&proposal);
+    harness.run(&ix::open_proposal_with_snapshot(
+        &org.authority,
+        &org.address,
+        &proposal,
+        0,
+        SLOT,
+        5_000,
+    ));
+
+    let state: Proposal = harness.state(&proposal);
+    assert_eq!(state.status, ProposalStatus::Open);
+    assert_eq!(state.opened_at, harness.now());
+    assert_eq!(state.snapshot_slot, SLOT);
+    assert_eq!(state.voter_merkle_root, [3u8; 32]);
+    assert_eq!(state.eligible_voting_power, 5_000);
+}
+
+#[test]
+fn set_eligible_voting_power_overwrites_draft_value() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+
+    harness.run(&ix::set_eligible_voting_power(
+        &org.authority,
+        &org.address,
+        &proposal,
+        0,
+        2_500,
+    ));
+
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).eligible_voting_power,
+        2_500
+    );
+    assert_eq!(harness.nonce(&org), 1);
+}
+
+#[test]
+fn adapter_signers_can_be_added_used_and_removed() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    let adapter = harness.funded_key();
+
+    harness.run(&ix::add_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+    assert!(harness
+        .state::<Organization>(&org.address)
+        .is_adapter_signer(&address(&adapter)));
+
+    harness.warp_to_voting_start(&proposal);
+    harness.run(&ix::update_proposal_status(
+        &adapter,
+        &org.address,
+        &proposal,
+        0,
+        ProposalStatus::Open,
+        &[],
+    ));
+
+    harness.run(&ix::remove_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+    assert_eq!(
+        harness
+            .state::<Organization>(&org.address)
+            .adapter_signers()
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn recovery_installs_new_authority_after_timelock() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let new_authority = harness.funded_key();
+
+    harness.run(&ix::initiate_recovery(
+        &org.recovery_authority,
+        &org.address,
+        &new_authority,
+    ));
+    harness.run(&ix::cancel_recovery(&org.authority, &org.address));
+    assert!(!harness
+        .state::<Organization>(&org.address)
+        .recovery_pending());
+
+    harness.run(&ix::initiate_recovery(
+        &org.recovery_authority,
+        &org.address,
+        &new_authority,
+    ));
+    harness.warp_to(NOW + RECOVERY_TIMELOCK_SECONDS);
+    harness.run(&ix::complete_recovery(
+        &org.recovery_authority,
+        &org.address,
+    ));
+
+    let state: Organization = harness.state(&org.address);
+    assert_eq!(common::pubkey(&state.authority), new_authority);
+    assert!(!state.recovery_pending());
+}
+
+#[test]
+fn log_version_needs_no_accounts() {
+    let mut harness = Harness::new();
+    harness.run(&ix::log_version());
+}
+
+#[test]
+fn frozen_organization_rejects_every_change() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    let adapter = harness.funded_key();
+    harness.run(&ix::add_adapter_signer(
+        &org.authority,
+        &org.address,
+        &adapter,
+    ));
+
+    harness.warp_to(NOW + 60);
+    harness.run(&ix::freeze_organization(&org.authority, &org.address));
+    let state: Organization = harness.state(&org.address);
+    assert_eq!(state.frozen_at, NOW + 60);
+    assert_eq!(state.updated_at, NOW + 60);
+
+    let params = ix::ProposalParams::new(3, NOW);
+    let blocked = [
+        ix::create_proposal(&org.authority, &org.address, &params, None),
+        ix::create_proposals_batch(
+            &org.authority,
+            &org.address,
+            std::slice::from_ref(&params),
+            None,
+        ),
+        ix::create_season(&org.authority, &org.address, [5u8; 16], NOW, NOW + 86_400),
+        ix::update_proposal_status(
+            &adapter,
+            &org.address,
+            &proposal,
+            0,
+            ProposalStatus::Open,
+            &[],
+        ),
+        ix::open_proposal_with_snapshot(&adapter, &org.address, &proposal, 0, SLOT, 5_000),
+        ix::set_eligible_voting_power(&adapter, &org.address, &proposal, 0, 5_000),
+        ix::commit_vote_results(&adapter, &org.address, &proposal, 0, 10),
+        ix::close_and_commit_results(&adapter, &org.address, &proposal, 0, 10),
+        ix::add_adapter_signer(&org.authority, &org.address, &org.recovery_authority),
+        ix::remove_adapter_signer(&org.authority, &org.address, &adapter),
+        ix::initiate_recovery(&org.recovery_authority, &org.address, &adapter),
+        ix::freeze_organization(&org.authority, &org.address),
+    ];
+    for instruction in &blocked {
+        harness.expect_error(instruction, GovernanceError::OrganizationFrozen);
+    }
+
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).status,
+        ProposalStatus::Draft
+    );
+    assert_eq!(harness.state::<Organization>(&org.address), state);
+}
+
+#[test]
+fn close_organization_refunds_rent_after_freeze_delay() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let rent = harness.account(&org.address).unwrap().lamports;
+
+    harness.expect_error(
+        &ix::close_organization(&org.authority, &org.address),
+        GovernanceError::OrganizationNotFrozen,
+    );
+    harness.run(&ix::freeze_organization(&org.authority, &org.address));
+    harness.expect_error(
+        &ix::close_organization(&org.authority, &org.address),
+        GovernanceError::CloseDelayActive,
+    );
+
+    let closed_at = NOW + ORGANIZATION_CLOSE_DELAY_SECONDS;
+    harness.warp_to(closed_at);
+    let frozen: Organization = harness.state(&org.address);
+    let balance = harness.account(&org.authority).unwrap().lamports;
+    harness.run(&ix::close_organization(&org.authority, &org.address));
+
+    assert_eq!(
+        harness.state::<ClosedOrganization>(&org.address),
+        ClosedOrganization::new(&frozen, closed_at)
+    );
+    let marker = harness.account(&org.address).unwrap();
+    assert_eq!(marker.data.len(), ClosedOrganization::LEN);
+    assert_eq!(
+        marker.lamports,
+        Rent::default().minimum_balance(ClosedOrganization::LEN)
+    );
+    assert_eq!(
+        harness.account(&org.authority).unwrap().lamports,
+        balance + rent - marker.lamports
+    );
+
+    // The marker keeps the id taken, so nobody can recreate the organization
+    // and inherit the addresses of its old proposals and seasons.
+    let stranger = harness.funded_key();
+    harness.expect_error(
+        &ix::create_organization(&stranger, org.id, &stranger, "Club"),
+        GovernanceError::OrganizationClosed,
+    );
+    harness.expect_error(
+        &ix::close_organization(&org.authority, &org.address),
+        GovernanceError::InvalidAccountData,
+    );
+}
+
+#[test]
+fn close_organization_requires_every_proposal_archived() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    harness.finalize_proposal(&org, &proposal, &[]);
+    harness.run(&ix::freeze_organization(&org.authority, &org.address));
+    harness.warp_to(harness.now() + ORGANIZATION_CLOSE_DELAY_SECONDS);
+
+    harness.expect_error(
+        &ix::close_organization(&org.authority, &org.address),
+        GovernanceError::OrganizationNotEmpty,
+    );
+
+    // Archiving is still allowed once the organization is frozen.
+    harness.run(&ix::archive_proposal(
+        &org.authority,
+        &org.address,
+        &proposal,
+    ));
+    harness.run(&ix::close_organization(&org.authority, &org.address));
+    assert_eq!(
+        harness.account(&org.address).unwrap().data.len(),
+        ClosedOrganization::LEN
+    );
+}
+
+#[test]
+fn discard_proposal_clears_unfinalized_proposals_of_a_frozen_org() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let draft = harness.create_proposal(&org, 2);
+    let finalized = harness.create_proposal(&org, 3);
+    harness.finalize_proposal(&org, &finalized, &[]);
+    harness.run(&ix::set_dispute_policy(
+        &org.authority,
+        &org.address,
+        86_400,
+        1_000,
+    ));
+    let disputed = harness.create_proposal(&org, 4);
+    let results = commit_results(&mut harness, &org, &disputed);
+    let challenger = harness.funded_key();
+    harness.run(&ix::dispute_results(&challenger, &org.address, &disputed));
+
+    let discard_draft = ix::discard_proposal(&org.authority, &org.address, &draft, None, None);
+    harness.expect_error(&discard_draft, GovernanceError::OrganizationNotFrozen);
+    harness.run(&ix::freeze_organization(&org.authority, &org.address));
+    harness.warp_to(harness.now() + ORGANIZATION_CLOSE_DELAY_SECONDS);
+
+    // Frozen proposals can no longer finalize, so discarding is the only way
+    // to clear them before closing.
+    harness.expect_error(
+        &ix::discard_proposal(&org.authority, &org.address, &finalized, None, None),
+        GovernanceError::ProposalFinalized,
+    );
+    let balance = harness.account(&org.authority).unwrap().lamports;
+    let deposit = harness.account(&draft).unwrap().lamports;
+    harness.run(&discard_draft);
+    assert!(harness.account(&draft).unwrap().data.is_empty());
+    assert_eq!(
+        harness.account(&org.authority).unwrap().lamports,
+        balance + deposit
+    );
+
+    // A challenger's bond goes back to them, not to the authority.
+    harness.expect_error(
+        &ix::discard_proposal(
+            &org.authority,
+            &org.address,
+            &disputed,
+            Some(&results),
+            None,
+        ),
+        GovernanceError::ChallengerMismatch,
+    );
+    let challenger_balance = harness.account(&challenger).unwrap().lamports;
+    harness.run(&ix::discard_proposal(
+        &org.authority,
+        &org.address,
+        &disputed,
+        Some(&results),
+        Some(&challenger),
+    ));
+    assert!(harness.account(&disputed).unwrap().data.is_empty());
+    assert!(harness.account(&results).unwrap().data.is_empty());
+    assert_eq!(
+        harness.account(&challenger).unwrap().lamports,
+        challenger_balance + 1_000
+    );
+
+    harness.run(&ix::archive_proposal(
+        &org.authority,
+        &org.address,
+        &finalized,
+    ));
+    let state: Organization = harness.state(&org.address);
+    assert_eq!(state.archived_proposal_count, state.proposal_count);
+    harness.run(&ix::close_organization(&org.authority, &org.address));
+}
+
+#[test]
+fn close_organization_requires_seasons_and_stats_closed() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    harness.run(&ix::create_season(
+        &org.authority,
+        &org.address,
+        [5u8; 16],
+        NOW,
+        NOW + 30 * 86_400,
+    ));
+    let (season, _) = ix::season_pda(&org.address, [5u8; 16]);
+    harness.run(&ix::create_org_stats(&org.authority, &org.address));
+    let (stats, _) = ix::org_stats_pda(&org.address);
+    let state: Organization = harness.state(&org.address);
+    assert_eq!(state.season_count, 1);
+    assert!(state.has_stats);
+
+    // Seasons and stats only close once nothing can be finalized into them.
+    let close_season = ix::close_season(&org.authority, &org.address, &season);
+    let close_stats = ix::close_org_stats(&org.authority, &org.address);
+    for instruction in [&close_season, &close_stats] {
+        harness.expect_error(instruction, GovernanceError::OrganizationNotFrozen);
+    }
+
+    harness.run(&ix::freeze_organization(&org.authority, &org.address));
+    harness.warp_to(NOW + ORGANIZATION_CLOSE_DELAY_SECONDS);
+    let close = ix::close_organization(&org.authority, &org.address);
+    harness.expect_error(&close, GovernanceError::OrganizationNotEmpty);
+
+    let balance = harness.account(&org.authority).unwrap().lamports;
+    let deposits =
+        harness.account(&season).unwrap().lamports + harness.account(&stats).unwrap().lamports;
+    harness.run(&close_season);
+    harness.expect_error(&close, GovernanceError::OrganizationNotEmpty);
+    harness.run(&close_stats);
+    let state: Organization = harness.state(&org.address);
+    assert_eq!(state.season_count, 0);
+    assert!(!state.has_stats);
+    for closed in [season, stats] {
+        assert!(harness.account(&closed).unwrap().data.is_empty());
+    }
+    assert_eq!(
+        harness.account(&org.authority).unwrap().lamports,
+        balance + deposits
+    );
+
+    harness.run(&close);
+}
+
+#[test]
+fn archive_proposal_shrinks_the_proposal_to_a_summary_in_place() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    let instruction = ix::archive_proposal(&org.authority, &org.address, &proposal);
+    harness.expect_error(&instruction, GovernanceError::ProposalNotFinalized);
+
+    let results = harness.finalize_proposal(&org, &proposal, &[]);
+    let finalized: Proposal = harness.state(&proposal);
+    let committed: ProposalResults = harness.state(&results);
+    let deposits =
+        harness.account(&proposal).unwrap().lamports + harness.account(&results).unwrap().lamports;
+    let balance = harness.account(&org.authority).unwrap().lamports;
+    let archived_at = harness.now() + 3_600;
+    harness.warp_to(archived_at);
+    harness.run(&instruction);
+
+    assert_eq!(
+        harness.state::<ProposalSummary>(&proposal),
+        ProposalSummary::new(&finalized, &committed)
+    );
+    let summary = harness.account(&proposal).unwrap();
+    assert_eq!(summary.data.len(), ProposalSummary::LEN);
+    assert_eq!(
+        summary.lamports,
+        Rent::default().minimum_balance(ProposalSummary::LEN)
+    );
+    let closed = harness.account(&results).unwrap();
+    assert_eq!(closed.lamports, 0);
+    assert!(closed.data.is_empty());
+    assert_eq!(
+        harness.account(&org.authority).unwrap().lamports,
+        balance + deposits - summary.lamports
+    );
+    let state: Organization = harness.state(&org.address);
+    assert_eq!(state.archived_proposal_count, 1);
+    assert_eq!(state.updated_at, archived_at);
+
+    // The summary keeps the address taken, so neither the archive nor the
+    // proposal id can be used again.
+    harness.expect_error(&instruction, GovernanceError::InvalidAccountData);
+    let params = ix::ProposalParams::new(2, harness.now());
+    harness.expect_error(
+        &ix::create_proposal(&org.authority, &org.address, &params, None),
+        GovernanceError::ProposalArchived,
+    );
+    harness.expect_error(
+        &ix::create_proposals_batch(&org.authority, &org.address, &[params], None),
+        GovernanceError::ProposalArchived,
+    );
+}
+
+#[test]
+fn org_stats_count_every_finalization_once_created() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let uncounted = harness.create_proposal(&org, 1);
+    harness.finalize_proposal(&org, &uncounted, &[]);
+    let counted = harness.create_proposal(&org, 2);
+
+    harness.run(&ix::create_org_stats(&org.authority, &org.address));
+    let (stats, _) = ix::org_stats_pda(&org.address);
+    let created: OrgStats = harness.state(&stats);
+    assert_eq!(common::pubkey(&created.organization), org.address);
+    assert_eq!(created.finalized_proposal_count(), 0);
+    assert_eq!(created.created_at, harness.now());
+    harness.expect_error(
+        &ix::create_org_stats(&org.authority, &org.address),
+        GovernanceError::AccountAlreadyInitialized,
+    );
+
+    harness.finalize_proposal(&org, &counted, &[stats]);
+    let finalized_at = harness.now();
+
+    // Leaving the stats account out is no longer possible.
+    let skipped = harness.create_proposal(&org, 3);
+    harness.open_proposal(&org, &skipped);
+    harness.close_proposal(&org, &skipped);
+    let nonce = harness.nonce(&org);
+    harness.run(&ix::commit_vote_results(
+        &org.authority,
+        &org.address,
+        &skipped,
+        nonce,
+        420,
+    ));
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &skipped,
+            nonce + 1,
+            ProposalStatus::Finalized,
+            &[ix::results_pda(&skipped).0],
+        ),
+        GovernanceError::OrgStatsRequired,
+    );
+
+    // 420 of 1_000 eligible, with the quorum met.
+    let state: OrgStats = harness.state(&stats);
+    assert_eq!(state.quorum_met_count, 1);
+    assert_eq!(state.quorum_failed_count, 0);
+    assert_eq!(state.total_votes_cast, 420);
+    assert_eq!(state.average_turnout_bps(), 4_200);
+    assert_eq!(state.updated_at, finalized_at);
+}
+
+#[test]
+fn update_proposal_draft_amends_until_the_proposal_opens() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+
+    let mut params = ix::ProposalParams::new(2, NOW);
+    params.start_at = NOW + 3_600;
+    params.end_at = NOW + 2 * 86_400;
+    params.quorum_requirement = 250;
+    params.title = "Away kit?";
+    harness.warp_to(NOW + 10);
+    harness.run(&ix::update_proposal_draft(
+        &org.authority,
+        &org.address,
+        &proposal,
+        &params,
+        None,
+    ));
+
+    let state: Proposal = harness.state(&proposal);
+    assert_eq!(state.status, ProposalStatus::Draft);
+    assert_eq!(state.content_hash, [10u8; 32]);
+    assert_eq!(state.start_at, NOW + 3_600);
+    assert_eq!(state.end_at, NOW + 2 * 86_400);
+    assert_eq!(state.quorum_requirement, 250);
+    assert_eq!(state.title(), b"Away kit?");
+    assert_eq!(state.amendment_count, 1);
+    assert_eq!(state.created_at, NOW);
+    assert_eq!(state.updated_at, NOW + 10);
+
+    // Amendments go through the same checks as creation.
+    let mut short = params.clone();
+    short.end_at = short.start_at + MIN_VOTING_DURATION_SECONDS - 1;
+    let mut unreachable = params.clone();
+    unreachable.quorum_requirement = 1_001;
+    let mut untitled = params.clone();
+    untitled.title = "";
+    for (params, error) in [
+        (short, GovernanceError::VotingWindowTooShort),
+        (unreachable, GovernanceError::QuorumExceedsEligiblePower),
+        (untitled, GovernanceError::InvalidTitle),
+    ] {
+        harness.expect_error(
+            &ix::update_proposal_draft(&org.authority, &org.address, &proposal, &params, None),
+            error,
+        );
+    }
+
+    harness.run(&ix::update_proposal_draft(
+        &org.authority,
+        &org.address,
+        &proposal,
+        &params,
+        None,
+    ));
+    assert_eq!(harness.state::<Proposal>(&proposal).amendment_count, 2);
+
+    harness.open_proposal(&org, &proposal);
+    harness.expect_error(
+        &ix::update_proposal_draft(&org.authority, &org.address, &proposal, &params, None),
+        GovernanceError::ProposalNotDraft,
+    );
+}
+
+#[test]
+fn update_proposal_draft_keeps_the_window_inside_its_season() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    harness.run(&ix::create_season(
+        &org.authority,
+        &org.address,
+        [5u8; 16],
+        NOW,
+        NOW + 3 * 86_400,
+    ));
+    let (season, _) = ix::season_pda(&org.address, [5u8; 16]);
+    let mut params = ix::ProposalParams::new(2, NOW);
+    harness.run(&ix::create_proposal(
+        &org.authority,
+        &org.address,
+        &params,
+        Some(&season),
+    ));
+    let (proposal, _) = ix::proposal_pda(&org.address, params.proposal_id);
+
+    params.end_at = NOW + 4 * 86_400;
+    harness.expect_error(
+        &ix::update_proposal_draft(
+            &org.authority,
+            &org.address,
+            &proposal,
+            &params,
+            Some(&season),
+        ),
+        GovernanceError::ProposalOutsideSeason,
+    );
+    params.end_at = NOW + 3 * 86_400;
+    harness.expect_error(
+        &ix::update_proposal_draft(&org.authority, &org.address, &proposal, &params, None),
+        GovernanceError::SeasonMismatch,
+    );
+    harness.run(&ix::update_proposal_draft(
+        &org.authority,
+        &org.address,
+        &proposal,
+        &params,
+        Some(&season),
+    ));
+}
+
+#[test]
+fn extend_voting_period_moves_the_end_once() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    let end_at = harness.state::<Proposal>(&proposal).end_at;
+    harness.expect_error(
+        &ix::extend_voting_period(
+            &org.authority,
+            &org.address,
+            &proposal,
+            end_at + 3_600,
+            None,
+        ),
+        GovernanceError::ProposalNotOpen,
+    );
+
+    harness.open_proposal(&org, &proposal);
+    harness.run(&ix::extend_voting_period(
+        &org.authority,
+        &org.address,
+        &proposal,
+        end_at + 3_600,
+        None,
+    ));
+    let state: Proposal = harness.state(&proposal);
+    assert_eq!(state.original_end_at, end_at);
+    assert_eq!(state.end_at, end_at + 3_600);
+    assert_eq!(state.updated_at, harness.now());
+    harness.expect_error(
+        &ix::extend_voting_period(
+            &org.authority,
+            &org.address,
+            &proposal,
+            end_at + 7_200,
+            None,
+        ),
+        GovernanceError::VotingPeriodAlreadyExtended,
+    );
+
+    // Closing waits for the extended end.
+    harness.warp_to(end_at);
+    let nonce = harness.nonce(&org);
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &proposal,
+            nonce,
+            ProposalStatus::Closed,
+            &[],
+        ),
+        GovernanceError::VotingNotEnded,
+    );
+    harness.close_proposal(&org, &proposal);
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).closed_at,
+        end_at + 3_600
+    );
+}
+
+#[test]
+fn extend_voting_period_stays_inside_its_season() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    harness.run(&ix::create_season(
+        &org.authority,
+        &org.address,
+        [5u8; 16],
+        NOW,
+        NOW + 2 * 86_400,
+    ));
+    let (season, _) = ix::season_pda(&org.address, [5u8; 16]);
+    let params = ix::ProposalParams::new(2, NOW);
+    harness.run(&ix::create_proposal(
+        &org.authority,
+        &org.address,
+        &params,
+        Some(&season),
+    ));
+    let (proposal, _) = ix::proposal_pda(&org.address, params.proposal_id);
+    harness.open_proposal(&org, &proposal);
+
+    harness.expect_error(
+        &ix::extend_voting_period(
+            &org.authority,
+            &org.address,
+            &proposal,
+            NOW + 2 * 86_400 + 1,
+            Some(&season),
+        ),
+        GovernanceError::ProposalOutsideSeason,
+    );
+    harness.expect_error(
+        &ix::extend_voting_period(
+            &org.authority,
+            &org.address,
+            &proposal,
+            NOW + 2 * 86_400,
+            None,
+        ),
+        GovernanceError::SeasonMismatch,
+    );
+    harness.run(&ix::extend_voting_period(
+        &org.authority,
+        &org.address,
+        &proposal,
+        NOW + 2 * 86_400,
+        Some(&season),
+    ));
+}
+
+#[test]
+fn set_org_config_creates_then_replaces_the_config() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let (config, bump) = ix::org_config_pda(&org.address);
+
+    harness.expect_error(
+        &ix::set_org_config(&org.authority, &org.address, 250, 3_600, 7_200, 86_400),
+        GovernanceError::InvalidOrgConfig,
+    );
+    harness.run(&ix::set_org_config(
+        &org.authority,
+        &org.address,
+        250,
+        2 * 86_400,
+        3_600,
+        7 * 86_400,
+    ));
+    assert!(harness.state::<Organization>(&org.address).has_config);
+    let state: OrgConfig = harness.state(&config);
+    assert_eq!(state.bump, bump);
+    assert_eq!(address(&org.address), state.organization);
+    assert_eq!(state.default_quorum_requirement, 250);
+    assert_eq!(state.default_voting_duration_seconds, 2 * 86_400);
+    assert_eq!(state.created_at, NOW);
+
+    harness.warp_to(NOW + 100);
+    harness.run(&ix::set_org_config(
+        &org.authority,
+        &org.address,
+        0,
+        86_400,
+        86_400,
+        86_400,
+    ));
+    let state: OrgConfig = harness.state(&config);
+    assert_eq!(state.default_quorum_requirement, 0);
+    assert_eq!(state.max_voting_duration_seconds, 86_400);
+    assert_eq!(state.created_at, NOW);
+    assert_eq!(state.updated_at, NOW + 100);
+}
+
+#[test]
+fn create_proposal_falls_back_to_the_org_config() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    harness.run(&ix::set_org_config(
+        &org.authority,
+        &org.address,
+        250,
+        2 * 86_400,
+        3_600,
+        7 * 86_400,
+    ));
+
+    let mut params = ix::ProposalParams::new(2, NOW);
+    params.end_at = USE_DEFAULT_END_AT;
+    params.quorum_requirement = USE_DEFAULT_QUORUM;
+    let create = ix::create_proposal(&org.authority, &org.address, &params, None);
+    harness.expect_error(&create, GovernanceError::OrgConfigRequired);
+    harness.run(&ix::with_org_config(create, 4, &org.address));
+
+    let (proposal, _) = ix::proposal_pda(&org.address, params.proposal_id);
+    let state: Proposal = harness.state(&proposal);
+    assert_eq!(state.end_at, params.start_at + 2 * 86_400);
+    assert_eq!(state.quorum_requirement, 250);
+
+    // Explicit values still apply, within the config's limits.
+    let mut params = ix::ProposalParams::new(3, NOW);
+    params.end_at = params.start_at + 7 * 86_400 + 1;
+    let create = ix::create_proposal(&org.authority, &org.address, &params, None);
+    harness.expect_error(
+        &ix::with_org_config(create, 4, &org.address),
+        GovernanceError::VotingWindowTooLong,
+    );
+    params.end_at = params.start_at + 7 * 86_400;
+    let batch = ix::create_proposals_batch(&org.authority, &org.address, &[params], None);
+    harness.run(&ix::with_org_config(batch, 4, &org.address));
+}
+
+#[test]
+fn org_config_limits_drafts_and_extensions() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    harness.run(&ix::set_org_config(
+        &org.authority,
+        &org.address,
+        100,
+        86_400,
+        3_600,
+        2 * 86_400,
+    ));
+
+    let mut params = ix::ProposalParams::new(2, NOW);
+    params.end_at = params.start_at + 1_800 + MIN_VOTING_DURATION_SECONDS;
+    let update = ix::update_proposal_draft(&org.authority, &org.address, &proposal, &params, None);
+    harness.expect_error(&update, GovernanceError::OrgConfigRequired);
+    params.end_at = params.start_at + 3 * 86_400;
+    let update = ix::update_proposal_draft(&org.authority, &org.address, &proposal, &params, None);
+    harness.expect_error(
+        &ix::with_org_config(update, 3, &org.address),
+        GovernanceError::VotingWindowTooLong,
+    );
+
+    harness.open_proposal(&org, &proposal);
+    let start_at = harness.state::<Proposal>(&proposal).start_at;
+    let extend = ix::extend_voting_period(
+        &org.authority,
+        &org.address,
+        &proposal,
+        start_at + 2 * 86_400 + 1,
+        None,
+    );
+    harness.expect_error(
+        &ix::with_org_config(extend, 3, &org.address),
+        GovernanceError::VotingWindowTooLong,
+    );
+    let extend = ix::extend_voting_period(
+        &org.authority,
+        &org.address,
+        &proposal,
+        start_at + 2 * 86_400,
+        None,
+    );
+    harness.run(&ix::with_org_config(extend, 3, &org.address));
+}
+
+#[test]
+fn close_organization_requires_the_org_config_closed() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    harness.run(&ix::set_org_config(
+        &org.authority,
+        &org.address,
+        100,
+        86_400,
+        3_600,
+        2 * 86_400,
+    ));
+    let (config, _) = ix::org_config_pda(&org.address);
+
+    let close_config = ix::close_org_config(&org.authority, &org.address);
+    harness.expect_error(&close_config, GovernanceError::OrganizationNotFrozen);
+
+    harness.run(&ix::freeze_organization(&org.authority, &org.address));
+    harness.warp_to(NOW + ORGANIZATION_CLOSE_DELAY_SECONDS);
+    let close = ix::close_organization(&org.authority, &org.address);
+    harness.expect_error(&close, GovernanceError::OrganizationNotEmpty);
+
+    let balance = harness.account(&org.authority).unwrap().lamports;
+    let deposit = harness.account(&config).unwrap().lamports;
+    harness.run(&close_config);
+    assert!(!harness.state::<Organization>(&org.address).has_config);
+    assert!(harness.account(&config).unwrap().data.is_empty());
+    assert_eq!(
+        harness.account(&org.authority).unwrap().lamports,
+        balance + deposit
+    );
+    harness.run(&close);
+}
+
+/// Opens and closes `proposal` and commits 420 votes, returning the results
+/// PDA.
+#[test]
+fn audit_log_records_privileged_instructions() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let adapter = harness.funded_key();
+    let (log, bump) = ix::audit_log_pda(&org.address);
+
+    harness.run(&ix::create_audit_log(&org.authority, &org.address));
+    assert!(harness.state::<Organization>(&org.address).has_audit_log);
+    let state: AuditLog = harness.state(&log);
+    assert_eq!(state.bump, bump);
+    assert_eq!(address(&org.address), state.organization);
+    assert_eq!(state.created_at, NOW);
+
+    // From now on privileged instructions need the log, passed last.
+    let add = ix::add_adapter_signer(&org.authority, &org.address, &adapter);
+    harness.expect_error(&add, GovernanceError::AuditLogRequired);
+    harness.warp_to(NOW + 10);
+    harness.run(&ix::with_audit_log(add, &org.address));
+
+    // Optional accounts keep their places in front of the log.
+    let (season, _) = ix::season_pda(&org.address, [7u8; 16]);
+    let create_season = ix::create_season(
+        &org.authority,
+        &org.address,
+        [7u8; 16],
+        NOW,
+        NOW + 30 * 86_400,
+    );
+    harness.run(&ix::with_audit_log(create_season, &org.address));
+    let params = ix::ProposalParams::new(2, NOW);
+    let (proposal, _) = ix::proposal_pda(&org.address, params.proposal_id);
+    let create = ix::create_proposal(&org.authority, &org.address, &params, Some(&season));
+    harness.run(&ix::with_audit_log(create, &org.address));
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).season,
+        address(&season)
+    );
+
+    harness.warp_to_voting_start(&proposal);
+    let open = ix::update_proposal_status(
+        &adapter,
+        &org.address,
+        &proposal,
+        harness.nonce(&org),
+        ProposalStatus::Open,
+        &[],
+    );
+    harness.run(&ix::with_audit_log(open, &org.address));
+
+    let state: AuditLog = harness.state(&log);
+    let entries: Vec<_> = state
+        .recent_entries()
+        .map(|entry| {
+            (
+                pubkey(&entry.actor),
+                entry.instruction,
+                pubkey(&entry.target),
+            )
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        [
+            (org.authority, CREATE_AUDIT_LOG, log),
+            (org.authority, ADD_ADAPTER_SIGNER, adapter),
+            (org.authority, CREATE_SEASON, season),
+            (org.authority, CREATE_PROPOSAL, proposal),
+            (adapter, UPDATE_PROPOSAL_STATUS, proposal),
+        ]
+    );
+    assert_eq!(state.entries[0].timestamp, NOW);
+    assert_eq!(state.entries[1].timestamp, NOW + 10);
+}
+
+#[test]
+fn close_organization_requires_the_audit_log_closed() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    harness.run(&ix::create_audit_log(&org.authority, &org.address));
+    let (log, _) = ix::audit_log_pda(&org.address);
+
+    let close_log = ix::close_audit_log(&org.authority, &org.address);
+    harness.expect_error(&close_log, GovernanceError::OrganizationNotFrozen);
+
+    let freeze = ix::freeze_organization(&org.authority, &org.address);
+    harness.run(&ix::with_audit_log(freeze, &org.address));
+    let state: AuditLog = harness.state(&log);
+    assert_eq!(state.entry_count, 2);
+    assert_eq!(state.entries[1].instruction, FREEZE_ORGANIZATION);
+
+    harness.warp_to(NOW + ORGANIZATION_CLOSE_DELAY_SECONDS);
+    let close = ix::close_organization(&org.authority, &org.address);
+    harness.expect_error(&close, GovernanceError::OrganizationNotEmpty);
+
+    let balance = harness.account(&org.authority).unwrap().lamports;
+    let deposit = harness.account(&log).unwrap().lamports;
+    harness.run(&close_log);
+    assert!(!harness.state::<Organization>(&org.address).has_audit_log);
+    assert!(harness.account(&log).unwrap().data.is_empty());
+    assert_eq!(
+        harness.account(&org.authority).unwrap().lamports,
+        balance + deposit
+    );
+    harness.run(&close);
+}
+
+fn commit_results(harness: &mut Harness, org: &Org, proposal: &Pubkey) -> Pubkey {
+    harness.open_proposal(org, proposal);
+    harness.close_proposal(org, proposal);
+    let nonce = harness.nonce(org);
+    harness.run(&ix::commit_vote_results(
+        &org.authority,
+        &org.address,
+        proposal,
+        nonce,
+        420,
+    ));
+    ix::results_pda(proposal).0
+}
+
+#[test]
+fn anyone_finalizes_once_the_dispute_window_ends() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    harness.run(&ix::set_dispute_policy(
+        &org.authority,
+        &org.address,
+        86_400,
+        0,
+    ));
+    let proposal = harness.create_proposal(&org, 2);
+    let results = commit_results(&mut harness, &org, &proposal);
+    let ends_at = harness.state::<ProposalResults>(&results).dispute_ends_at;
+    assert_eq!(ends_at, harness.now() + 86_400);
+
+    let nonce = harness.nonce(&org);
+    harness.expect_error(
+        &ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &proposal,
+            nonce,
+            ProposalStatus::Finalized,
+            &[results],
+        ),
+        GovernanceError::DisputeWindowOpen,
+    );
+    harness.expect_error(
+        &ix::finalize_proposal(&org.address, &proposal, &[]),
+        GovernanceError::DisputeWindowOpen,
+    );
+
+    harness.warp_to(ends_at);
+    harness.run(&ix::finalize_proposal(&org.address, &proposal, &[]));
+    assert_eq!(
+        harness.state::<Proposal>(&proposal).status,
+        ProposalStatus::Finalized
+    );
+    assert_eq!(
+        harness.state::<ProposalResults>(&results).finalized_at,
+        ends_at
+    );
+    // Permissionless finalizing consumes no operation nonce.
+    assert_eq!(harness.nonce(&org), nonce);
+    harness.expect_error(
+        &ix::finalize_proposal(&org.address, &proposal, &[]),
+        GovernanceError::InvalidStatusTransition,
+    );
+}
+
+#[test]
+fn finalize_proposal_counts_the_season_and_stats() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    harness.run(&ix::create_season(
+        &org.authority,
+        &org.address,
+        [5u8; 16],
+        NOW,
+        NOW + 3 * 86_400,
+    ));
+    let (season, _) = ix::season_pda(&org.address, [5u8; 16]);
+    harness.run(&ix::create_org_stats(&org.authority, &org.address));
+    let (stats, _) = ix::org_stats_pda(&org.address);
+    let params = ix::ProposalParams::new(2, NOW);
+    harness.run(&ix::create_proposal(
+        &org.authority,
+        &org.address,
+        &params,
+        Some(&season),
+    ));
+    let (proposal, _) = ix::proposal_pda(&org.address, params.proposal_id);
+    commit_results(&mut harness, &org, &proposal);
+
+    harness.expect_error(
+        &ix::finalize_proposal(&org.address, &proposal, &[season]),
+        GovernanceError::OrgStatsRequired,
+    );
+    harness.run(&ix::finalize_proposal(
+        &org.address,
+        &proposal,
+        &[season, stats],
+    ));
+    assert_eq!(harness.state::<Season>(&season).finalized_proposal_count, 1);
+    assert_eq!(harness.state::<OrgStats>(&stats).total_votes_cast, 420);
+}
+
+#[test]
+fn disputed_results_wait_for_the_operator_and_refund_the_bond() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    harness.run(&ix::set_dispute_policy(
+        &org.authority,
+        &org.address,
+        86_400,
+        1_000_000,
+    ));
+    let challenger = harness.funded_key();
+    let balance = harness.account(&challenger).unwrap().lamports;
+    let proposal = harness.create_proposal(&org, 2);
+    let results = commit_results(&mut harness, &org, &proposal);
+    let rent = harness.account(&results).unwrap().lamports;
+
+    harness.run(&ix::dispute_results(&challenger, &org.address, &proposal));
+    let state: ProposalResults = harness.state(&results);
+    assert_eq!(state.disputed_by, address(&challenger));
+    assert_eq!(state.disputed_at, harness.now());
+    assert_eq!(state.dispute_bond, 1_000_000);
+    assert_eq!(
+        harness.account(&results).unwrap().lamports,
+        rent + 1_000_000
+    );
+    assert_eq!(
+        harness.account(&challenger).unwrap().lamports,
+        balance - 1_000_000
+    );
+    harness.expect_error(
+        &ix::dispute_results(&org.authority, &org.address, &proposal),
+        GovernanceError::ResultsDisputed,
+    );
+
+    harness.warp_to(state.dispute_ends_at);
+    harness.expect_error(
+        &ix::finalize_proposal(&org.address, &proposal, &[]),
+        GovernanceError::ResultsDisputed,
+    );
+    let nonce = harness.nonce(&org);
+    let finalize = |extra: &[Pubkey]| {
+        let accounts: Vec<_> = [results].into_iter().chain(extra.iter().copied()).collect();
+        ix::update_proposal_status(
+            &org.authority,
+            &org.address,
+            &proposal,
+            nonce,
+            ProposalStatus::Finalized,
+            &accounts,
+        )
+    };
+    harness.expect_error(&finalize(&[]), GovernanceError::ChallengerMismatch);
+    harness.expect_error(
+        &finalize(&[org.authority]),
+        GovernanceError::ChallengerMismatch,
+    );
+    harness.run(&finalize(&[challenger]));
+    assert_eq!(harness.account(&results).unwrap().lamports, rent);
+    assert_eq!(harness.account(&challenger).unwrap().lamports, balance);
+}
+
+#[test]
+fn results_can_only_be_disputed_inside_the_window() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    harness.expect_error(
+        &ix::dispute_results(&org.authority, &org.address, &proposal),
+        GovernanceError::ResultsNotCommitted,
+    );
+
+    // Without a window, results are final as soon as they are committed.
+    commit_results(&mut harness, &org, &proposal);
+    harness.expect_error(
+        &ix::dispute_results(&org.authority, &org.address, &proposal),
+        GovernanceError::DisputeWindowClosed,
+    );
+
+    harness.run(&ix::set_dispute_policy(
+        &org.authority,
+        &org.address,
+        3_600,
+        0,
+    ));
+    let disputed = harness.create_proposal(&org, 3);
+    let results = commit_results(&mut harness, &org, &disputed);
+    harness.run(&ix::dispute_results(
+        &org.authority,
+        &org.address,
+        &disputed,
+    ));
+    let state: ProposalResults = harness.state(&results);
+    assert_eq!(state.disputed_by, address(&org.authority));
+    assert_eq!(state.dispute_bond, 0);
+
+    // The authority's dispute posts no bond, so no challenger is needed.
+    harness.warp_to(state.dispute_ends_at);
+    let nonce = harness.nonce(&org);
+    harness.run(&ix::update_proposal_status(
+        &org.authority,
+        &org.address,
+        &disputed,
+        nonce,
+        ProposalStatus::Finalized,
+        &[results],
+    ));
+}
+
+/// Overwrites the committed `results_hash` with the hash of a canonical
+/// encoding for `proposal_id` and returns that encoding.
+fn commit_preimage(harness: &mut Harness, results: &Pubkey, proposal_id: [u8; 16]) -> Vec<u8> {
+    let mut state: ProposalResults = harness.state(results);
+    let options = [
+        OptionTally {
+            option_id: [1; 16],
+            vote_count: 300,
+            voting_power: 3_000,
+        },
+        OptionTally {
+            option_id: [2; 16],
+            vote_count: 120,
+            voting_power: 1_200,
+        },
+    ];
+    let preimage = canonical_results_bytes(&VoteResults {
+        proposal_id,
+        total_votes_cast: state.total_votes_cast,
+        total_voting_power: 4_200,
+        options: &options,
+    })
+    .unwrap();
+    state.results_hash = hash_results_bytes(&preimage);
+    harness.set_state(*results, &state);
+    preimage
+}
+
+#[test]
+fn anyone_verifies_results_against_their_preimage() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    harness.expect_error(
+        &ix::verify_results_hash(&proposal, &[], &[]),
+        GovernanceError::ResultsNotCommitted,
+    );
+
+    let results = commit_results(&mut harness, &org, &proposal);
+    let preimage = commit_preimage(&mut harness, &results, [2; 16]);
+
+    let mut tampered = preimage.clone();
+    *tampered.last_mut().unwrap() ^= 1;
+    harness.expect_error(
+        &ix::verify_results_hash(&proposal, &tampered, &[]),
+        GovernanceError::ResultsHashMismatch,
+    );
+    harness.expect_error(
+        &ix::verify_results_hash(&proposal, &preimage[..preimage.len() - 1], &[]),
+        GovernanceError::InvalidResultsPreimage,
+    );
+    assert!(!harness.state::<ProposalResults>(&results).hash_verified);
+
+    harness.run(&ix::verify_results_hash(&proposal, &preimage, &[]));
+    assert!(harness.state::<ProposalResults>(&results).hash_verified);
+}
+
+#[test]
+fn verify_results_hash_reads_preimage_chunks_from_accounts() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    let results = commit_results(&mut harness, &org, &proposal);
+    let preimage = commit_preimage(&mut harness, &results, [2; 16]);
+
+    let (inline, rest) = preimage.split_at(20);
+    let chunks: Vec<Pubkey> = rest
+        .chunks(30)
+        .map(|chunk| {
+            let key = Pubkey::new_unique();
+            harness.set_account(
+                key,
+                Account {
+                    lamports: 1,
+                    data: chunk.to_vec(),
+                    owner: Pubkey::new_unique(),
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            );
+            key
+        })
+        .collect();
+
+    harness.expect_error(
+        &ix::verify_results_hash(&proposal, inline, &chunks[1..]),
+        GovernanceError::InvalidResultsPreimage,
+    );
+    harness.run(&ix::verify_results_hash(&proposal, inline, &chunks));
+    assert!(harness.state::<ProposalResults>(&results).hash_verified);
+}
+
+#[test]
+fn verify_results_hash_rejects_another_proposals_preimage() {
+    let mut harness = Harness::new();
+    let org = harness.create_org(1);
+    let proposal = harness.create_proposal(&org, 2);
+    let results = commit_results(&mut harness, &org, &proposal);
+    // Hashes match, but the encoding names a different proposal.
+    let preimage = commit_preimage(&mut harness, &results, [3; 16]);
+
+    harness.expect_error(
+        &ix::verify_results_hash(&proposal, &preimage, &[]),
+        GovernanceError::InvalidResultsPreimage,
+    );
+
+    let other = harness.create_proposal(&org, 3);
+    let mut instruction = ix::verify_results_hash(&other, &preimage, &[]);
+    instruction.accounts[1].pubkey = results;
+    harness.expect_error(&instruction, GovernanceError::ResultsNotCommitted);
+}
@@ -0,0 +1,142 @@
+use fan_engagement_log::results::{
+    canonical_results_bytes, compute_results_hash, encoded_len, hash_results_bytes,
+    hash_results_chunks, parse_results_header, OptionTally, ResultsEncodingError, ResultsHeader,
+    VoteResults, RESULTS_ENCODING_VERSION,
+};
+
+fn id(byte: u8) -> [u8; 16] {
+    [byte; 16]
+}
+
+fn sample_options() -> [OptionTally; 3] {
+    [
+        OptionTally { option_id: id(3), vote_count: 7, voting_power: 700 },
+        OptionTally { option_id: id(1), vote_count: 12, voting_power: 1_500 },
+        OptionTally { option_id: id(2), vote_count: 0, voting_power: 0 },
+    ]
+}
+
+fn sample_results(options: &[OptionTally]) -> VoteResults<'_> {
+    VoteResults {
+        proposal_id: id(0xAB),
+        total_votes_cast: 19,
+        total_voting_power: 2_200,
+        options,
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[test]
+fn encoding_has_fixed_layout() {
+    let options = sample_options();
+    let bytes = canonical_results_bytes(&sample_results(&options)).unwrap();
+
+    assert_eq!(bytes.len(), encoded_len(3));
+    assert_eq!(bytes[0], RESULTS_ENCODING_VERSION);
+    assert_eq!(&bytes[1..17], &id(0xAB));
+    assert_eq!(&bytes[17..25], &19u64.to_le_bytes());
+    assert_eq!(&bytes[25..33], &2_200u64.to_le_bytes());
+    assert_eq!(&bytes[33..37], &3u32.to_le_bytes());
+
+    // First entry is the lowest option id, not the first supplied.
+    assert_eq!(&bytes[37..53], &id(1));
+    assert_eq!(&bytes[53..61], &12u64.to_le_bytes());
+    assert_eq!(&bytes[61..69], &1_500u64.to_le_bytes());
+}
+
+#[test]
+fn hash_is_independent_of_option_order() {
+    let options = sample_options();
+    let mut reversed = options;
+    reversed.reverse();
+
+    assert_eq!(
+        compute_results_hash(&sample_results(&options)).unwrap(),
+        compute_results_hash(&sample_results(&reversed)).unwrap()
+    );
+}
+
+#[test]
+fn hash_matches_golden_vector() {
+    // Any change to this value is a breaking change for every committed
+    // results hash and must come with a RESULTS_ENCODING_VERSION bump.
+    let options = sample_options();
+    let hash = compute_results_hash(&sample_results(&options)).unwrap();
+
+    assert_eq!(to_hex(&hash), GOLDEN_HASH);
+}
+
+#[test]
+fn hash_of_preimage_matches_compute() {
+    let options = sample_options();
+    let results = sample_results(&options);
+    let bytes = canonical_results_bytes(&results).unwrap();
+
+    assert_eq!(hash_results_bytes(&bytes), compute_results_hash(&results).unwrap());
+}
+
+#[test]
+fn rejects_duplicate_option_ids() {
+    let options = [
+        OptionTally { option_id: id(1), vote_count: 1, voting_power: 1 },
+        OptionTally { option_id: id(1), vote_count: 2, voting_power: 2 },
+    ];
+
+    assert_eq!(
+        canonical_results_bytes(&sample_results(&options)),
+        Err(ResultsEncodingError::DuplicateOptionId)
+    );
+}
+
+#[test]
+fn encodes_empty_option_list() {
+    let bytes = canonical_results_bytes(&sample_results(&[])).unwrap();
+
+    assert_eq!(bytes.len(), encoded_len(0));
+    assert_eq!(&bytes[33..37], &0u32.to_le_bytes());
+}
+
+#[test]
+fn parses_header_split_across_chunks() {
+    let options = sample_options();
+    let bytes = canonical_results_bytes(&sample_results(&options)).unwrap();
+    let expected = ResultsHeader {
+        proposal_id: id(0xAB),
+        total_votes_cast: 19,
+        total_voting_power: 2_200,
+        option_count: 3,
+    };
+
+    for split in [0, 1, 20, 37, 50, bytes.len()] {
+        let (head, tail) = bytes.split_at(split);
+        assert_eq!(parse_results_header(&[head, tail]), Some(expected), "split at {split}");
+        assert_eq!(hash_results_chunks(&[head, tail]), hash_results_bytes(&bytes));
+    }
+    let singles: Vec<&[u8]> = bytes.chunks(1).collect();
+    assert_eq!(parse_results_header(&singles), Some(expected));
+    assert_eq!(hash_results_chunks(&singles), hash_results_bytes(&bytes));
+}
+
+#[test]
+fn rejects_non_canonical_preimages() {
+    let options = sample_options();
+    let bytes = canonical_results_bytes(&sample_results(&options)).unwrap();
+
+    assert_eq!(parse_results_header(&[]), None);
+    assert_eq!(parse_results_header(&[&bytes[..36]]), None);
+    assert_eq!(parse_results_header(&[&bytes[..bytes.len() - 1]]), None);
+    assert_eq!(parse_results_header(&[&bytes, &[0]]), None);
+
+    let mut wrong_version = bytes.clone();
+    wrong_version[0] = RESULTS_ENCODING_VERSION + 1;
+    assert_eq!(parse_results_header(&[&wrong_version]), None);
+
+    let mut wrong_count = bytes.clone();
+    wrong_count[33..37].copy_from_slice(&2u32.to_le_bytes());
+    assert_eq!(parse_results_header(&[&wrong_count]), None);
+}
+
+const GOLDEN_HASH: &str = "e0f64d628a6a9ec19c04c05122fa68fcdd46ef232f805696d3b1c6908703ab53";
@@ -0,0 +1,195 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use common::fixtures::{audit_log, organization, proposal, results, season};
+use fan_engagement_log::state::{
+    AuditLog, Organization, Proposal, ProposalResults, ProposalStatus, Season, MAX_ADAPTER_SIGNERS,
+};
+use serde_json::json;
+
+#[test]
+fn season_json_shape_is_stable() {
+    assert_eq!(
+        serde_json::to_value(season()).unwrap(),
+        json!({
+            "bump": 252,
+            "organization": "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi",
+            "season_id": "19191919191919191919191919191919",
+            "start_at": 1_690_000_000,
+            "end_at": 1_720_000_000,
+            "proposal_count": 4,
+            "finalized_proposal_count": 2,
+            "total_votes_cast": 900,
+            "created_at": 1_689_000_000,
+            "updated_at": 1_700_000_000,
+        })
+    );
+}
+
+#[test]
+fn proposal_json_shape_is_stable() {
+    assert_eq!(
+        serde_json::to_value(proposal()).unwrap(),
+        json!({
+            "bump": 254,
+            "status": "Open",
+            "organization": "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi",
+            "proposal_id": "02020202020202020202020202020202",
+            "created_by": "YMN9Qj5jPNp7j14VPcML1B6xGgcPWVZUGLFU3Mnyfaf",
+            "season": "cGfHiC6Kgg3FpFZvgwGcswsCRtp4aBP2fzuXRQPizuN",
+            "results": "GgBaCs3NCBuZN12kCJgAW63ydqohFkHEdfdEXBPzLHq",
+            "content_hash": "0606060606060606060606060606060606060606060606060606060606060606",
+            "content_hash_scheme": "Sha256",
+            "start_at": 1_700_000_000,
+            "end_at": 1_700_086_400,
+            "original_end_at": 1_700_050_000,
+            "quorum_requirement": 100,
+            "eligible_voting_power": 10_000,
+            "snapshot_slot": 250_000_000,
+            "voter_merkle_root": "0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d0d",
+            "created_at": 1_699_999_000,
+            "updated_at": 1_700_000_000,
+            "opened_at": 1_700_000_000,
+            "closed_at": 0,
+            "amendment_count": 1,
+            "history_hash": "0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f",
+            "title": "New kit?",
+        })
+    );
+}
+
+#[test]
+fn proposal_results_json_shape_is_stable() {
+    assert_eq!(
+        serde_json::to_value(results()).unwrap(),
+        json!({
+            "bump": 253,
+            "proposal": "GgBaCs3NCBuZN12kCJgAW63ydqohFkHEdfdEXBPzLHq",
+            "results_hash": "0505050505050505050505050505050505050505050505050505050505050505",
+            "results_hash_scheme": "Sha256",
+            "winning_option_id": "07070707070707070707070707070707",
+            "total_votes_cast": 321,
+            "quorum_met": true,
+            "committed_at": 1_700_090_000,
+            "finalized_at": 0,
+            "dispute_ends_at": 1_700_176_400,
+            "disputed_by": "p2Yicb86aZig616Eav2VWG9vuXR5mEqhtzshZYBxzsV",
+            "disputed_at": 1_700_100_000,
+            "dispute_bond": 1_000_000,
+            "hash_verified": true,
+        })
+    );
+}
+
+#[test]
+fn organization_encodes_addresses_ids_and_text() {
+    let value = serde_json::to_value(organization()).unwrap();
+
+    assert_eq!(value["organization_id"], "03030303030303030303030303030303");
+    assert_eq!(
+        value["authority"],
+        "YMN9Qj5jPNp7j14VPcML1B6xGgcPWVZUGLFU3Mnyfaf"
+    );
+    assert_eq!(
+        value["adapter_signers"][0],
+        "gBxS1f6uyyGPuW5MzGBukidSb71jdsCb5fZaoSzULE5"
+    );
+    assert_eq!(
+        value["adapter_signers"][3],
+        "11111111111111111111111111111111"
+    );
+    assert_eq!(value["name"], "Club");
+    assert!(value.get("name_len").is_none());
+}
+
+#[test]
+fn audit_log_lists_every_slot() {
+    let log = audit_log();
+    let value = serde_json::to_value(&log).unwrap();
+
+    assert_eq!(value["entry_count"], 2);
+    assert_eq!(
+        value["entries"][1]["actor"],
+        "YMN9Qj5jPNp7j14VPcML1B6xGgcPWVZUGLFU3Mnyfaf"
+    );
+    assert_eq!(value["entries"][1]["instruction"], 1);
+    assert_eq!(
+        value["entries"][2]["actor"],
+        "11111111111111111111111111111111"
+    );
+    assert_eq!(value["entries"].as_array().unwrap().len(), 32);
+    assert_eq!(serde_json::from_value::<AuditLog>(value).unwrap(), log);
+}
+
+#[test]
+fn text_lengths_are_derived_from_the_text() {
+    let mut value = serde_json::to_value(organization()).unwrap();
+    value["name"] = json!("Clubhouse");
+    value["name_len"] = json!(4);
+    let org = serde_json::from_value::<Organization>(value).unwrap();
+    assert_eq!(org.name_len, 9);
+    assert_eq!(org.name(), b"Clubhouse");
+
+    let mut value = serde_json::to_value(proposal()).unwrap();
+    value["title"] = json!("Kit");
+    let proposal = serde_json::from_value::<Proposal>(value).unwrap();
+    assert_eq!(proposal.title_len, 3);
+    assert_eq!(proposal.title(), b"Kit");
+}
+
+#[test]
+fn state_round_trips_through_json() {
+    let org = organization();
+    let json = serde_json::to_string(&org).unwrap();
+    assert_eq!(serde_json::from_str::<Organization>(&json).unwrap(), org);
+
+    let season = season();
+    let json = serde_json::to_string(&season).unwrap();
+    assert_eq!(serde_json::from_str::<Season>(&json).unwrap(), season);
+
+    let proposal = proposal();
+    let json = serde_json::to_string(&proposal).unwrap();
+    assert_eq!(serde_json::from_str::<Proposal>(&json).unwrap(), proposal);
+
+    let results = results();
+    let json = serde_json::to_string(&results).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ProposalResults>(&json).unwrap(),
+        results
+    );
+}
+
+#[test]
+fn proposal_status_serializes_by_name() {
+    assert_eq!(
+        serde_json::to_value(ProposalStatus::Closed).unwrap(),
+        json!("Closed")
+    );
+    assert_eq!(
+        serde_json::from_value::<ProposalStatus>(json!("Finalized")).unwrap(),
+        ProposalStatus::Finalized
+    );
+}
+
+#[test]
+fn rejects_malformed_encodings() {
+    let mut value = serde_json::to_value(season()).unwrap();
+    value["organization"] = json!("not-base58!");
+    assert!(serde_json::from_value::<Season>(value).is_err());
+
+    let mut value = serde_json::to_value(season()).unwrap();
+    value["season_id"] = json!("abab");
+    assert!(serde_json::from_value::<Season>(value).is_err());
+}
+
+#[test]
+fn rejects_adapter_signer_count_over_limit() {
+    let mut value = serde_json::to_value(organization()).unwrap();
+    value["adapter_signer_count"] = json!(MAX_ADAPTER_SIGNERS);
+    assert!(serde_json::from_value::<Organization>(value.clone()).is_ok());
+
+    value["adapter_signer_count"] = json!(MAX_ADAPTER_SIGNERS + 1);
+    let error = serde_json::from_value::<Organization>(value).unwrap_err();
+    assert!(error.to_string().contains("adapter_signer_count"));
+}
@@ -0,0 +1,748 @@
+mod common;
+
+use common::fixtures::{
+    audit_log, closed_organization, org_config, org_stats, organization, proposal, results, season,
+    summary,
+};
+use fan_engagement_log::{
+    error::GovernanceError,
+    state::{
+        AccountState, AuditEntry, AuditLog, ClosedOrganization, HashScheme, OrgConfig, OrgStats,
+        Organization, Proposal, ProposalStatus, ProposalSummary, Season, AUDIT_LOG_CAPACITY,
+        AUDIT_LOG_DISCRIMINATOR, CLOSED_ORGANIZATION_DISCRIMINATOR, MAX_ADAPTER_SIGNERS,
+        MAX_DISPUTE_WINDOW_SECONDS, MAX_VOTING_DURATION_SECONDS, MAX_VOTING_EXTENSION_SECONDS,
+        MIN_VOTING_DURATION_SECONDS, ORGANIZATION_CLOSE_DELAY_SECONDS, ORGANIZATION_DISCRIMINATOR,
+        ORG_CONFIG_DISCRIMINATOR, ORG_STATS_DISCRIMINATOR, PROPOSAL_DISCRIMINATOR,
+        PROPOSAL_RESULTS_DISCRIMINATOR, PROPOSAL_SUMMARY_DISCRIMINATOR, RECOVERY_TIMELOCK_SECONDS,
+        SEASON_DISCRIMINATOR,
+    },
+};
+use pinocchio::Address;
+
+fn round_trip<T: AccountState + PartialEq + core::fmt::Debug>(state: T) {
+    let mut data = vec![0u8; T::LEN];
+    state.pack(&mut data).unwrap();
+    assert_eq!(data[0], T::DISCRIMINATOR);
+    assert_eq!(T::unpack(&data).unwrap(), state);
+}
+
+#[test]
+fn accounts_round_trip() {
+    round_trip(organization());
+    round_trip(proposal());
+    round_trip(results());
+    round_trip(season());
+    round_trip(summary());
+    round_trip(org_stats());
+    round_trip(org_config());
+    round_trip(audit_log());
+    round_trip(closed_organization());
+}
+
+#[test]
+fn discriminators_are_stable() {
+    // Clients match accounts on these bytes; changing one orphans existing accounts.
+    assert_eq!(ORGANIZATION_DISCRIMINATOR, 1);
+    assert_eq!(PROPOSAL_DISCRIMINATOR, 2);
+    assert_eq!(PROPOSAL_RESULTS_DISCRIMINATOR, 3);
+    assert_eq!(SEASON_DISCRIMINATOR, 4);
+    assert_eq!(PROPOSAL_SUMMARY_DISCRIMINATOR, 5);
+    assert_eq!(ORG_STATS_DISCRIMINATOR, 6);
+    assert_eq!(ORG_CONFIG_DISCRIMINATOR, 7);
+    assert_eq!(AUDIT_LOG_DISCRIMINATOR, 8);
+    assert_eq!(CLOSED_ORGANIZATION_DISCRIMINATOR, 9);
+    assert_eq!(Organization::DISCRIMINATOR, ORGANIZATION_DISCRIMINATOR);
+    assert_eq!(Season::DISCRIMINATOR, SEASON_DISCRIMINATOR);
+    assert_eq!(ProposalSummary::DISCRIMINATOR, PROPOSAL_SUMMARY_DISCRIMINATOR);
+    assert_eq!(OrgStats::DISCRIMINATOR, ORG_STATS_DISCRIMINATOR);
+    assert_eq!(OrgConfig::DISCRIMINATOR, ORG_CONFIG_DISCRIMINATOR);
+    assert_eq!(AuditLog::DISCRIMINATOR, AUDIT_LOG_DISCRIMINATOR);
+    assert_eq!(
+        ClosedOrganization::DISCRIMINATOR,
+        CLOSED_ORGANIZATION_DISCRIMINATOR
+    );
+}
+
+#[test]
+fn accessors_trim_padding() {
+    assert_eq!(organization().name(), b"Club");
+    assert_eq!(proposal().title(), b"New kit?");
+}
+
+#[test]
+fn unpack_rejects_wrong_discriminator_and_size() {
+    let mut data = vec![0u8; Proposal::LEN];
+    proposal().pack(&mut data).unwrap();
+
+    data[0] = ORGANIZATION_DISCRIMINATOR;
+    assert_eq!(Proposal::unpack(&data), Err(GovernanceError::InvalidAccountData));
+    assert_eq!(Organization::unpack(&data), Err(GovernanceError::InvalidAccountData));
+    assert_eq!(
+        Organization::unpack(&[]),
+        Err(GovernanceError::InvalidAccountData)
+    );
+}
+
+#[test]
+fn status_transitions_follow_lifecycle() {
+    use ProposalStatus::*;
+
+    assert!(Draft.can_transition_to(Open));
+    assert!(Open.can_transition_to(Closed));
+    assert!(Closed.can_transition_to(Finalized));
+
+    assert!(!Draft.can_transition_to(Closed));
+    assert!(!Open.can_transition_to(Draft));
+    assert!(!Closed.can_transition_to(Open));
+    assert!(!Finalized.can_transition_to(Draft));
+    assert!(!Open.can_transition_to(Open));
+}
+
+#[test]
+fn only_sha256_hashes_are_supported() {
+    assert_eq!(HashScheme::default(), HashScheme::Sha256);
+    assert_eq!(HashScheme::Sha256.check_supported(), Ok(()));
+    for scheme in [HashScheme::Keccak256, HashScheme::Blake3] {
+        assert_eq!(
+            scheme.check_supported(),
+            Err(GovernanceError::UnsupportedHashScheme)
+        );
+    }
+
+    assert_eq!(HashScheme::try_from(2), Ok(HashScheme::Blake3));
+    assert_eq!(
+        HashScheme::try_from(3),
+        Err(GovernanceError::InvalidAccountData)
+    );
+}
+
+#[test]
+fn transitions_follow_the_voting_window() {
+    let mut proposal = proposal();
+    proposal.status = ProposalStatus::Draft;
+    let (start_at, end_at) = (proposal.start_at, proposal.end_at);
+
+    assert_eq!(
+        proposal.check_transition(ProposalStatus::Open, start_at - 1),
+        Err(GovernanceError::VotingNotStarted)
+    );
+    assert_eq!(proposal.check_transition(ProposalStatus::Open, start_at), Ok(()));
+    assert_eq!(proposal.check_transition(ProposalStatus::Open, end_at - 1), Ok(()));
+    assert_eq!(
+        proposal.check_transition(ProposalStatus::Open, end_at),
+        Err(GovernanceError::VotingEnded)
+    );
+    assert_eq!(
+        proposal.check_transition(ProposalStatus::Closed, end_at),
+        Err(GovernanceError::InvalidStatusTransition)
+    );
+
+    proposal.status = ProposalStatus::Open;
+    assert_eq!(
+        proposal.check_transition(ProposalStatus::Closed, end_at - 1),
+        Err(GovernanceError::VotingNotEnded)
+    );
+    assert_eq!(proposal.check_transition(ProposalStatus::Closed, end_at), Ok(()));
+
+    proposal.status = ProposalStatus::Closed;
+    assert_eq!(proposal.check_transition(ProposalStatus::Finalized, start_at), Ok(()));
+}
+
+#[test]
+fn transitions_record_when_voting_opened_and_closed() {
+    let mut proposal = proposal();
+    proposal.status = ProposalStatus::Draft;
+    proposal.opened_at = 0;
+    let (start_at, end_at) = (proposal.start_at, proposal.end_at);
+
+    assert_eq!(
+        proposal.transition(ProposalStatus::Open, start_at - 1),
+        Err(GovernanceError::VotingNotStarted)
+    );
+    assert_eq!(proposal.status, ProposalStatus::Draft);
+    assert_eq!(proposal.opened_at, 0);
+
+    proposal.transition(ProposalStatus::Open, start_at + 5).unwrap();
+    proposal.transition(ProposalStatus::Closed, end_at + 7).unwrap();
+    proposal.transition(ProposalStatus::Finalized, end_at + 600).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Finalized);
+    assert_eq!(proposal.opened_at, start_at + 5);
+    assert_eq!(proposal.closed_at, end_at + 7);
+    assert_eq!(proposal.updated_at, end_at + 600);
+}
+
+#[test]
+fn open_pins_the_snapshot_slot() {
+    let mut proposal = proposal();
+    proposal.status = ProposalStatus::Draft;
+    proposal.snapshot_slot = 0;
+    let start_at = proposal.start_at;
+
+    assert_eq!(
+        proposal.open(start_at - 1, 300_000_000),
+        Err(GovernanceError::VotingNotStarted)
+    );
+    assert_eq!(proposal.snapshot_slot, 0);
+
+    proposal.open(start_at, 300_000_000).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Open);
+    assert_eq!(proposal.opened_at, start_at);
+    assert_eq!(proposal.snapshot_slot, 300_000_000);
+}
+
+#[test]
+fn transitions_extend_the_history_hash_chain() {
+    let mut proposal = proposal();
+    proposal.status = ProposalStatus::Draft;
+    proposal.history_hash = [0; 32];
+    let (start_at, end_at) = (proposal.start_at, proposal.end_at);
+
+    let mut expected = [0u8; 32];
+    for (status, at) in [
+        (ProposalStatus::Open, start_at),
+        (ProposalStatus::Closed, end_at),
+        (ProposalStatus::Finalized, end_at + 600),
+    ] {
+        proposal.transition(status, at).unwrap();
+
+        let mut preimage = expected.to_vec();
+        preimage.push(status as u8);
+        preimage.extend_from_slice(&at.to_le_bytes());
+        expected = solana_sha256_hasher::hash(&preimage).to_bytes();
+        assert_eq!(proposal.history_hash, expected);
+    }
+
+    // A rejected transition leaves the chain alone.
+    assert_eq!(
+        proposal.transition(ProposalStatus::Open, start_at),
+        Err(GovernanceError::InvalidStatusTransition)
+    );
+    assert_eq!(proposal.history_hash, expected);
+}
+
+#[test]
+fn open_with_snapshot_replaces_eligible_power_only_from_draft() {
+    let mut proposal = proposal();
+    assert_eq!(
+        proposal.open_with_snapshot(300_000_000, [14u8; 32], 20_000, 1_700_000_000),
+        Err(GovernanceError::InvalidStatusTransition)
+    );
+
+    proposal.status = ProposalStatus::Draft;
+    assert_eq!(
+        proposal.open_with_snapshot(300_000_000, [14u8; 32], 99, 1_700_000_000),
+        Err(GovernanceError::QuorumExceedsEligiblePower)
+    );
+    assert_eq!(proposal.status, ProposalStatus::Draft);
+    assert_eq!(proposal.eligible_voting_power, 10_000);
+
+    proposal.open_with_snapshot(300_000_000, [14u8; 32], 20_000, 1_700_000_000).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Open);
+    assert_eq!(proposal.opened_at, 1_700_000_000);
+    assert_eq!(proposal.snapshot_slot, 300_000_000);
+    assert_eq!(proposal.voter_merkle_root, [14u8; 32]);
+    assert_eq!(proposal.eligible_voting_power, 20_000);
+    round_trip(proposal);
+}
+
+#[test]
+fn eligible_voting_power_is_locked_once_opened() {
+    let mut proposal = proposal();
+    assert_eq!(
+        proposal.set_eligible_voting_power(20_000),
+        Err(GovernanceError::VotingPowerLocked)
+    );
+
+    proposal.status = ProposalStatus::Draft;
+    assert_eq!(
+        proposal.set_eligible_voting_power(99),
+        Err(GovernanceError::QuorumExceedsEligiblePower)
+    );
+    proposal.set_eligible_voting_power(20_000).unwrap();
+    assert_eq!(proposal.eligible_voting_power, 20_000);
+}
+
+#[test]
+fn quorum_must_be_reachable() {
+    assert_eq!(Proposal::validate_quorum(0, 0), Ok(()));
+    assert_eq!(Proposal::validate_quorum(100, 100), Ok(()));
+    assert_eq!(
+        Proposal::validate_quorum(1, 0),
+        Err(GovernanceError::ZeroEligibleVotingPower)
+    );
+    assert_eq!(
+        Proposal::validate_quorum(101, 100),
+        Err(GovernanceError::QuorumExceedsEligiblePower)
+    );
+}
+
+#[test]
+fn voting_duration_is_bounded() {
+    let start_at = 1_700_000_000;
+    assert_eq!(
+        Proposal::validate_voting_duration(start_at, start_at + MIN_VOTING_DURATION_SECONDS),
+        Ok(())
+    );
+    assert_eq!(
+        Proposal::validate_voting_duration(start_at, start_at + MAX_VOTING_DURATION_SECONDS),
+        Ok(())
+    );
+    assert_eq!(
+        Proposal::validate_voting_duration(start_at, start_at + MIN_VOTING_DURATION_SECONDS - 1),
+        Err(GovernanceError::VotingWindowTooShort)
+    );
+    assert_eq!(
+        Proposal::validate_voting_duration(start_at, start_at + MAX_VOTING_DURATION_SECONDS + 1),
+        Err(GovernanceError::VotingWindowTooLong)
+    );
+    assert_eq!(
+        Proposal::validate_voting_duration(i64::MIN, i64::MAX),
+        Err(GovernanceError::VotingWindowTooLong)
+    );
+}
+
+#[test]
+fn voting_period_extends_once_forward_and_within_limits() {
+    let mut proposal = proposal();
+    assert_eq!(
+        proposal.extend_voting_period(proposal.end_at + 60, 1_700_000_100),
+        Err(GovernanceError::VotingPeriodAlreadyExtended)
+    );
+
+    proposal.original_end_at = 0;
+    let end_at = proposal.end_at;
+    let now = 1_700_000_100;
+    assert_eq!(
+        proposal.extend_voting_period(end_at + 60, end_at),
+        Err(GovernanceError::VotingEnded)
+    );
+    for (new_end_at, error) in [
+        (end_at, GovernanceError::InvalidVotingExtension),
+        (end_at - 60, GovernanceError::InvalidVotingExtension),
+        (
+            end_at + MAX_VOTING_EXTENSION_SECONDS + 1,
+            GovernanceError::VotingExtensionTooLong,
+        ),
+    ] {
+        assert_eq!(proposal.extend_voting_period(new_end_at, now), Err(error));
+    }
+
+    let mut long = proposal.clone();
+    long.end_at = long.start_at + MAX_VOTING_DURATION_SECONDS - 60;
+    assert_eq!(
+        long.extend_voting_period(long.end_at + 120, now),
+        Err(GovernanceError::VotingWindowTooLong)
+    );
+
+    let mut draft = proposal.clone();
+    draft.status = ProposalStatus::Draft;
+    assert_eq!(
+        draft.extend_voting_period(end_at + 60, now),
+        Err(GovernanceError::ProposalNotOpen)
+    );
+
+    proposal
+        .extend_voting_period(end_at + MAX_VOTING_EXTENSION_SECONDS, now)
+        .unwrap();
+    assert!(proposal.was_extended());
+    assert_eq!(proposal.original_end_at, end_at);
+    assert_eq!(proposal.end_at, end_at + MAX_VOTING_EXTENSION_SECONDS);
+    assert_eq!(proposal.updated_at, now);
+    assert_eq!(
+        proposal.extend_voting_period(proposal.end_at + 60, now + 100),
+        Err(GovernanceError::VotingPeriodAlreadyExtended)
+    );
+}
+
+#[test]
+fn dispute_policy_window_is_bounded() {
+    let mut org = organization();
+    org.set_dispute_policy(MAX_DISPUTE_WINDOW_SECONDS, 0)
+        .unwrap();
+    assert_eq!(org.dispute_window_seconds, MAX_DISPUTE_WINDOW_SECONDS);
+    assert_eq!(org.dispute_bond_lamports, 0);
+    for window in [-1, MAX_DISPUTE_WINDOW_SECONDS + 1] {
+        assert_eq!(
+            org.set_dispute_policy(window, 5),
+            Err(GovernanceError::InvalidDisputeWindow)
+        );
+    }
+    assert_eq!(org.dispute_window_seconds, MAX_DISPUTE_WINDOW_SECONDS);
+}
+
+#[test]
+fn results_are_disputed_once_within_the_window() {
+    let mut results = results();
+    results.disputed_by = Address::default();
+    results.disputed_at = 0;
+    results.dispute_bond = 0;
+    let ends_at = results.dispute_ends_at;
+    let challenger = Address::new_from_array([12u8; 32]);
+
+    assert_eq!(
+        results.check_dispute_window_ended(ends_at - 1),
+        Err(GovernanceError::DisputeWindowOpen)
+    );
+    results.check_dispute_window_ended(ends_at).unwrap();
+    assert_eq!(
+        results.dispute(challenger, 500, ends_at),
+        Err(GovernanceError::DisputeWindowClosed)
+    );
+
+    results.dispute(challenger, 500, ends_at - 1).unwrap();
+    assert!(results.is_disputed());
+    assert_eq!(results.disputed_by, challenger);
+    assert_eq!(results.disputed_at, ends_at - 1);
+    assert_eq!(results.dispute_bond, 500);
+    assert_eq!(
+        results.dispute(Address::new_from_array([13u8; 32]), 0, ends_at - 1),
+        Err(GovernanceError::ResultsDisputed)
+    );
+}
+
+#[test]
+fn votes_cast_cannot_exceed_eligible_power() {
+    let proposal = proposal();
+
+    assert_eq!(proposal.validate_votes_cast(10_000), Ok(()));
+    assert_eq!(
+        proposal.validate_votes_cast(10_001),
+        Err(GovernanceError::VotesExceedEligiblePower)
+    );
+}
+
+#[test]
+fn season_contains_only_windows_inside_it() {
+    let season = season();
+
+    assert!(season.contains(season.start_at, season.end_at));
+    assert!(!season.contains(season.start_at - 1, season.end_at));
+    assert!(!season.contains(season.start_at, season.end_at + 1));
+}
+
+#[test]
+fn proposal_without_season_uses_default_address() {
+    let mut proposal = proposal();
+    assert!(proposal.has_season());
+
+    proposal.season = Address::default();
+    assert!(!proposal.has_season());
+}
+
+#[test]
+fn operation_nonce_only_advances_on_expected_value() {
+    let mut org = organization();
+
+    assert_eq!(
+        org.advance_operation_nonce(40),
+        Err(GovernanceError::StaleOperationNonce)
+    );
+    assert_eq!(
+        org.advance_operation_nonce(42),
+        Err(GovernanceError::StaleOperationNonce)
+    );
+    assert_eq!(org.operation_nonce, 41);
+
+    org.advance_operation_nonce(41).unwrap();
+    assert_eq!(org.operation_nonce, 42);
+
+    // A retried submission carrying the consumed nonce is rejected.
+    assert_eq!(
+        org.advance_operation_nonce(41),
+        Err(GovernanceError::StaleOperationNonce)
+    );
+}
+
+#[test]
+fn adapter_signers_are_bounded_and_unique() {
+    let mut org = organization();
+    let key = |n: u8| Address::new_from_array([n; 32]);
+
+    assert!(org.is_adapter_signer(&key(10)));
+    assert!(!org.is_adapter_signer(&org.authority));
+    assert_eq!(
+        org.add_adapter_signer(key(10)),
+        Err(GovernanceError::AdapterSignerAlreadyRegistered)
+    );
+
+    for n in 11..10 + MAX_ADAPTER_SIGNERS as u8 {
+        org.add_adapter_signer(key(n)).unwrap();
+    }
+    assert_eq!(org.adapter_signers().len(), MAX_ADAPTER_SIGNERS);
+    assert_eq!(
+        org.add_adapter_signer(key(99)),
+        Err(GovernanceError::AdapterSignerLimitReached)
+    );
+    round_trip(org);
+}
+
+#[test]
+fn removing_adapter_signer_compacts_the_list() {
+    let mut org = organization();
+    let key = |n: u8| Address::new_from_array([n; 32]);
+    org.add_adapter_signer(key(11)).unwrap();
+    org.add_adapter_signer(key(12)).unwrap();
+
+    org.remove_adapter_signer(&key(10)).unwrap();
+    assert_eq!(org.adapter_signers(), &[key(12), key(11)]);
+    assert_eq!(org.adapter_signers[2], Address::default());
+    assert_eq!(
+        org.remove_adapter_signer(&key(10)),
+        Err(GovernanceError::AdapterSignerNotFound)
+    );
+}
+
+#[test]
+fn unpack_rejects_adapter_signer_count_over_limit() {
+    let mut data = vec![0u8; Organization::LEN];
+    organization().pack(&mut data).unwrap();
+
+    // discriminator, bump, organization_id, authority, proposal_count,
+    // archived_proposal_count, season_count, has_stats, has_config,
+    // has_audit_log, operation_nonce
+    data[1 + 1 + 16 + 32 + 8 + 8 + 8 + 1 + 1 + 1 + 8] = MAX_ADAPTER_SIGNERS as u8 + 1;
+    assert_eq!(
+        Organization::unpack(&data),
+        Err(GovernanceError::InvalidAccountData)
+    );
+}
+
+#[test]
+fn recovery_replaces_authority_only_after_timelock() {
+    let mut org = organization();
+    let new_authority = Address::new_from_array([12u8; 32]);
+    let now = 1_700_100_000;
+
+    assert_eq!(
+        org.complete_recovery(now),
+        Err(GovernanceError::RecoveryNotInitiated)
+    );
+    assert_eq!(
+        org.initiate_recovery(Address::default(), now),
+        Err(GovernanceError::InvalidAuthority)
+    );
+
+    org.initiate_recovery(new_authority, now).unwrap();
+    assert!(org.recovery_pending());
+    assert_eq!(
+        org.initiate_recovery(new_authority, now),
+        Err(GovernanceError::RecoveryAlreadyInitiated)
+    );
+    round_trip(org.clone());
+
+    assert_eq!(
+        org.complete_recovery(now + RECOVERY_TIMELOCK_SECONDS - 1),
+        Err(GovernanceError::RecoveryTimelockActive)
+    );
+    org.complete_recovery(now + RECOVERY_TIMELOCK_SECONDS).unwrap();
+    assert_eq!(org.authority, new_authority);
+    assert_eq!(org.pending_authority, Address::default());
+    assert!(!org.recovery_pending());
+}
+
+#[test]
+fn recovery_can_be_cancelled_and_requires_configuration() {
+    let mut org = organization();
+    let now = 1_700_100_000;
+
+    assert_eq!(
+        org.cancel_recovery(),
+        Err(GovernanceError::RecoveryNotInitiated)
+    );
+    org.initiate_recovery(Address::new_from_array([12u8; 32]), now).unwrap();
+    org.cancel_recovery().unwrap();
+    assert!(!org.recovery_pending());
+    assert_eq!(
+        org.complete_recovery(now + RECOVERY_TIMELOCK_SECONDS),
+        Err(GovernanceError::RecoveryNotInitiated)
+    );
+    assert_eq!(org.authority, Address::new_from_array([8u8; 32]));
+
+    org.recovery_authority = Address::default();
+    assert!(!org.has_recovery_authority());
+    assert_eq!(
+        org.initiate_recovery(Address::new_from_array([12u8; 32]), now),
+        Err(GovernanceError::RecoveryNotConfigured)
+    );
+}
+
+#[test]
+fn freeze_is_permanent_and_waits_for_pending_recovery() {
+    let mut org = organization();
+    let now = 1_700_100_000;
+
+    org.initiate_recovery(Address::new_from_array([12u8; 32]), now).unwrap();
+    assert_eq!(
+        org.freeze(now),
+        Err(GovernanceError::RecoveryAlreadyInitiated)
+    );
+    org.cancel_recovery().unwrap();
+
+    assert_eq!(org.require_not_frozen(), Ok(()));
+    org.freeze(now).unwrap();
+    assert!(org.is_frozen());
+    assert_eq!(org.frozen_at, now);
+    assert_eq!(
+        org.require_not_frozen(),
+        Err(GovernanceError::OrganizationFrozen)
+    );
+    assert_eq!(org.freeze(now + 1), Err(GovernanceError::OrganizationFrozen));
+    round_trip(org);
+}
+
+#[test]
+fn only_frozen_organizations_with_every_proposal_archived_close_after_the_delay() {
+    let mut org = organization();
+    org.proposal_count = 0;
+    org.archived_proposal_count = 0;
+    org.season_count = 0;
+    org.has_stats = false;
+    org.has_config = false;
+    org.has_audit_log = false;
+    let now = 1_700_100_000;
+
+    assert_eq!(
+        org.check_closable(now),
+        Err(GovernanceError::OrganizationNotFrozen)
+    );
+    org.freeze(now).unwrap();
+    assert_eq!(
+        org.check_closable(now + ORGANIZATION_CLOSE_DELAY_SECONDS - 1),
+        Err(GovernanceError::CloseDelayActive)
+    );
+    assert_eq!(
+        org.check_closable(now + ORGANIZATION_CLOSE_DELAY_SECONDS),
+        Ok(())
+    );
+
+    org.proposal_count = 2;
+    org.archived_proposal_count = 1;
+    assert_eq!(
+        org.check_closable(now + ORGANIZATION_CLOSE_DELAY_SECONDS),
+        Err(GovernanceError::OrganizationNotEmpty)
+    );
+    org.archived_proposal_count = 2;
+    assert_eq!(
+        org.check_closable(now + ORGANIZATION_CLOSE_DELAY_SECONDS),
+        Ok(())
+    );
+
+    org.season_count = 1;
+    assert_eq!(
+        org.check_closable(now + ORGANIZATION_CLOSE_DELAY_SECONDS),
+        Err(GovernanceError::OrganizationNotEmpty)
+    );
+    org.season_count = 0;
+    org.has_stats = true;
+    assert_eq!(
+        org.check_closable(now + ORGANIZATION_CLOSE_DELAY_SECONDS),
+        Err(GovernanceError::OrganizationNotEmpty)
+    );
+    org.has_stats = false;
+    org.has_config = true;
+    assert_eq!(
+        org.check_closable(now + ORGANIZATION_CLOSE_DELAY_SECONDS),
+        Err(GovernanceError::OrganizationNotEmpty)
+    );
+    org.has_config = false;
+    org.has_audit_log = true;
+    assert_eq!(
+        org.check_closable(now + ORGANIZATION_CLOSE_DELAY_SECONDS),
+        Err(GovernanceError::OrganizationNotEmpty)
+    );
+}
+
+#[test]
+fn org_config_limits_are_ordered_within_program_limits() {
+    let config = org_config();
+    assert_eq!(config.validate(), Ok(()));
+
+    let mut unordered = config.clone();
+    unordered.default_voting_duration_seconds = unordered.max_voting_duration_seconds + 1;
+    assert_eq!(unordered.validate(), Err(GovernanceError::InvalidOrgConfig));
+    let mut unordered = config.clone();
+    unordered.min_voting_duration_seconds = unordered.default_voting_duration_seconds + 1;
+    assert_eq!(unordered.validate(), Err(GovernanceError::InvalidOrgConfig));
+
+    let mut too_short = config.clone();
+    too_short.min_voting_duration_seconds = MIN_VOTING_DURATION_SECONDS - 1;
+    assert_eq!(too_short.validate(), Err(GovernanceError::InvalidOrgConfig));
+    let mut too_long = config;
+    too_long.max_voting_duration_seconds = MAX_VOTING_DURATION_SECONDS + 1;
+    assert_eq!(too_long.validate(), Err(GovernanceError::InvalidOrgConfig));
+}
+
+#[test]
+fn org_config_bounds_voting_windows_and_supplies_the_default_end() {
+    let config = org_config();
+    let start_at = 1_700_000_000;
+
+    assert_eq!(config.default_end_at(start_at), Ok(start_at + 3 * 86_400));
+    assert_eq!(
+        config.check_voting_duration(start_at, start_at + 86_400 - 1),
+        Err(GovernanceError::VotingWindowTooShort)
+    );
+    assert_eq!(
+        config.check_voting_duration(start_at, start_at + 86_400),
+        Ok(())
+    );
+    assert_eq!(
+        config.check_voting_duration(start_at, start_at + 14 * 86_400),
+        Ok(())
+    );
+    assert_eq!(
+        config.check_voting_duration(start_at, start_at + 14 * 86_400 + 1),
+        Err(GovernanceError::VotingWindowTooLong)
+    );
+}
+
+#[test]
+fn audit_log_keeps_the_latest_entries_oldest_first() {
+    let mut log = audit_log();
+    assert_eq!(log.entry_count, 2);
+    let timestamps: Vec<i64> = log.recent_entries().map(|entry| entry.timestamp).collect();
+    assert_eq!(timestamps, [1_690_000_000, 1_699_999_000]);
+
+    let entry = |timestamp| AuditEntry {
+        actor: Address::new_from_array([8u8; 32]),
+        instruction: 0,
+        target: Address::new_from_array([2u8; 32]),
+        timestamp,
+    };
+    for timestamp in 0..AUDIT_LOG_CAPACITY as i64 {
+        log.append(entry(timestamp));
+    }
+
+    // The two entries from the fixture were overwritten, in order.
+    assert_eq!(log.entry_count, AUDIT_LOG_CAPACITY as u64 + 2);
+    let timestamps: Vec<i64> = log.recent_entries().map(|entry| entry.timestamp).collect();
+    assert_eq!(timestamps, (0..AUDIT_LOG_CAPACITY as i64).collect::<Vec<_>>());
+    assert_eq!(log.entries[0].timestamp, AUDIT_LOG_CAPACITY as i64 - 2);
+    round_trip(log);
+}
+
+#[test]
+fn org_stats_count_outcomes_and_average_turnout() {
+    let mut stats = OrgStats {
+        quorum_met_count: 0,
+        quorum_failed_count: 0,
+        total_votes_cast: 0,
+        turnout_bps_sum: 0,
+        ..org_stats()
+    };
+    assert_eq!(stats.average_turnout_bps(), 0);
+
+    // 321 of 10_000 eligible: 3.21% turnout.
+    let mut results = results();
+    stats.record_finalized(&proposal(), &results, 1_700_100_000);
+    results.quorum_met = false;
+    results.total_votes_cast = 7_000;
+    stats.record_finalized(&proposal(), &results, 1_700_200_000);
+
+    assert_eq!(stats.quorum_met_count, 1);
+    assert_eq!(stats.quorum_failed_count, 1);
+    assert_eq!(stats.finalized_proposal_count(), 2);
+    assert_eq!(stats.total_votes_cast, 7_321);
+    assert_eq!(stats.average_turnout_bps(), (321 + 7_000) / 2);
+    assert_eq!(stats.updated_at, 1_700_200_000);
+}